@@ -8,7 +8,7 @@ use syn::{
     punctuated::Punctuated,
     spanned::Spanned,
     token::{Colon, Comma},
-    PathArguments, Type,
+    Path, PathArguments, Type, TypeParamBound,
 };
 
 use crate::{
@@ -38,6 +38,11 @@ impl Parse for BindingKind {
 
         if lookahead.peek(manual::kw::cloned_instance_bind)
             || lookahead.peek(manual::kw::scoped_instance_bind)
+            || lookahead.peek(manual::kw::shared_instance_bind)
+            || lookahead.peek(manual::kw::arc_instance_bind)
+            || lookahead.peek(manual::kw::provider_instance_bind)
+            || lookahead.peek(manual::kw::env_bind)
+            || lookahead.peek(manual::kw::config_bind)
         {
             return input.parse::<ManualBindingKind>().map(BindingKind::Manual);
         }
@@ -45,6 +50,7 @@ impl Parse for BindingKind {
         if lookahead.peek(automatic::kw::singleton_bind)
             || lookahead.peek(automatic::kw::scoped_bind)
             || lookahead.peek(automatic::kw::static_bind)
+            || lookahead.peek(automatic::kw::members_bind)
         {
             return input
                 .parse::<AutomaticBindingKind>()
@@ -117,19 +123,19 @@ impl BindingKind {
 
         let mut map = HashMap::new();
 
-        let maybe_args_fun = {
-            let type_path = fun_ty.as_path()?;
-            let segments = &type_path.path.segments;
-
-            segments.last().map(|l| &l.arguments)
-        };
+        if fun_ty.as_trait_object().is_ok() != binding_ty.as_trait_object().is_ok() {
+            return Err(ComponentLogicAbort::TypeMismatch {
+                fun_type: fun_ty.clone(),
+                binding_kind: Box::new(self.clone()),
+            })?;
+        }
 
-        let maybe_args_binding = {
-            let type_path = binding_ty.as_path()?;
-            let segments = &type_path.path.segments;
+        let maybe_args_fun = last_path(fun_ty)?.segments.last().map(|l| &l.arguments);
 
-            segments.last().map(|l| &l.arguments)
-        };
+        let maybe_args_binding = last_path(&binding_ty)?
+            .segments
+            .last()
+            .map(|l| &l.arguments);
 
         // Check if angle-bracketed generics match
         {
@@ -158,7 +164,7 @@ impl BindingKind {
                 } else {
                     return Err(ComponentLogicAbort::TypeMismatch {
                         fun_type: fun_ty.clone(),
-                        binding_kind: self.clone(),
+                        binding_kind: Box::new(self.clone()),
                     })?;
                 }
             };
@@ -167,12 +173,50 @@ impl BindingKind {
         Ok(map)
     }
 
+    pub(crate) fn is_scoped(&self) -> bool {
+        matches!(
+            self,
+            BindingKind::Automatic(AutomaticBindingKind::Scoped { .. })
+                | BindingKind::Manual(ManualBindingKind::ScopedInstance { .. })
+        )
+    }
+
+    pub(crate) fn is_members(&self) -> bool {
+        match self {
+            BindingKind::Automatic(a) => a.is_members(),
+            BindingKind::Manual(_) => false,
+        }
+    }
+
     pub(crate) fn hint(&self) -> &'static str {
         match self {
             BindingKind::Automatic(a) => a.hint(),
             BindingKind::Manual(m) => m.hint(),
         }
     }
+
+    pub(crate) fn kind_name(&self) -> &'static str {
+        match self {
+            BindingKind::Automatic(a) => a.kind_name(),
+            BindingKind::Manual(m) => m.kind_name(),
+        }
+    }
+}
+
+/// Path of a type, or of the first trait of a trait object, e.g., `Storage<T>` for `dyn Storage<T> + Send`
+fn last_path(ty: &Type) -> ComponentResult<&Path> {
+    if let Ok(trait_object) = ty.as_trait_object() {
+        return trait_object
+            .bounds
+            .iter()
+            .find_map(|bound| match bound {
+                TypeParamBound::Trait(trait_bound) => Some(&trait_bound.path),
+                _ => None,
+            })
+            .ok_or_else(|| ComponentLogicAbort::InvalidType(ty.clone()).into());
+    }
+
+    Ok(&ty.as_path()?.path)
 }
 
 fn unwrap_once<'ty>(ty: &'ty Type, expected_name: &str) -> ComponentResult<&'ty Type> {
@@ -226,6 +270,10 @@ impl Binding {
     pub(crate) fn kind(&self) -> &BindingKind {
         &self.kind
     }
+
+    pub(crate) fn index(&self) -> usize {
+        self.index
+    }
 }
 
 impl ParseWithContext<usize> for Binding {
@@ -253,18 +301,6 @@ impl PartialOrd for Binding {
 
 impl Ord for Binding {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        if let Some(dependencies) = other.kind.dependencies() {
-            if dependencies.iter().any(|d| *d == self.identifier) {
-                return std::cmp::Ordering::Less;
-            }
-        }
-
-        if let Some(dependencies) = self.kind.dependencies() {
-            if dependencies.iter().any(|d| *d == other.identifier) {
-                return std::cmp::Ordering::Greater;
-            }
-        }
-
         Ord::cmp(&self.index, &other.index)
     }
 }
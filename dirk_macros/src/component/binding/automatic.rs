@@ -1,18 +1,25 @@
+use proc_macro2::Span;
 use syn::ExprPath;
 use syn::{
     bracketed, parenthesized,
-    parse::Parse,
+    parse::{Parse, ParseStream},
     punctuated::Punctuated,
-    token::{Bracket, Comma, Dot, Paren},
-    Error, Expr, ExprMethodCall, Ident, Path, PathArguments, PathSegment, Type,
+    spanned::Spanned,
+    token::{Bracket, Comma, Dot, Impl, Paren, RArrow},
+    Error, Expr, ExprMethodCall, Ident, Lifetime, ParenthesizedGenericArguments, Path,
+    PathArguments, PathSegment, ReturnType, Token, TraitBound, TraitBoundModifier, Type,
+    TypeImplTrait, TypeParamBound,
 };
 
 use crate::{
     component::error::{ComponentLogicAbort, ComponentResult},
     expectable::TypeExpectable,
     syntax::wrap_type,
-    util::{type_arc, type_rc, type_refcell, type_rwlock},
-    FACTORY_PREFIX_SCOPED, FACTORY_PREFIX_SINGLETON, FACTORY_PREFIX_STATIC,
+    util::{
+        path_fn, type_arc, type_kind_scoped, type_kind_singleton, type_kind_static, type_rc,
+        type_refcell, type_rwlock,
+    },
+    FACTORY_PREFIX_ANY, FACTORY_PREFIX_MEMBERS,
 };
 
 use super::{bindable::Bindable, bindable::FactoryBindable, unwrap_once};
@@ -21,6 +28,20 @@ pub(crate) mod kw {
     syn::custom_keyword!(singleton_bind);
     syn::custom_keyword!(scoped_bind);
     syn::custom_keyword!(static_bind);
+    syn::custom_keyword!(members_bind);
+    syn::custom_keyword!(qualifier);
+}
+
+/// Parses `, qualifier = q` following the type of a binding, selecting the provider function qualified by `q`
+fn parse_qualifier(input: ParseStream) -> syn::Result<Option<Ident>> {
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    input.parse::<Comma>()?;
+    input.parse::<kw::qualifier>()?;
+    input.parse::<Token![=]>()?;
+    input.parse().map(Some)
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -29,6 +50,7 @@ pub(crate) enum AutomaticBindingKind {
         kw: kw::singleton_bind,
         paren: Paren,
         ty: Type,
+        qualifier: Option<Ident>,
         bracket: Option<Bracket>,
         dependencies: Punctuated<Ident, Comma>,
     },
@@ -36,6 +58,7 @@ pub(crate) enum AutomaticBindingKind {
         kw: kw::scoped_bind,
         paren: Paren,
         ty: Type,
+        qualifier: Option<Ident>,
         bracket: Option<Bracket>,
         dependencies: Punctuated<Ident, Comma>,
     },
@@ -43,6 +66,14 @@ pub(crate) enum AutomaticBindingKind {
         kw: kw::static_bind,
         paren: Paren,
         ty: Type,
+        qualifier: Option<Ident>,
+        bracket: Option<Bracket>,
+        dependencies: Punctuated<Ident, Comma>,
+    },
+    Members {
+        kw: kw::members_bind,
+        paren: Paren,
+        ty: Type,
         bracket: Option<Bracket>,
         dependencies: Punctuated<Ident, Comma>,
     },
@@ -57,6 +88,7 @@ impl Parse for AutomaticBindingKind {
             let kw = kw::singleton_bind::parse(input)?;
             let paren = parenthesized!(ty_buf in input);
             let ty = ty_buf.parse()?;
+            let qualifier = parse_qualifier(&ty_buf)?;
             let (bracket, dependencies) = {
                 if input.peek(Bracket) {
                     let deps_buf;
@@ -75,6 +107,7 @@ impl Parse for AutomaticBindingKind {
             return Ok(Self::Singleton {
                 kw,
                 ty,
+                qualifier,
                 paren,
                 bracket,
                 dependencies,
@@ -86,6 +119,7 @@ impl Parse for AutomaticBindingKind {
             let kw = kw::scoped_bind::parse(input)?;
             let paren = parenthesized!(ty_buf in input);
             let ty = ty_buf.parse()?;
+            let qualifier = parse_qualifier(&ty_buf)?;
             let (bracket, dependencies) = {
                 if input.peek(Bracket) {
                     let deps_buf;
@@ -105,6 +139,7 @@ impl Parse for AutomaticBindingKind {
                 kw,
                 paren,
                 ty,
+                qualifier,
                 bracket,
                 dependencies,
             });
@@ -115,6 +150,7 @@ impl Parse for AutomaticBindingKind {
             let kw = kw::static_bind::parse(input)?;
             let paren = parenthesized!(ty_buf in input);
             let ty = ty_buf.parse()?;
+            let qualifier = parse_qualifier(&ty_buf)?;
             let (bracket, dependencies) = {
                 if input.peek(Bracket) {
                     let deps_buf;
@@ -131,6 +167,36 @@ impl Parse for AutomaticBindingKind {
             }
 
             return Ok(Self::Static {
+                kw,
+                paren,
+                ty,
+                qualifier,
+                bracket,
+                dependencies,
+            });
+        }
+
+        if lookahead.peek(kw::members_bind) {
+            let ty_buf;
+            let kw = kw::members_bind::parse(input)?;
+            let paren = parenthesized!(ty_buf in input);
+            let ty = ty_buf.parse()?;
+            let (bracket, dependencies) = {
+                if input.peek(Bracket) {
+                    let deps_buf;
+                    let bracket = bracketed!(deps_buf in input);
+                    let deps = deps_buf.parse_terminated(Ident::parse, Comma)?;
+                    (Some(bracket), deps)
+                } else {
+                    (None, Punctuated::new())
+                }
+            };
+
+            if !ty_buf.is_empty() {
+                Err(Error::new(input.span(), "Did not expect further tokens"))?;
+            }
+
+            return Ok(Self::Members {
                 kw,
                 paren,
                 ty,
@@ -154,9 +220,10 @@ impl Bindable for AutomaticBindingKind {
         }
 
         let ty = match self {
-            Self::Singleton { ty, .. } | Self::Scoped { ty, .. } | Self::Static { ty, .. } => {
-                ty.clone()
-            }
+            Self::Singleton { ty, .. }
+            | Self::Scoped { ty, .. }
+            | Self::Static { ty, .. }
+            | Self::Members { ty, .. } => ty.clone(),
         };
 
         if let Ok(type_impl_trait) = ty.as_impl_trait() {
@@ -176,7 +243,7 @@ impl Bindable for AutomaticBindingKind {
             Self::Scoped { .. } => self
                 .ty()
                 .map(|ty| wrap_type(wrap_type(ty, type_refcell), type_rc)),
-            Self::Static { .. } => self.ty(),
+            Self::Static { .. } | Self::Members { .. } => self.ty(),
         }
     }
 
@@ -192,7 +259,7 @@ impl Bindable for AutomaticBindingKind {
                 let other = unwrap_once(other, "RefCell")?;
                 Ok(other)
             }
-            Self::Static { ty: _, .. } => Ok(other),
+            Self::Static { ty: _, .. } | Self::Members { ty: _, .. } => Ok(other),
         }
     }
 
@@ -205,6 +272,18 @@ impl Bindable for AutomaticBindingKind {
                 "scoped bindings wrap their type T into a std::rc::Rc<std::cell::RefCell<T>>"
             }
             Self::Static { .. } => "static bindings do not wrap their type T and just return a T",
+            Self::Members { .. } => {
+                "members bindings inject into an existing T and are queried via a function taking a `&mut T`"
+            }
+        }
+    }
+
+    fn kind_name(&self) -> &'static str {
+        match self {
+            Self::Singleton { .. } => "singleton",
+            Self::Scoped { .. } => "scoped",
+            Self::Static { .. } => "static",
+            Self::Members { .. } => "members",
         }
     }
 }
@@ -239,10 +318,10 @@ impl FactoryBindable for AutomaticBindingKind {
                 let expr_method_call = ExprMethodCall {
                     attrs: Vec::new(),
                     receiver: Box::new(receiver),
-                    dot_token: Dot::default(),
+                    dot_token: Dot(dependency.span()),
                     method,
                     turbofish: None,
-                    paren_token: Paren::default(),
+                    paren_token: Paren(dependency.span()),
                     args: Punctuated::new(),
                 };
 
@@ -254,11 +333,44 @@ impl FactoryBindable for AutomaticBindingKind {
         res
     }
 
+    fn qualifier(&self) -> Option<&Ident> {
+        match self {
+            Self::Singleton { qualifier, .. }
+            | Self::Scoped { qualifier, .. }
+            | Self::Static { qualifier, .. } => qualifier.as_ref(),
+            Self::Members { .. } => None,
+        }
+    }
+
     fn factory_prefix(&self) -> &'static str {
         match self {
-            Self::Singleton { .. } => FACTORY_PREFIX_SINGLETON,
-            Self::Scoped { .. } => FACTORY_PREFIX_SCOPED,
-            Self::Static { .. } => FACTORY_PREFIX_STATIC,
+            // The kind is checked via `MatchesKind`, such that a mismatch does not leave the factory unresolved
+            Self::Singleton { .. } | Self::Scoped { .. } | Self::Static { .. } => {
+                FACTORY_PREFIX_ANY
+            }
+            Self::Members { .. } => FACTORY_PREFIX_MEMBERS,
+        }
+    }
+
+    fn dependencies_paren(&self) -> Paren {
+        let bracket = match self {
+            Self::Singleton { bracket, .. }
+            | Self::Scoped { bracket, .. }
+            | Self::Static { bracket, .. }
+            | Self::Members { bracket, .. } => bracket,
+        };
+
+        bracket
+            .map(|bracket| Paren { span: bracket.span })
+            .unwrap_or_default()
+    }
+
+    fn kind_ty(&self, span: Span) -> Option<Type> {
+        match self {
+            Self::Singleton { .. } => Some(type_kind_singleton(PathArguments::None, span)),
+            Self::Scoped { .. } => Some(type_kind_scoped(PathArguments::None, span)),
+            Self::Static { .. } => Some(type_kind_static(PathArguments::None, span)),
+            Self::Members { .. } => None,
         }
     }
 }
@@ -270,7 +382,61 @@ impl AutomaticBindingKind {
                 dependencies: _, ..
             } => None,
             Self::Scoped { dependencies, .. } => Some(dependencies),
-            Self::Static { dependencies, .. } => Some(dependencies),
+            Self::Static { dependencies, .. } | Self::Members { dependencies, .. } => {
+                Some(dependencies)
+            }
+        }
+    }
+
+    pub(crate) fn is_members(&self) -> bool {
+        matches!(self, Self::Members { .. })
+    }
+
+    /// Method of `Overrides` replacing a binding of this kind, if it may be overridden
+    pub(crate) fn override_fn(&self) -> Option<&'static str> {
+        match self {
+            Self::Singleton { .. } => Some("override_singleton"),
+            Self::Scoped { .. } => Some("override_scoped"),
+            Self::Static { .. } => Some("override_static"),
+            Self::Members { .. } => None,
+        }
+    }
+
+    /// Type of the argument of an `override_...(...)` method, i.e., a function creating instances for static bindings and an instance otherwise
+    pub(crate) fn override_arg_ty(&self) -> ComponentResult<Type> {
+        let ty = self.ty()?;
+
+        if !matches!(self, Self::Static { .. }) {
+            return Ok(ty);
         }
+
+        let span = ty.span();
+
+        let fn_bound = {
+            let arguments = PathArguments::Parenthesized(ParenthesizedGenericArguments {
+                paren_token: Paren::default(),
+                inputs: Punctuated::new(),
+                output: ReturnType::Type(RArrow::default(), Box::new(ty)),
+            });
+            TypeParamBound::Trait(TraitBound {
+                paren_token: None,
+                modifier: TraitBoundModifier::None,
+                lifetimes: None,
+                path: path_fn(arguments, span),
+            })
+        };
+        let static_bound = TypeParamBound::Lifetime(Lifetime {
+            apostrophe: span,
+            ident: Ident::new("static", span),
+        });
+
+        let mut bounds = Punctuated::new();
+        bounds.push(fn_bound);
+        bounds.push(static_bound);
+
+        Ok(Type::ImplTrait(TypeImplTrait {
+            impl_token: Impl::default(),
+            bounds,
+        }))
     }
 }
@@ -1,13 +1,22 @@
-use proc_macro2::Ident;
+use proc_macro2::{Ident, Span};
+use quote::quote;
 use syn::{
     punctuated::Punctuated,
     spanned::Spanned,
-    token::{Comma, Paren},
-    Expr, ExprCall, ExprPath, Path, PathArguments, PathSegment, Type,
+    token::{As, Brace, Comma, Gt, Lt, Paren, Semi, Struct, Underscore},
+    AngleBracketedGenericArguments, Block, Expr, ExprBlock, ExprCall, ExprParen, ExprPath,
+    ExprTuple, Fields, GenericArgument, Generics, Item, ItemStruct, Path, PathArguments,
+    PathSegment, QSelf, Stmt, Type, TypeInfer, TypePath, Visibility,
 };
 
 use crate::{
-    component::error::ComponentResult, errors::InfallibleError, expectable::TypeExpectable,
+    component::error::ComponentResult,
+    errors::InfallibleError,
+    expectable::TypeExpectable,
+    lint::mk_attribute,
+    syntax::qualifier_ty,
+    util::{path_bindable_via, path_from_dependencies, path_matches_kind, path_qualified},
+    FACTORY_PREFIX_QUALIFIED,
 };
 
 pub(crate) trait Bindable {
@@ -15,49 +24,294 @@ pub(crate) trait Bindable {
     fn wrapped_ty(&self) -> ComponentResult<Type>;
     fn unwrap_ty<'o>(&self, other: &'o Type) -> ComponentResult<&'o Type>;
     fn hint(&self) -> &'static str;
+    fn kind_name(&self) -> &'static str;
 }
 
 pub(crate) trait FactoryBindable: Bindable {
-    fn get_factory_create_call(&self) -> ComponentResult<ExprCall> {
-        let path = {
-            let ty = self.ty()?;
-
-            let mut segments = ty.as_path()?.path.segments.clone();
-            let last = segments
-                .last_mut()
-                .ok_or_else(|| InfallibleError::EmptyPath(ty.span()))?;
-            last.ident = Ident::new(
-                &format!("{}{}", self.factory_prefix(), last.ident),
-                last.ident.span(),
-            );
-            last.arguments = PathArguments::None;
+    /// `DirkFactoryT::create_checked(...)`, checked against the kind of the binding
+    fn get_factory_create_call(&self) -> ComponentResult<Expr> {
+        let fun = match self.qualifier() {
+            Some(qualifier) => self.get_qualified_create_fn(qualifier)?,
+            None => {
+                let ty = self.ty()?;
 
-            let create = PathSegment {
-                ident: Ident::new("create", ty.span()),
-                arguments: PathArguments::None,
-            };
-            segments.push(create);
+                let mut path = self.get_sibling_path(self.factory_prefix())?;
+                path.segments
+                    .push(PathSegment::from(Ident::new("create_checked", ty.span())));
 
-            Path {
-                leading_colon: None,
-                segments,
+                Expr::Path(ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path,
+                })
             }
         };
-        let expr_path = ExprPath {
+
+        // `create_checked((a_provider.clone(), ...))`, such that a wrong number of dependencies is reported by the factory
+        let mut elems = self.provider_calls();
+        if elems.len() == 1 {
+            elems.push_punct(Comma::default());
+        }
+        let dependencies = Expr::Tuple(ExprTuple {
             attrs: Vec::new(),
-            qself: None,
-            path,
-        };
-        let fun = syn::Expr::Path(expr_path);
+            paren_token: self.dependencies_paren(),
+            elems,
+        });
 
-        Ok(ExprCall {
+        let mut args = Punctuated::new();
+        args.push(dependencies);
+
+        let create_call = ExprCall {
             attrs: Vec::new(),
             func: Box::new(fun),
-            paren_token: Paren::default(),
-            args: self.provider_calls(),
+            paren_token: self.dependencies_paren(),
+            args,
+        };
+
+        self.get_checked_expr(Expr::Call(create_call))
+    }
+
+    /// Path of an item generated alongside `T`, named `<prefix>T`
+    ///
+    /// The item is resolved relative to the path of the type, e.g., `car::DirkFactoryEngine` for `car::Engine`.
+    fn get_sibling_path(&self, prefix: &str) -> ComponentResult<Path> {
+        let ty = self.ty()?;
+
+        let ty_path = &ty.as_path()?.path;
+        let mut segments = ty_path.segments.clone();
+        let last = segments
+            .last_mut()
+            .ok_or_else(|| InfallibleError::EmptyPath(ty.span()))?;
+        last.ident = Ident::new(&format!("{prefix}{}", last.ident), last.ident.span());
+        last.arguments = PathArguments::None;
+
+        Ok(Path {
+            leading_colon: ty_path.leading_colon,
+            segments,
         })
     }
 
+    /// `({ struct q; <<T as Qualified<Q, q, DirkQualifiedFactoryT>>::Factory as FromDependencies<_>>::from_dependencies })`, creating the factory of the function qualified by `q`
+    ///
+    /// An unknown qualifier is reported by name, as `q` is passed as well.
+    /// `q` is declared in a block of its own, such that it does not shadow a provider passed as dependency.
+    fn get_qualified_create_fn(&self, qualifier: &Ident) -> ComponentResult<Expr> {
+        let ty = self.ty()?;
+        let span = ty.span();
+
+        let name_struct = Item::Struct(ItemStruct {
+            attrs: vec![mk_attribute(
+                "allow",
+                quote! { non_camel_case_types, dead_code },
+                span,
+            )],
+            vis: Visibility::Inherited,
+            struct_token: Struct(span),
+            ident: qualifier.clone(),
+            generics: Generics::default(),
+            fields: Fields::Unit,
+            semi_token: Some(Semi(span)),
+        });
+
+        // `<T as Qualified<Q, q, DirkQualifiedFactoryT>>::Factory`
+        let factory = {
+            let mut args = Punctuated::new();
+            args.push(GenericArgument::Type(qualifier_ty(qualifier)));
+            args.push(GenericArgument::Type(Type::Path(TypePath {
+                qself: None,
+                path: Path::from(qualifier.clone()),
+            })));
+            args.push(GenericArgument::Type(Type::Path(TypePath {
+                qself: None,
+                path: self.get_sibling_path(FACTORY_PREFIX_QUALIFIED)?,
+            })));
+            let mut path = path_qualified(
+                PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                    colon2_token: None,
+                    lt_token: Lt(span),
+                    args,
+                    gt_token: Gt(span),
+                }),
+                span,
+            );
+            let position = path.segments.len();
+            path.segments
+                .push(PathSegment::from(Ident::new("Factory", span)));
+
+            Type::Path(TypePath {
+                qself: Some(QSelf {
+                    lt_token: Lt(span),
+                    ty: Box::new(ty),
+                    position,
+                    as_token: Some(As(span)),
+                    gt_token: Gt(span),
+                }),
+                path,
+            })
+        };
+
+        // `<... as FromDependencies<_>>::from_dependencies`
+        let from_dependencies = {
+            let mut args = Punctuated::new();
+            args.push(GenericArgument::Type(Type::Infer(TypeInfer {
+                underscore_token: Underscore(span),
+            })));
+            let mut path = path_from_dependencies(
+                PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                    colon2_token: None,
+                    lt_token: Lt(span),
+                    args,
+                    gt_token: Gt(span),
+                }),
+                span,
+            );
+            let position = path.segments.len();
+            path.segments
+                .push(PathSegment::from(Ident::new("from_dependencies", span)));
+
+            Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: Some(QSelf {
+                    lt_token: Lt(span),
+                    ty: Box::new(factory),
+                    position,
+                    as_token: Some(As(span)),
+                    gt_token: Gt(span),
+                }),
+                path,
+            })
+        };
+
+        let block = Expr::Block(ExprBlock {
+            attrs: Vec::new(),
+            label: None,
+            block: Block {
+                brace_token: Brace(span),
+                stmts: vec![Stmt::Item(name_struct), Stmt::Expr(from_dependencies, None)],
+            },
+        });
+
+        Ok(Expr::Paren(ExprParen {
+            attrs: Vec::new(),
+            paren_token: Paren(span),
+            expr: Box::new(block),
+        }))
+    }
+
+    /// `<<T as BindableVia>::Kind as MatchesKind<T, kind>>::bind(expr)`, such that uses of `expr` are not reported if the kind does not match
+    fn get_checked_expr(&self, expr: Expr) -> ComponentResult<Expr> {
+        let span = self.ty()?.span();
+
+        let Some((qself, path)) = self.matches_kind_path(Ident::new("bind", span))? else {
+            return Ok(expr);
+        };
+
+        let mut args = Punctuated::new();
+        args.push(expr);
+
+        Ok(Expr::Call(ExprCall {
+            attrs: Vec::new(),
+            func: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: Some(qself),
+                path,
+            })),
+            paren_token: Paren(span),
+            args,
+        }))
+    }
+
+    /// `<<T as BindableVia>::Kind as MatchesKind<T, kind>>::Checked<ty>`, such that uses of `ty` are not reported if the kind does not match
+    fn get_checked_ty(&self, ty: Type) -> ComponentResult<Type> {
+        let span = self.ty()?.span();
+
+        let Some((qself, mut path)) = self.matches_kind_path(Ident::new("Checked", span))? else {
+            return Ok(ty);
+        };
+
+        let mut args = Punctuated::new();
+        args.push(GenericArgument::Type(ty));
+        if let Some(last) = path.segments.last_mut() {
+            last.arguments = PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                colon2_token: None,
+                lt_token: Lt(span),
+                args,
+                gt_token: Gt(span),
+            });
+        }
+
+        Ok(Type::Path(TypePath {
+            qself: Some(qself),
+            path,
+        }))
+    }
+
+    /// `<<T as BindableVia>::Kind as MatchesKind<T, kind>>::item`, reporting a binding not matching how `T` is provided
+    ///
+    /// Returns `None` if the kind is not checked.
+    fn matches_kind_path(&self, item: Ident) -> ComponentResult<Option<(QSelf, Path)>> {
+        let ty = self.ty()?;
+        let span = ty.span();
+
+        let Some(kind_ty) = self.kind_ty(span) else {
+            return Ok(None);
+        };
+
+        // `<T as BindableVia>::Kind`
+        let bindable_via = path_bindable_via(PathArguments::None, span);
+        let provided_kind = {
+            let mut path = bindable_via.clone();
+            path.segments
+                .push(PathSegment::from(Ident::new("Kind", span)));
+
+            Type::Path(TypePath {
+                qself: Some(QSelf {
+                    lt_token: Lt(span),
+                    ty: Box::new(ty.clone()),
+                    position: bindable_via.segments.len(),
+                    as_token: Some(As(span)),
+                    gt_token: Gt(span),
+                }),
+                path,
+            })
+        };
+
+        let mut args = Punctuated::new();
+        args.push(GenericArgument::Type(ty));
+        args.push(GenericArgument::Type(kind_ty));
+        let matches_kind = path_matches_kind(
+            PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                colon2_token: None,
+                lt_token: Lt(span),
+                args,
+                gt_token: Gt(span),
+            }),
+            span,
+        );
+
+        let qself = QSelf {
+            lt_token: Lt(span),
+            ty: Box::new(provided_kind),
+            position: matches_kind.segments.len(),
+            as_token: Some(As(span)),
+            gt_token: Gt(span),
+        };
+
+        let mut path = matches_kind;
+        path.segments.push(PathSegment::from(item));
+
+        Ok(Some((qself, path)))
+    }
+
     fn provider_calls(&self) -> Punctuated<Expr, Comma>;
     fn factory_prefix(&self) -> &'static str;
+
+    /// Qualifier selecting one of several provider functions of the bound type, if any
+    fn qualifier(&self) -> Option<&Ident>;
+
+    /// Parentheses of the dependencies passed to `create_checked()`, spanning the list of dependencies such that arity errors point at it
+    fn dependencies_paren(&self) -> Paren;
+
+    /// Type naming the kind of binding, if it is checked against the kind the type is provided as
+    fn kind_ty(&self, span: Span) -> Option<Type>;
 }
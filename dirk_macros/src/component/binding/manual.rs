@@ -1,14 +1,27 @@
 use syn::{
-    parenthesized, parse::Parse, punctuated::Punctuated, spanned::Spanned, token::Paren, Error,
-    Expr, ExprCall, ExprPath, Ident, Path, Type,
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::{And, Comma, Dot, Dyn, Eq, Gt, Impl, Lt, Paren, PathSep, Question},
+    AngleBracketedGenericArguments, Error, Expr, ExprCall, ExprField, ExprLit, ExprPath,
+    ExprReference, ExprTry, GenericArgument, GenericParam, Ident, Index, Lifetime, Lit, LitStr,
+    Member, Path, PathArguments, TraitBound, TraitBoundModifier, Type, TypeImplTrait, TypeParam,
+    TypeParamBound, TypePath, TypeTraitObject,
 };
 
 use crate::{
     component::error::{ComponentLogicAbort, ComponentResult},
+    component::syntax::get_config_ident,
     expectable::TypeExpectable,
     syntax::wrap_type,
     util::{
-        path_cloned_instance_factory_new, path_scoped_instance_factory_new, type_rc, type_refcell,
+        path_arc_instance_factory_from_shared, path_arc_new, path_cloned_instance_factory_new,
+        path_config_factory_from_config, path_env_factory_from_env, path_into_provider,
+        path_into_provider_into_provider, path_none, path_provider_instance_factory_new,
+        path_rc_new, path_refcell_new, path_scoped_instance_factory_from_shared,
+        path_shared_instance_factory_from_shared, path_small_self, path_some, type_arc,
+        type_provider, type_rc, type_refcell,
     },
 };
 
@@ -17,6 +30,13 @@ use super::{bindable::Bindable, unwrap_once};
 pub(crate) mod kw {
     syn::custom_keyword!(cloned_instance_bind);
     syn::custom_keyword!(scoped_instance_bind);
+    syn::custom_keyword!(shared_instance_bind);
+    syn::custom_keyword!(arc_instance_bind);
+    syn::custom_keyword!(provider_instance_bind);
+    syn::custom_keyword!(env_bind);
+    syn::custom_keyword!(default);
+    syn::custom_keyword!(config_bind);
+    syn::custom_keyword!(path);
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -29,6 +49,29 @@ pub(crate) enum ManualBindingKind {
         kw: kw::scoped_instance_bind,
         ty: Type,
     },
+    SharedInstance {
+        kw: kw::shared_instance_bind,
+        ty: Type,
+    },
+    ArcInstance {
+        kw: kw::arc_instance_bind,
+        ty: Type,
+    },
+    ProviderInstance {
+        kw: kw::provider_instance_bind,
+        ty: Type,
+    },
+    Env {
+        kw: kw::env_bind,
+        ty: Type,
+        variable: LitStr,
+        default: Option<Expr>,
+    },
+    Config {
+        kw: kw::config_bind,
+        ty: Type,
+        section: Option<LitStr>,
+    },
 }
 
 impl Parse for ManualBindingKind {
@@ -46,6 +89,50 @@ impl Parse for ManualBindingKind {
             parenthesized!(ty in input);
             let ty = ty.parse()?;
             Self::ScopedInstance { kw, ty }
+        } else if lookahead.peek(kw::shared_instance_bind) {
+            let kw = kw::shared_instance_bind::parse(input)?;
+            parenthesized!(ty in input);
+            let ty = ty.parse()?;
+            Self::SharedInstance { kw, ty }
+        } else if lookahead.peek(kw::arc_instance_bind) {
+            let kw = kw::arc_instance_bind::parse(input)?;
+            parenthesized!(ty in input);
+            let ty = ty.parse()?;
+            Self::ArcInstance { kw, ty }
+        } else if lookahead.peek(kw::provider_instance_bind) {
+            let kw = kw::provider_instance_bind::parse(input)?;
+            parenthesized!(ty in input);
+            let ty = ty.parse()?;
+            Self::ProviderInstance { kw, ty }
+        } else if lookahead.peek(kw::env_bind) {
+            let kw = kw::env_bind::parse(input)?;
+            parenthesized!(ty in input);
+            let env_ty = ty.parse()?;
+            ty.parse::<Comma>()?;
+            let variable = ty.parse()?;
+            let default = parse_default(&ty)?;
+            Self::Env {
+                kw,
+                ty: env_ty,
+                variable,
+                default,
+            }
+        } else if lookahead.peek(kw::config_bind) {
+            let kw = kw::config_bind::parse(input)?;
+            if !cfg!(feature = "serde") {
+                return Err(Error::new(
+                    kw.span,
+                    "`config_bind(...)` requires the `serde` feature of `dirk_framework`",
+                ));
+            }
+            parenthesized!(ty in input);
+            let config_ty = ty.parse()?;
+            let section = parse_section(&ty)?;
+            Self::Config {
+                kw,
+                ty: config_ty,
+                section,
+            }
         } else {
             return Err(lookahead.error());
         };
@@ -63,12 +150,31 @@ impl Bindable for ManualBindingKind {
         let ty = match self {
             Self::ScopedInstance { kw: _, ty } => ty.clone(),
             Self::ClonedInstance { kw: _, ty } => ty.clone(),
+            Self::SharedInstance { kw: _, ty } => ty.clone(),
+            Self::ArcInstance { kw: _, ty } => ty.clone(),
+            Self::ProviderInstance { kw: _, ty } => ty.clone(),
+            Self::Env { ty, .. } => ty.clone(),
+            Self::Config { ty, .. } => ty.clone(),
         };
         if let Ok(type_impl_trait) = ty.as_impl_trait() {
             Err(ComponentLogicAbort::ImplTraitBinding(
                 type_impl_trait.clone(),
             ))?;
         }
+        if let Ok(type_trait_object) = ty.as_trait_object() {
+            // trait objects are unsized and hence need to be wrapped
+            if matches!(
+                self,
+                Self::ClonedInstance { .. }
+                    | Self::ProviderInstance { .. }
+                    | Self::Env { .. }
+                    | Self::Config { .. }
+            ) {
+                Err(ComponentLogicAbort::TraitObjectBinding(
+                    type_trait_object.clone(),
+                ))?;
+            }
+        }
         Ok(ty)
     }
 
@@ -77,7 +183,12 @@ impl Bindable for ManualBindingKind {
             Self::ScopedInstance { .. } => self
                 .ty()
                 .map(|ty| wrap_type(wrap_type(ty.clone(), type_refcell), type_rc)),
-            Self::ClonedInstance { .. } => self.ty(),
+            Self::SharedInstance { .. } => self.ty().map(|ty| wrap_type(ty, type_rc)),
+            Self::ArcInstance { .. } => self.ty().map(|ty| wrap_type(ty, type_arc)),
+            Self::ClonedInstance { .. }
+            | Self::ProviderInstance { .. }
+            | Self::Env { .. }
+            | Self::Config { .. } => self.ty(),
         }
     }
 
@@ -86,10 +197,14 @@ impl Bindable for ManualBindingKind {
             Self::ScopedInstance { kw: _, ty: _ } => {
                 let other = unwrap_once(other, "Rc")?;
                 let other = unwrap_once(other, "RefCell")?;
-                let other = dbg!(other);
                 Ok(other)
             }
-            Self::ClonedInstance { kw: _, ty: _ } => Ok(other),
+            Self::SharedInstance { kw: _, ty: _ } => unwrap_once(other, "Rc"),
+            Self::ArcInstance { kw: _, ty: _ } => unwrap_once(other, "Arc"),
+            Self::ClonedInstance { kw: _, ty: _ }
+            | Self::ProviderInstance { kw: _, ty: _ }
+            | Self::Env { .. }
+            | Self::Config { .. } => Ok(other),
         }
     }
 
@@ -101,18 +216,76 @@ impl Bindable for ManualBindingKind {
             Self::ScopedInstance {kw: _, ty:_ } => {
                 "scoped instance bindings wrap their type T into a std::rc::Rc<std::cell::RefCell<T>>"
             },
+            Self::SharedInstance {kw: _, ty:_ } => {
+                "shared instance bindings wrap their type T into a std::rc::Rc<T>"
+            },
+            Self::ArcInstance {kw: _, ty:_ } => {
+                "arc instance bindings wrap their type T into a std::sync::Arc<T>"
+            },
+            Self::ProviderInstance {kw: _, ty:_ } => {
+                "provider instance bindings do not wrap their type T and just return whatever the provider returns"
+            },
+            Self::Env { .. } => {
+                "environment bindings do not wrap their type T and just return a T"
+            },
+            Self::Config { .. } => {
+                "configuration bindings do not wrap their type T and just return a T"
+            },
+        }
+    }
+
+    fn kind_name(&self) -> &'static str {
+        match self {
+            Self::ClonedInstance { .. } => "cloned_instance",
+            Self::ScopedInstance { .. } => "scoped_instance",
+            Self::SharedInstance { .. } => "shared_instance",
+            Self::ArcInstance { .. } => "arc_instance",
+            Self::ProviderInstance { .. } => "provider_instance",
+            Self::Env { .. } => "env",
+            Self::Config { .. } => "config",
         }
     }
 }
 
 impl ManualBindingKind {
+    /// Whether the instance is passed to a builder method, as opposed to being read when the component is built
+    pub(crate) fn is_set_by_builder(&self) -> bool {
+        !matches!(self, Self::Env { .. } | Self::Config { .. })
+    }
+
+    /// Whether the instance is deserialized from the configuration held by the builder
+    pub(crate) fn is_config(&self) -> bool {
+        matches!(self, Self::Config { .. })
+    }
+
     pub(crate) fn get_new_factory(&self, ident: &Ident) -> Expr {
         let path = match self {
             ManualBindingKind::ClonedInstance { kw: _, ty } => {
                 path_cloned_instance_factory_new(syn::PathArguments::None, ty.span())
             }
             ManualBindingKind::ScopedInstance { kw: _, ty } => {
-                path_scoped_instance_factory_new(syn::PathArguments::None, ty.span())
+                path_scoped_instance_factory_from_shared(syn::PathArguments::None, ty.span())
+            }
+            ManualBindingKind::SharedInstance { kw: _, ty } => {
+                path_shared_instance_factory_from_shared(syn::PathArguments::None, ty.span())
+            }
+            ManualBindingKind::ArcInstance { kw: _, ty } => {
+                path_arc_instance_factory_from_shared(syn::PathArguments::None, ty.span())
+            }
+            ManualBindingKind::ProviderInstance { kw: _, ty } => {
+                path_provider_instance_factory_new(syn::PathArguments::None, ty.span())
+            }
+            // `<ident>?`, the factory has already been created by `get_fallible_factory`
+            ManualBindingKind::Env { .. } | ManualBindingKind::Config { .. } => {
+                return Expr::Try(ExprTry {
+                    attrs: Vec::new(),
+                    expr: Box::new(Expr::Path(ExprPath {
+                        attrs: Vec::new(),
+                        qself: None,
+                        path: Path::from(ident.clone()),
+                    })),
+                    question_token: Question::default(),
+                })
             }
         };
 
@@ -141,4 +314,326 @@ impl ManualBindingKind {
         };
         Expr::Call(expr_call)
     }
+
+    /// Type stored by the builder once the instance has been set, i.e., `std::rc::Rc<dyn Provider<T>>` for provider instance bindings, the wrapped type for scoped, shared and arc instance bindings and `T` otherwise
+    pub(crate) fn set_ty(&self) -> ComponentResult<Type> {
+        if matches!(
+            self,
+            Self::ScopedInstance { .. } | Self::SharedInstance { .. } | Self::ArcInstance { .. }
+        ) {
+            return self.wrapped_ty();
+        }
+
+        let ty = self.ty()?;
+
+        if !matches!(self, Self::ProviderInstance { .. }) {
+            return Ok(ty);
+        }
+
+        let span = ty.span();
+        let provider_path = wrap_type(ty, type_provider).as_path()?.path.clone();
+
+        let mut bounds = Punctuated::new();
+        bounds.push(TypeParamBound::Trait(TraitBound {
+            paren_token: None,
+            modifier: TraitBoundModifier::None,
+            lifetimes: None,
+            path: provider_path,
+        }));
+        bounds.push(TypeParamBound::Lifetime(Lifetime {
+            apostrophe: span,
+            ident: Ident::new("static", span),
+        }));
+
+        let dyn_type = Type::TraitObject(TypeTraitObject {
+            dyn_token: Some(Dyn::default()),
+            bounds,
+        });
+
+        Ok(wrap_type(dyn_type, type_rc))
+    }
+
+    /// Type of the argument of the builder method setting the instance, accompanied by a generic parameter of that method, if required
+    ///
+    /// Provider instance bindings accept `impl IntoProvider<T, Marker>`, i.e., providers as well as closures, bindings of a trait object `dyn Trait` accept `impl Trait + 'static`
+    pub(crate) fn setter_arg_ty(&self) -> ComponentResult<(Type, Option<GenericParam>)> {
+        let ty = self.ty()?;
+
+        if let Ok(type_trait_object) = ty.as_trait_object() {
+            let mut bounds = type_trait_object.bounds.clone();
+            if !bounds
+                .iter()
+                .any(|bound| matches!(bound, TypeParamBound::Lifetime(_)))
+            {
+                bounds.push(TypeParamBound::Lifetime(Lifetime {
+                    apostrophe: ty.span(),
+                    ident: Ident::new("static", ty.span()),
+                }));
+            }
+
+            let arg_ty = Type::ImplTrait(TypeImplTrait {
+                impl_token: Impl::default(),
+                bounds,
+            });
+            return Ok((arg_ty, None));
+        }
+
+        if !matches!(self, Self::ProviderInstance { .. }) {
+            return Ok((ty, None));
+        }
+
+        let span = ty.span();
+        let marker_ident = Ident::new("Marker", span);
+
+        let mut args = Punctuated::new();
+        args.push(GenericArgument::Type(ty));
+        args.push(GenericArgument::Type(Type::Path(TypePath {
+            qself: None,
+            path: Path::from(marker_ident.clone()),
+        })));
+        let arguments = PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+            colon2_token: None,
+            lt_token: Lt::default(),
+            args,
+            gt_token: Gt::default(),
+        });
+
+        let mut bounds = Punctuated::new();
+        bounds.push(TypeParamBound::Trait(TraitBound {
+            paren_token: None,
+            modifier: TraitBoundModifier::None,
+            lifetimes: None,
+            path: path_into_provider(arguments, span),
+        }));
+        let arg_ty = Type::ImplTrait(TypeImplTrait {
+            impl_token: Impl::default(),
+            bounds,
+        });
+
+        let marker_param = GenericParam::Type(TypeParam::from(marker_ident));
+
+        Ok((arg_ty, Some(marker_param)))
+    }
+
+    /// Converts the argument of the builder method setting the instance into a value of type [`Self::set_ty`]
+    pub(crate) fn get_set_value(&self, ident: &Ident) -> Expr {
+        let arg = Expr::Path(ExprPath {
+            attrs: Vec::new(),
+            qself: None,
+            path: Path::from(ident.clone()),
+        });
+
+        let Self::ProviderInstance { kw: _, ty } = self else {
+            return arg;
+        };
+
+        let mut args = Punctuated::new();
+        args.push(arg);
+        Expr::Call(ExprCall {
+            attrs: Vec::new(),
+            func: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: path_into_provider_into_provider(PathArguments::None, ty.span()),
+            })),
+            paren_token: Paren::default(),
+            args,
+        })
+    }
+
+    /// Name of the additional builder method of scoped, shared and arc instance bindings, setting an already existing instance of the wrapped type, e.g., `std::rc::Rc<std::cell::RefCell<T>>`
+    pub(crate) fn shared_setter_ident(&self, ident: &Ident) -> Option<Ident> {
+        match self {
+            Self::ScopedInstance { .. }
+            | Self::SharedInstance { .. }
+            | Self::ArcInstance { .. } => {
+                Some(Ident::new(&format!("{ident}_shared"), ident.span()))
+            }
+            Self::ClonedInstance { .. }
+            | Self::ProviderInstance { .. }
+            | Self::Env { .. }
+            | Self::Config { .. } => None,
+        }
+    }
+
+    /// Wraps the argument of the builder method, e.g., `std::rc::Rc::new(std::cell::RefCell::new(<ident>))`, before passing it on to its shared counterpart
+    ///
+    /// Trait objects are coerced when being passed on
+    pub(crate) fn get_shared_value(&self, ident: &Ident) -> Expr {
+        let span = ident.span();
+        let mut value = Expr::Path(ExprPath {
+            attrs: Vec::new(),
+            qself: None,
+            path: Path::from(ident.clone()),
+        });
+
+        let wrappers = match self {
+            Self::ScopedInstance { .. } => vec![
+                path_refcell_new(PathArguments::None, span),
+                path_rc_new(PathArguments::None, span),
+            ],
+            Self::SharedInstance { .. } => vec![path_rc_new(PathArguments::None, span)],
+            Self::ArcInstance { .. } => vec![path_arc_new(PathArguments::None, span)],
+            Self::ClonedInstance { .. }
+            | Self::ProviderInstance { .. }
+            | Self::Env { .. }
+            | Self::Config { .. } => Vec::new(),
+        };
+
+        for path in wrappers {
+            let mut args = Punctuated::new();
+            args.push(value);
+            value = Expr::Call(ExprCall {
+                attrs: Vec::new(),
+                func: Box::new(Expr::Path(ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path,
+                })),
+                paren_token: Paren::default(),
+                args,
+            });
+        }
+
+        value
+    }
+
+    /// Factory of an environment or configuration binding, which is created before all errors are reported at once, e.g., `dirk_framework::component::env_binds::EnvFactory::<T>::from_env("VAR", Some(default))`
+    pub(crate) fn get_fallible_factory(&self, ident: &Ident) -> Option<Expr> {
+        let (ty, path, args) = match self {
+            Self::Env {
+                kw: _,
+                ty,
+                variable,
+                default,
+            } => {
+                let span = ty.span();
+                let default = match default {
+                    Some(default) => {
+                        let mut args = Punctuated::new();
+                        args.push(default.clone());
+                        Expr::Call(ExprCall {
+                            attrs: Vec::new(),
+                            func: Box::new(Expr::Path(ExprPath {
+                                attrs: Vec::new(),
+                                qself: None,
+                                path: path_some(PathArguments::None, span),
+                            })),
+                            paren_token: Paren::default(),
+                            args,
+                        })
+                    }
+                    None => Expr::Path(ExprPath {
+                        attrs: Vec::new(),
+                        qself: None,
+                        path: path_none(PathArguments::None, span),
+                    }),
+                };
+
+                let mut args = Punctuated::new();
+                args.push(Expr::Lit(ExprLit {
+                    attrs: Vec::new(),
+                    lit: Lit::Str(variable.clone()),
+                }));
+                args.push(default);
+
+                (
+                    ty,
+                    path_env_factory_from_env(PathArguments::None, span),
+                    args,
+                )
+            }
+            // `ConfigFactory::<T>::from_config(&self.dirk_config.0, "<ident>", "<section>")`, as `build()` requires the configuration to be set
+            Self::Config { kw: _, ty, section } => {
+                let span = ty.span();
+                let config_of_self = Expr::Field(ExprField {
+                    attrs: Vec::new(),
+                    base: Box::new(Expr::Path(ExprPath {
+                        attrs: Vec::new(),
+                        qself: None,
+                        path: path_small_self(PathArguments::None, span),
+                    })),
+                    dot_token: Dot::default(),
+                    member: Member::Named(get_config_ident(span)),
+                });
+                let config = Expr::Reference(ExprReference {
+                    attrs: Vec::new(),
+                    and_token: And::default(),
+                    mutability: None,
+                    expr: Box::new(Expr::Field(ExprField {
+                        attrs: Vec::new(),
+                        base: Box::new(config_of_self),
+                        dot_token: Dot::default(),
+                        member: Member::Unnamed(Index::from(0)),
+                    })),
+                });
+                let section = section.clone().unwrap_or_else(|| LitStr::new("", span));
+
+                let mut args = Punctuated::new();
+                args.push(config);
+                args.push(Expr::Lit(ExprLit {
+                    attrs: Vec::new(),
+                    lit: Lit::Str(LitStr::new(&ident.to_string(), ident.span())),
+                }));
+                args.push(Expr::Lit(ExprLit {
+                    attrs: Vec::new(),
+                    lit: Lit::Str(section),
+                }));
+
+                (
+                    ty,
+                    path_config_factory_from_config(PathArguments::None, span),
+                    args,
+                )
+            }
+            _ => return None,
+        };
+
+        let mut path = path;
+        let position = path.segments.len() - 2;
+        let mut ty_args = Punctuated::new();
+        ty_args.push(GenericArgument::Type(ty.clone()));
+        path.segments[position].arguments =
+            PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                colon2_token: Some(PathSep::default()),
+                lt_token: Lt::default(),
+                args: ty_args,
+                gt_token: Gt::default(),
+            });
+
+        Some(Expr::Call(ExprCall {
+            attrs: Vec::new(),
+            func: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path,
+            })),
+            paren_token: Paren::default(),
+            args,
+        }))
+    }
+}
+
+/// `, default = <expr>`
+fn parse_default(input: ParseStream) -> syn::Result<Option<Expr>> {
+    if !input.peek(Comma) || !input.peek2(kw::default) {
+        return Ok(None);
+    }
+
+    input.parse::<Comma>()?;
+    input.parse::<kw::default>()?;
+    input.parse::<Eq>()?;
+    Ok(Some(input.parse()?))
+}
+
+/// `, path = "section"`
+fn parse_section(input: ParseStream) -> syn::Result<Option<LitStr>> {
+    if !input.peek(Comma) {
+        return Ok(None);
+    }
+
+    input.parse::<Comma>()?;
+    input.parse::<kw::path>()?;
+    input.parse::<Eq>()?;
+    Ok(Some(input.parse()?))
 }
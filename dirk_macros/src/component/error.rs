@@ -1,19 +1,25 @@
-use proc_macro_error::{abort, emit_error};
-use syn::{punctuated::Punctuated, token::Comma, Ident, Type, TypeImplTrait, WhereClause};
+use std::path::PathBuf;
+
+use proc_macro_error::{abort, abort_if_dirty, emit_error, Diagnostic, Level};
+use quote::ToTokens;
+use syn::{
+    punctuated::Punctuated, token::Comma, Ident, Signature, Type, TypeImplTrait, TypeTraitObject,
+    WhereClause,
+};
 
 use crate::{
     errors::ExpectableError,
     errors::{InfallibleError, SyntaxError},
 };
 
-use super::binding::BindingKind;
+use super::{binding::BindingKind, graph::GRAPH_DIR_ENV};
 
 pub(crate) type ComponentResult<T> = std::result::Result<T, ComponentError>;
 
 #[derive(Debug)]
 pub(crate) enum ComponentError {
     Infallible(InfallibleError<ComponentSyntaxError>),
-    Logic(ComponentLogicAbort),
+    Logic(Box<ComponentLogicAbort>),
 }
 
 impl_abort!(ComponentError);
@@ -45,25 +51,34 @@ pub(crate) enum ComponentLogicAbort {
     NotFound(Ident),
     TypeMismatch {
         fun_type: Type,
-        binding_kind: BindingKind,
+        binding_kind: Box<BindingKind>,
     },
     InvalidType(Type),
     ImplTraitBinding(TypeImplTrait),
+    TraitObjectBinding(TypeTraitObject),
     UnexpectedDependencies(Punctuated<Ident, Comma>),
     ContainsWhereClause(WhereClause),
+    InvalidMembersSignature(Box<Signature>),
+    NothingToMock(Ident),
 }
 
 impl From<ComponentLogicAbort> for ComponentError {
     fn from(value: ComponentLogicAbort) -> Self {
-        Self::Logic(value)
+        Self::Logic(Box::new(value))
     }
 }
 
 impl ComponentLogicAbort {
     fn abort(self) -> ! {
+        self.emit();
+        abort_if_dirty();
+        unreachable!("Emitting a logic error needs to mark the macro invocation as failed")
+    }
+
+    pub(crate) fn emit(self) {
         match self {
             ComponentLogicAbort::NotFound(binding) => {
-                abort!(binding, "Binding is not defined")
+                emit_error!(binding, "Binding is not defined");
             }
             ComponentLogicAbort::TypeMismatch {
                 fun_type,
@@ -74,32 +89,50 @@ impl ComponentLogicAbort {
                 match binding_kind.ty() {
                     Ok(ty) => {
                         emit_error!(ty, "Type of binding does not match... (1/2)"; hint=hint);
-                        abort!(fun_type, "...type specified here (2/2)")
+                        emit_error!(fun_type, "...type specified here (2/2)");
                     }
                     Err(e) => e.abort(),
                 }
             }
-            ComponentLogicAbort::InvalidType(ty) => abort!(ty, "Found invalid type"),
-            ComponentLogicAbort::ImplTraitBinding(impl_trait) => abort!(
+            ComponentLogicAbort::InvalidType(ty) => emit_error!(ty, "Found invalid type"),
+            ComponentLogicAbort::ImplTraitBinding(impl_trait) => emit_error!(
                 impl_trait,
                 "The type of a binding must not be an `impl <trait>`"
             ),
-            ComponentLogicAbort::UnexpectedDependencies(dependencies) => abort!(
+            ComponentLogicAbort::TraitObjectBinding(trait_object) => emit_error!(
+                trait_object,
+                "A trait object can only be bound by a shared, arc or scoped instance binding";
+                hint = "Try `shared_instance_bind({0})`, which is injected as `std::rc::Rc<{0}>`", trait_object.to_token_stream()
+            ),
+            ComponentLogicAbort::UnexpectedDependencies(dependencies) => emit_error!(
                 dependencies,
                 "A singleton binding cannot depend on any other bindings"
             ),
-            ComponentLogicAbort::ContainsWhereClause(where_clause) => abort!(
+            ComponentLogicAbort::ContainsWhereClause(where_clause) => emit_error!(
                 where_clause,
                 "Using a `where` clause on a trait annotated with #[component(...)] is not supported";
                 hint = "Try to specify bounds directly"
             ),
+            ComponentLogicAbort::InvalidMembersSignature(sig) => emit_error!(
+                sig,
+                "A function querying a members binding needs to take exactly one additional argument of type `&mut T` and must not return anything";
+                hint = "Try `fn inject_into(&self, target: &mut T);`"
+            ),
+            ComponentLogicAbort::NothingToMock(mockall) => emit_error!(
+                mockall,
+                "No binding of this component can be mocked";
+                hint = "Only `scoped_instance_bind(T)`s where `T: Trait` is a generic parameter of the component are mocked by `MockTrait`"
+            ),
         }
     }
 }
 
 pub(crate) enum ComponentLogicEmit {
     NotFound(Ident),
-    CycleDetected(Ident, Ident),
+    CycleDetected(Vec<(Ident, Ident)>),
+    MembersBindingAsDependency(Ident),
+    GraphExportFailed(Ident, PathBuf, std::io::Error),
+    GraphExportCollision(Ident, PathBuf, String),
 }
 
 impl ComponentLogicEmit {
@@ -108,14 +141,55 @@ impl ComponentLogicEmit {
             ComponentLogicEmit::NotFound(binding) => {
                 emit_error!(binding, "Binding is not defined");
             }
-            ComponentLogicEmit::CycleDetected(source, dependency) => {
+            ComponentLogicEmit::CycleDetected(cycle) => {
+                let Some((first, _)) = cycle.first() else {
+                    return;
+                };
+
+                let path = cycle
+                    .iter()
+                    .map(|(binding, _)| binding.to_string())
+                    .chain(std::iter::once(first.to_string()))
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+
+                // A single error on the first binding, with a note on each edge of the cycle
+                cycle
+                    .iter()
+                    .fold(
+                        Diagnostic::spanned(
+                            first.span(),
+                            Level::Error,
+                            format!("Cycle detected! This binding transitively depends on itself via `{path}`"),
+                        ),
+                        |diagnostic, (binding, dependency)| {
+                            diagnostic.span_note(
+                                dependency.span(),
+                                format!("`{binding}` depends on `{dependency}` here"),
+                            )
+                        },
+                    )
+                    .emit();
+            }
+            ComponentLogicEmit::MembersBindingAsDependency(dependency) => {
                 emit_error!(
-                    source,
-                    "Cycle detected! This binding transitively depends on itself... (1/2)"
+                    dependency,
+                    "A members binding cannot be used as a dependency";
+                    help = "members bindings only assign fields of an existing instance and do not provide anything"
                 );
+            }
+            ComponentLogicEmit::GraphExportFailed(component, dir, e) => {
                 emit_error!(
-                    dependency,
-                    "... via a cycle starting at this dependency (2/2)"
+                    component,
+                    "Failed to export dependency graph to {}: {}", dir.display(), e;
+                    hint = "The target directory may be changed by setting `{}`", GRAPH_DIR_ENV
+                );
+            }
+            ComponentLogicEmit::GraphExportCollision(component, path, location) => {
+                emit_error!(
+                    component,
+                    "Failed to export dependency graph to {}, as the component at {} is exported to the same file", path.display(), location;
+                    help = "Components declared in the same file need distinct names in order to export their dependency graphs"
                 );
             }
         }
@@ -0,0 +1,292 @@
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    path::{Component, Path, PathBuf},
+};
+
+use proc_macro2::{Ident, Span};
+
+use crate::syntax::type_to_string;
+
+use super::{binding::Binding, error::ComponentLogicEmit, syntax::sorted_bindings};
+
+/// Environment variable naming a directory the dependency graph of every component is written to
+pub(crate) const GRAPH_DIR_ENV: &str = "DIRK_GRAPH_DIR";
+
+pub(crate) struct DependencyGraph {
+    component: String,
+    nodes: Vec<DependencyNode>,
+}
+
+struct DependencyNode {
+    name: String,
+    kind: &'static str,
+    ty: String,
+    dependencies: Vec<String>,
+}
+
+impl DependencyGraph {
+    pub(crate) fn new(component: &Ident, bindings: &HashMap<&Ident, &Binding>) -> Self {
+        let nodes = sorted_bindings(bindings)
+            .into_iter()
+            .map(|(ident, binding)| {
+                let kind = binding.kind();
+
+                let ty = kind.ty().map(|ty| type_to_string(&ty)).unwrap_or_default();
+
+                let dependencies = kind
+                    .dependencies()
+                    .map(|dependencies| dependencies.iter().map(ToString::to_string).collect())
+                    .unwrap_or_default();
+
+                DependencyNode {
+                    name: ident.to_string(),
+                    kind: kind.kind_name(),
+                    ty,
+                    dependencies,
+                }
+            })
+            .collect();
+
+        Self {
+            component: component.to_string(),
+            nodes,
+        }
+    }
+
+    pub(crate) fn to_dot(&self) -> String {
+        let mut res = String::new();
+
+        let _ = writeln!(res, "digraph {} {{", self.component);
+        let _ = writeln!(res, "    rankdir=LR;");
+        for node in &self.nodes {
+            let _ = writeln!(
+                res,
+                "    \"{}\" [label=\"{}\\n{}: {}\"];",
+                node.name,
+                node.name,
+                node.kind,
+                escape_dot(&node.ty)
+            );
+        }
+        for node in &self.nodes {
+            for dependency in &node.dependencies {
+                let _ = writeln!(res, "    \"{}\" -> \"{}\";", node.name, dependency);
+            }
+        }
+        res.push_str("}\n");
+
+        res
+    }
+
+    pub(crate) fn to_mermaid(&self) -> String {
+        let mut res = String::new();
+
+        let _ = writeln!(res, "graph LR");
+        for node in &self.nodes {
+            let _ = writeln!(
+                res,
+                "    {}[\"{}<br/>{}: {}\"]",
+                node.name,
+                node.name,
+                node.kind,
+                escape_mermaid(&node.ty)
+            );
+        }
+        for node in &self.nodes {
+            for dependency in &node.dependencies {
+                let _ = writeln!(res, "    {} --> {}", node.name, dependency);
+            }
+        }
+
+        res
+    }
+
+    pub(crate) fn to_json(&self) -> String {
+        self.json(None)
+    }
+
+    /// The graph as JSON, for an exported graph along with the location of the component
+    fn json(&self, location: Option<&str>) -> String {
+        let mut res = String::new();
+
+        res.push_str("{\n");
+        let _ = writeln!(
+            res,
+            "  \"component\": \"{}\",",
+            escape_json(&self.component)
+        );
+        if let Some(location) = location {
+            let _ = writeln!(res, "  \"location\": \"{}\",", escape_json(location));
+        }
+        res.push_str("  \"bindings\": [");
+        for (index, node) in self.nodes.iter().enumerate() {
+            if index > 0 {
+                res.push(',');
+            }
+            let dependencies = node
+                .dependencies
+                .iter()
+                .map(|d| format!("\"{}\"", escape_json(d)))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            res.push_str("\n    {\n");
+            let _ = writeln!(res, "      \"name\": \"{}\",", escape_json(&node.name));
+            let _ = writeln!(res, "      \"kind\": \"{}\",", node.kind);
+            let _ = writeln!(res, "      \"type\": \"{}\",", escape_json(&node.ty));
+            let _ = writeln!(res, "      \"dependencies\": [{dependencies}]");
+            res.push_str("    }");
+        }
+        if !self.nodes.is_empty() {
+            res.push_str("\n  ");
+        }
+        res.push_str("]\n}\n");
+
+        res
+    }
+
+    /// Writes the graph to `$DIRK_GRAPH_DIR` or, if not set, to `$OUT_DIR/dirk`, if any of them is available
+    ///
+    /// The files are named `<crate>.<module>.<component>`, such that components of the same name in different crates or files do not overwrite each other.
+    /// The exported JSON records the location of the component, a component of the same name still declared at the location found in an existing file is reported as a collision.
+    pub(crate) fn export(&self, span_ident: &Ident) {
+        let dir = std::env::var_os(GRAPH_DIR_ENV)
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("OUT_DIR").map(|out| PathBuf::from(out).join("dirk")));
+
+        let Some(dir) = dir else {
+            return;
+        };
+
+        let mut name = std::env::var("CARGO_CRATE_NAME")
+            .into_iter()
+            .chain(module_path(span_ident.span()))
+            .collect::<Vec<_>>();
+        name.push(self.component.clone());
+
+        let name = name.join(".");
+        let base = dir.join(&name);
+
+        let span = span_ident.span().unwrap();
+        let location = format!("{}:{}:{}", span.file(), span.line(), span.column());
+        let previous = std::fs::read_to_string(dir.join(format!("{name}.json")))
+            .ok()
+            .and_then(|json| exported_location(&json))
+            .filter(|previous| *previous != location && declares(previous, span_ident));
+        if let Some(previous) = previous {
+            ComponentLogicEmit::GraphExportCollision(span_ident.clone(), base, previous).emit();
+            return;
+        }
+
+        let res = std::fs::create_dir_all(&dir).and_then(|()| {
+            // Not via `with_extension`, which would replace the component as the last part of the name
+            let path = |extension: &str| dir.join(format!("{name}.{extension}"));
+            std::fs::write(path("dot"), self.to_dot())?;
+            std::fs::write(path("mmd"), self.to_mermaid())?;
+            std::fs::write(path("json"), self.json(Some(&location)))
+        });
+
+        if let Err(e) = res {
+            ComponentLogicEmit::GraphExportFailed(span_ident.clone(), dir, e).emit();
+        }
+    }
+}
+
+/// The location of the component recorded in an exported graph, see [`DependencyGraph::export`]
+fn exported_location(json: &str) -> Option<String> {
+    json.lines()
+        .find_map(|line| line.strip_prefix("  \"location\": \""))
+        .and_then(|line| line.strip_suffix("\","))
+        .map(unescape_json)
+}
+
+/// Whether an identifier equal to `ident` is still declared at `location`, i.e., `<file>:<line>:<column>`, as opposed to a location outdated by changes to the file
+fn declares(location: &str, ident: &Ident) -> bool {
+    let mut parts = location.rsplitn(3, ':');
+    let (Some(column), Some(line), Some(file)) = (parts.next(), parts.next(), parts.next()) else {
+        return false;
+    };
+    let (Ok(column), Ok(line)) = (column.parse::<usize>(), line.parse::<usize>()) else {
+        return false;
+    };
+
+    let mut file = PathBuf::from(file);
+    if file.is_relative() {
+        if let Some(manifest_dir) = std::env::var_os("CARGO_MANIFEST_DIR") {
+            file = Path::new(&manifest_dir).join(file);
+        }
+    }
+    let Ok(source) = std::fs::read_to_string(file) else {
+        return false;
+    };
+
+    let Some(declared) = source.lines().nth(line.saturating_sub(1)) else {
+        return false;
+    };
+    let declared = declared
+        .chars()
+        .skip(column.saturating_sub(1))
+        .take_while(|c| c.is_alphanumeric() || *c == '_');
+
+    ident.to_string().chars().eq(declared)
+}
+
+/// Path of the module declared by the file containing `span`, relative to the crate root, e.g., `["services", "shop"]` for `src/services/shop.rs`
+///
+/// Inline modules are not taken into account.
+fn module_path(span: Span) -> Vec<String> {
+    let Some(file) = span.unwrap().local_file() else {
+        return Vec::new();
+    };
+
+    let file = std::fs::canonicalize(&file).unwrap_or(file);
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .map(|dir| std::fs::canonicalize(&dir).unwrap_or(dir));
+    let Some(relative) =
+        manifest_dir.and_then(|dir| file.strip_prefix(dir).ok().map(PathBuf::from))
+    else {
+        return Vec::new();
+    };
+
+    let mut segments = relative
+        .with_extension("")
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(segment) => Some(segment.to_string_lossy().into_owned()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if segments.first().is_some_and(|segment| segment == "src") {
+        segments.remove(0);
+    }
+    if segments
+        .last()
+        .is_some_and(|segment| matches!(segment.as_str(), "lib" | "main" | "mod"))
+    {
+        segments.pop();
+    }
+
+    segments
+}
+
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(value: &str) -> String {
+    value
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+}
+
+fn escape_json(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn unescape_json(value: &str) -> String {
+    value.replace("\\\"", "\"").replace("\\\\", "\\")
+}
@@ -1,14 +1,17 @@
 use proc_macro::TokenStream;
 
-use proc_macro2::Span;
+use proc_macro2::{Ident, Span};
 use quote::{quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     token::Comma,
+    Path, Token,
 };
 
-use crate::{errors::InfallibleResult, parse::ExtensionParseBufferWithContext};
+use crate::{
+    errors::InfallibleResult, parse::ExtensionParseBufferWithContext, util::with_framework_path,
+};
 
 use self::{
     binding::Binding,
@@ -17,8 +20,9 @@ use self::{
 };
 
 pub(crate) mod error;
+mod graph;
 pub(crate) mod processor;
-mod syntax;
+pub(crate) mod syntax;
 
 mod binding;
 
@@ -27,8 +31,9 @@ pub(crate) fn _macro(
 ) -> InfallibleResult<TokenStream, ComponentSyntaxError> {
     let data = data;
     let processor = InfallibleComponentMacroProcessor::new(&data);
+    let framework = data.framework_path()?;
 
-    processor.process().map(|items| {
+    with_framework_path(framework, || processor.process()).map(|items| {
         let expanded = quote! { #(#items)* };
         TokenStream::from(expanded)
     })
@@ -37,8 +42,9 @@ pub(crate) fn _macro(
 pub(crate) fn _macro_helper(data: ComponentMacroData) -> ComponentResult<TokenStream> {
     let data = data;
     let processor = ComponentMacroProcessor::new(&data);
+    let framework = data.framework_path()?;
 
-    processor.process().map(|items| {
+    with_framework_path(framework, || processor.process()).map(|items| {
         let expanded = quote! { #(#items)* };
         TokenStream::from(expanded)
     })
@@ -46,14 +52,85 @@ pub(crate) fn _macro_helper(data: ComponentMacroData) -> ComponentResult<TokenSt
 
 mod kw {
     syn::custom_keyword!(__inner);
+    syn::custom_keyword!(name);
+    syn::custom_keyword!(builder);
+    syn::custom_keyword!(mockall);
 }
 
 #[derive(Debug)]
 struct ComponentMacroInput {
+    options: ComponentMacroOptions,
     bindings: Punctuated<Binding, Comma>,
     inner: Option<(kw::__inner, Comma)>,
 }
 
+/// Options preceding the bindings, e.g., `name = AppContainer, builder = AppContainerBuilder`
+#[derive(Debug, Default)]
+struct ComponentMacroOptions {
+    name: Option<Ident>,
+    builder: Option<Ident>,
+    /// Opts into generating mocks for generic scoped instance bindings, e.g., `mockall`
+    mockall: Option<Ident>,
+    /// Path of the framework, e.g., `crate = platform::di`
+    krate: Option<Path>,
+}
+
+impl Parse for ComponentMacroOptions {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut options = Self::default();
+
+        // Options are distinguished from bindings by `=` instead of `:`, flags by not being followed by `:`
+        loop {
+            if input.peek(kw::mockall) && !input.peek2(Token![:]) {
+                let span = input.parse::<kw::mockall>()?.span;
+                if options.mockall.is_some() {
+                    return Err(syn::Error::new(span, "duplicate option"));
+                }
+                options.mockall = Some(Ident::new("mockall", span));
+
+                if !input.is_empty() {
+                    input.parse::<Comma>()?;
+                }
+                continue;
+            }
+
+            if !input.peek2(Token![=]) {
+                break;
+            }
+
+            let lookahead = input.lookahead1();
+            if lookahead.peek(Token![crate]) {
+                let span = input.parse::<Token![crate]>()?.span;
+                if options.krate.is_some() {
+                    return Err(syn::Error::new(span, "duplicate option"));
+                }
+                input.parse::<Token![=]>()?;
+                options.krate = Some(input.call(Path::parse_mod_style)?);
+            } else {
+                let (option, span) = if lookahead.peek(kw::name) {
+                    (&mut options.name, input.parse::<kw::name>()?.span)
+                } else if lookahead.peek(kw::builder) {
+                    (&mut options.builder, input.parse::<kw::builder>()?.span)
+                } else {
+                    return Err(lookahead.error());
+                };
+
+                if option.is_some() {
+                    return Err(syn::Error::new(span, "duplicate option"));
+                }
+                input.parse::<Token![=]>()?;
+                *option = Some(input.parse()?);
+            }
+
+            if !input.is_empty() {
+                input.parse::<Comma>()?;
+            }
+        }
+
+        Ok(options)
+    }
+}
+
 impl Parse for ComponentMacroInput {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let inner = input
@@ -62,6 +139,8 @@ impl Parse for ComponentMacroInput {
             .map(|r| r.and_then(|kw| input.parse::<Comma>().map(|comma| (kw, comma))))
             .transpose()?;
 
+        let options = input.parse()?;
+
         let bindings = {
             let mut punctuated = Punctuated::new();
 
@@ -82,7 +161,11 @@ impl Parse for ComponentMacroInput {
 
             punctuated
         };
-        let res = ComponentMacroInput { bindings, inner };
+        let res = ComponentMacroInput {
+            options,
+            bindings,
+            inner,
+        };
 
         Ok(res)
     }
@@ -5,20 +5,25 @@ use itertools::Itertools;
 use proc_macro::TokenStream;
 
 use proc_macro2::Span;
+use proc_macro_error::set_dummy;
+use quote::{quote, ToTokens};
 
 use syn::{
     parse,
     punctuated::Punctuated,
     spanned::Spanned,
     token::{
-        Brace, Bracket, Colon, Comma, Dot, Eq, For, Gt, Impl, Let, Lt, Paren, Pound, RArrow,
-        SelfValue, Semi, Struct,
+        And, Brace, Bracket, Colon, Comma, Const, Dot, Eq, For, Gt, Impl, Let, Lt, Mut, Paren,
+        Pound, Pub, Question, RArrow, SelfValue, Semi, Struct, Where,
     },
-    AngleBracketedGenericArguments, Attribute, Block, Expr, ExprCall, ExprField, ExprPath,
-    ExprStruct, Field, FieldValue, Fields, FieldsNamed, FnArg, GenericArgument, GenericParam,
-    Generics, Ident, ImplItem, ImplItemFn, Item, ItemImpl, ItemStruct, ItemTrait, Local, LocalInit,
-    Member, Meta, MetaList, Pat, PatIdent, PatTupleStruct, PatType, Path, PathArguments,
-    PathSegment, Receiver, ReturnType, Stmt, TraitBound, Type, TypeParam, TypeParamBound, TypePath,
+    AngleBracketedGenericArguments, Attribute, Block, Expr, ExprArray, ExprCall, ExprField,
+    ExprLit, ExprMethodCall, ExprPath, ExprStruct, ExprTry, ExprTuple, Field, FieldValue, Fields,
+    FieldsNamed, FnArg, GenericArgument, GenericParam, Generics, Ident, ImplItem, ImplItemConst,
+    ImplItemFn, Item, ItemImpl, ItemStruct, ItemTrait, ItemType, Lifetime, Lit, LitStr, Local,
+    LocalInit, Member, Meta, MetaList, Pat, PatIdent, PatTuple, PatTupleStruct, PatType, Path,
+    PathArguments, PathSegment, PredicateType, Receiver, ReturnType, Signature, Stmt, TraitBound,
+    TraitItem, TraitItemFn, Type, TypeImplTrait, TypeParam, TypeParamBound, TypePath,
+    TypeReference, TypeTuple, Visibility, WhereClause, WherePredicate,
 };
 
 use crate::{
@@ -27,18 +32,27 @@ use crate::{
     expectable::{
         GenericParamExpectable, ReturnTypeExpectable, TraitItemExpectable, TypeExpectable,
     },
-    syntax::{mk_fn, wrap_path},
+    lint::{lint, mk_attribute, track_env, LintLevel, LINTS_ENV},
+    syntax::{collect_idents, mk_fn, wrap_path, wrap_type},
     util::{
-        path_builder, path_component, path_input_status, path_self, path_set,
-        path_static_component, path_unset, path_unset_builder, type_set, type_unset,
+        path_builder, path_builder_check, path_builder_unwrap_built, path_component,
+        path_component_attribute, path_debug, path_input_status, path_into, path_into_into,
+        path_introspect, path_introspect_fmt_bindings, path_ok, path_rc_new, path_refcell_new,
+        path_self, path_set, path_small_self, path_static_component, path_unset,
+        path_unset_builder, path_vec_from, type_binding_descriptor, type_build_error, type_config,
+        type_fmt_result, type_formatter, type_no_overrides, type_overrides, type_rc, type_refcell,
+        type_result, type_set, type_unset, type_vec,
     },
 };
 
 use super::{
     binding::{bindable::Bindable, manual::ManualBindingKind, Binding},
-    error::{ComponentResult, ComponentSyntaxError},
+    error::{ComponentError, ComponentResult, ComponentSyntaxError},
+    graph::{DependencyGraph, GRAPH_DIR_ENV},
     syntax::{
-        generic_argument_from_generic_param, get_dirk_name, get_provider_call, get_providers,
+        generic_argument_from_generic_param, get_binding_descriptor, get_config_ident,
+        get_dirk_name, get_members_injector_call, get_overrides_ident, get_overrides_ty,
+        get_overrides_ty_ident, get_provider_call, get_providers, get_stub_block, sorted_bindings,
     },
     ComponentMacroInput,
 };
@@ -73,6 +87,10 @@ impl ComponentMacroData {
         Ok(self.input_macro()?.inner.is_some())
     }
 
+    pub(crate) fn framework_path(&self) -> InfallibleResult<Option<&Path>, ComponentSyntaxError> {
+        Ok(self.input_macro()?.options.krate.as_ref())
+    }
+
     fn helper_attribute(&self) -> &Attribute {
         if let Some(cached) = self.helper_attribute.get() {
             return cached;
@@ -81,14 +99,7 @@ impl ComponentMacroData {
         let attr = {
             let attr = self.attr.clone();
 
-            let mut segments = Punctuated::new();
-            segments.push(Ident::new("dirk_framework", Span::call_site()).into());
-            segments.push(Ident::new("component", Span::call_site()).into());
-
-            let path = Path {
-                leading_colon: None,
-                segments,
-            };
+            let path = path_component_attribute(PathArguments::None, Span::call_site());
 
             let mut tokens: proc_macro2::TokenStream = ComponentMacroInput::inner_marker();
             tokens.extend(std::iter::once(std::convert::Into::<
@@ -179,10 +190,9 @@ impl<'data> InfallibleComponentMacroProcessor<'data> {
             return Ok(cached);
         }
 
-        let dirk_ident = {
-            let trait_ident = self.trait_ident()?;
-
-            get_dirk_name(trait_ident, None)
+        let dirk_ident = match &self.data.input_macro()?.options.name {
+            Some(name) => name.clone(),
+            None => get_dirk_name(self.trait_ident()?),
         };
 
         Ok(self.dirk_ident.get_or_init(|| dirk_ident))
@@ -281,14 +291,27 @@ impl<'data> ComponentMacroProcessor<'data> {
         Ok(dirk_ty)
     }
 
+    /// `DirkXBuilder`, unless named by the user
+    fn builder_ident(&self) -> ComponentResult<Ident> {
+        if let Some(builder) = &self.data.input_macro()?.options.builder {
+            return Ok(builder.clone());
+        }
+
+        let dirk_ident = self.delegate.dirk_ident()?;
+        Ok(Ident::new(
+            &format!("{dirk_ident}Builder"),
+            dirk_ident.span(),
+        ))
+    }
+
     fn impl_ident(&self) -> ComponentResult<&Ident> {
         if let Some(cached) = self.impl_ident.get() {
             return Ok(cached);
         }
 
         let impl_ident = {
-            let trait_ident = self.trait_ident()?;
-            get_dirk_name(trait_ident, Some("Impl"))
+            let dirk_ident = self.delegate.dirk_ident()?;
+            Ident::new(&format!("{dirk_ident}Impl"), dirk_ident.span())
         };
 
         Ok(self.impl_ident.get_or_init(|| impl_ident))
@@ -375,13 +398,15 @@ impl<'data> ComponentMacroProcessor<'data> {
 
                 funs.map(|fun| {
                     let name = &fun.sig.ident;
-                    let ty = &fun.sig.output.as_type()?;
 
                     bindings
                         .get(name)
                         .ok_or_else(|| ComponentLogicAbort::NotFound(name.clone()))
                         .map_err(Into::into)
-                        .and_then(|binding| binding.kind().compare_types(ty.1))
+                        .and_then(|binding| {
+                            let ty = Self::function_ty(fun, binding)?;
+                            binding.kind().compare_types(ty)
+                        })
                         .map(std::iter::IntoIterator::into_iter)
                 })
                 .collect::<ComponentResult<Vec<_>>>()?
@@ -555,7 +580,7 @@ impl<'data> ComponentMacroProcessor<'data> {
         Ok(generic_args_unbound)
     }
 
-    fn functions(&self) -> ComponentResult<Vec<ImplItem>> {
+    fn functions(&self) -> ComponentResult<(Vec<ImplItem>, Vec<Ident>)> {
         let functions = {
             let input_trait = self.data.input_trait()?;
             let bindings = self.bindings()?;
@@ -567,6 +592,7 @@ impl<'data> ComponentMacroProcessor<'data> {
                 .collect::<ComponentResult<Vec<_>>>()?;
 
             let mut res = Vec::new();
+            let mut stubbed = Vec::new();
 
             for function in fns {
                 let ident = &function.sig.ident;
@@ -574,41 +600,41 @@ impl<'data> ComponentMacroProcessor<'data> {
                     .get(&function.sig.ident)
                     .ok_or_else(|| ComponentLogicAbort::NotFound(ident.clone()))?;
 
-                // Replace return type
-                let ty_before = &function.sig.output;
-                let ty_after =
-                    ReturnType::Type(RArrow::default(), Box::new(binding.kind().wrapped_ty()?));
-
-                // Check if types match
-                {
-                    let mut path_before = ty_before.as_type()?.1.as_path()?.path.segments.clone();
-                    let mut path_after = ty_after.as_type()?.1.as_path()?.path.segments.clone();
-                    let span_before = path_before.span();
-                    path_before
-                        .last_mut()
-                        .ok_or_else(|| InfallibleError::EmptyPath(span_before))?
-                        .arguments = PathArguments::None;
-                    let span_after = path_after.span();
-                    path_after
-                        .last_mut()
-                        .ok_or_else(|| InfallibleError::EmptyPath(span_after))?
-                        .arguments = PathArguments::None;
-
-                    if path_before.last() != path_after.last() {
-                        Err(ComponentLogicAbort::TypeMismatch {
-                            fun_type: ty_before.as_type()?.1.clone(),
-                            binding_kind: (*binding).kind().clone(),
-                        })?;
-                    }
+                let ty_before = Self::function_ty(function, binding)?;
+                let ty_after = binding.kind().wrapped_ty()?;
+
+                // Types not matching have already been reported, the trait provides a stub instead
+                if !Self::matches_function_ty(ty_before, binding)? {
+                    stubbed.push(ident.clone());
+                    continue;
                 }
 
-                // Add call to self.*provider.get()
-                let call = get_provider_call(ident);
+                let (call, sig) = if binding.kind().is_members() {
+                    // Add call to self.*provider.inject_members(target)
+                    let target_ident = Ident::new("target", ident.span());
+                    let call = get_members_injector_call(ident, &target_ident);
+
+                    let mut sig = function.sig.clone();
+                    if let Some(FnArg::Typed(pat_type)) = sig.inputs.iter_mut().nth(1) {
+                        let pat_ident = PatIdent {
+                            attrs: Vec::new(),
+                            by_ref: None,
+                            mutability: None,
+                            ident: target_ident,
+                            subpat: None,
+                        };
+                        *pat_type.pat = Pat::Ident(pat_ident);
+                    }
+
+                    (call, sig)
+                } else {
+                    // Add call to self.*provider.get() and replace return type
+                    let call = get_provider_call(ident);
 
-                let sig = {
                     let mut sig = function.sig.clone();
-                    sig.output = ty_after;
-                    sig
+                    sig.output = ReturnType::Type(RArrow::default(), Box::new(ty_after));
+
+                    (call, sig)
                 };
 
                 let stmt = syn::Stmt::Expr(call, None);
@@ -631,287 +657,1831 @@ impl<'data> ComponentMacroProcessor<'data> {
                 res.push(impl_item);
             }
 
-            res
+            (res, stubbed)
         };
 
         Ok(functions)
     }
 
-    fn builder_kind(&self) -> ComponentResult<ComponentBuilderKind> {
-        let builder_data = ComponentBuilderData::new(self.bindings()?, self.trait_ident()?);
-        ComponentBuilderKind::evaluate(&builder_data, self)
+    fn matches_function_ty(ty_before: &Type, binding: &Binding) -> ComponentResult<bool> {
+        let ty_after = binding.kind().wrapped_ty()?;
+
+        let mut path_before = ty_before.as_path()?.path.segments.clone();
+        let mut path_after = ty_after.as_path()?.path.segments.clone();
+        let span_before = path_before.span();
+        path_before
+            .last_mut()
+            .ok_or_else(|| InfallibleError::EmptyPath(span_before))?
+            .arguments = PathArguments::None;
+        let span_after = path_after.span();
+        path_after
+            .last_mut()
+            .ok_or_else(|| InfallibleError::EmptyPath(span_after))?
+            .arguments = PathArguments::None;
+
+        Ok(path_before.last() == path_after.last())
     }
 
-    pub(crate) fn process(self) -> ComponentResult<Vec<Item>> {
+    /// Emits every logic error found in the bindings or the functions of the trait, returns whether none was found
+    fn validate(&self) -> ComponentResult<bool> {
+        let input_macro = self.data.input_macro()?;
+        let input_trait = self.data.input_trait()?;
         let bindings = self.bindings()?;
 
-        let impl_ident = self.impl_ident()?;
-        let impl_ty = self.impl_ty()?;
-
-        let trait_path = self.trait_path()?;
-
-        let functions = self.functions()?;
-
-        let generics_unbound_formal = self.generics_unbound()?;
+        let mut errors = Vec::new();
 
-        let (providers_signature, providers_actual, providers_formal, providers_instantiation) =
-            get_providers(bindings)?;
+        if let Some(where_clause) = &input_trait.generics.where_clause {
+            errors.push(ComponentLogicAbort::ContainsWhereClause(
+                where_clause.clone(),
+            ));
+        }
 
-        let items = {
-            let input_trait = self.data.input_trait()?.clone();
-            let trait_visibility = input_trait.vis.clone();
+        let mut invalid_bindings = Vec::new();
+        for binding in &input_macro.bindings {
+            if let Err(e) = binding.kind().ty() {
+                invalid_bindings.push(binding.identifier());
+                match e {
+                    ComponentError::Logic(e) => errors.push(*e),
+                    e @ ComponentError::Infallible(_) => return Err(e),
+                }
+            }
+        }
 
-            let fields_named = FieldsNamed {
-                brace_token: Brace::default(),
-                named: providers_signature,
-            };
+        for function in &input_trait.items {
+            let function = function.as_fn()?;
+            let ident = &function.sig.ident;
 
-            let struct_impl = ItemStruct {
-                attrs: Vec::new(),
-                vis: trait_visibility,
-                struct_token: Struct::default(),
-                ident: impl_ident.clone(),
-                generics: generics_unbound_formal.clone(),
-                fields: syn::Fields::Named(fields_named),
-                semi_token: None,
+            let Some(binding) = bindings.get(ident) else {
+                errors.push(ComponentLogicAbort::NotFound(ident.clone()));
+                continue;
             };
 
-            let impl_impl = {
-                let span = trait_path.span();
+            // Errors regarding the binding itself have already been collected
+            if invalid_bindings.contains(&binding.identifier()) {
+                continue;
+            }
 
-                let self_path = path_self(PathArguments::None, span);
-                let type_path = TypePath {
-                    qself: None,
-                    path: self_path.clone(),
-                };
-                let self_ty = Type::Path(type_path);
+            let res = Self::function_ty(function, binding).and_then(|ty| {
+                binding.kind().compare_types(ty)?;
 
-                let mut stmts = providers_instantiation;
-                let expr_struct = ExprStruct {
-                    attrs: Vec::new(),
-                    qself: None,
-                    path: self_path,
-                    brace_token: Brace::default(),
-                    fields: providers_actual,
-                    dot2_token: None,
-                    rest: None,
-                };
-                let self_struct = Expr::Struct(expr_struct);
-                stmts.push(Stmt::Expr(self_struct, None));
+                if !Self::matches_function_ty(ty, binding)? {
+                    Err(ComponentLogicAbort::TypeMismatch {
+                        fun_type: ty.clone(),
+                        binding_kind: Box::new(binding.kind().clone()),
+                    })?;
+                }
 
-                let block = Block {
-                    brace_token: Brace::default(),
-                    stmts,
-                };
+                Ok(())
+            });
 
-                let new_fn = mk_fn(
-                    Ident::new("new", span),
-                    syn::Visibility::Inherited,
-                    Generics::default(),
-                    providers_formal,
-                    self_ty,
-                    block,
-                );
+            match res {
+                Ok(()) => {}
+                Err(ComponentError::Logic(e)) => errors.push(*e),
+                Err(e @ ComponentError::Infallible(_)) => return Err(e),
+            }
+        }
 
-                ItemImpl {
-                    attrs: Vec::new(),
-                    defaultness: None,
-                    unsafety: None,
-                    impl_token: Impl::default(),
-                    generics: generics_unbound_formal.clone(),
-                    trait_: None,
-                    self_ty: Box::new(impl_ty.clone()),
-                    brace_token: Brace::default(),
-                    items: vec![new_fn],
-                }
-            };
+        if let Some(mockall) = &input_macro.options.mockall {
+            if errors.is_empty() && self.mocked_binds()?.is_empty() {
+                errors.push(ComponentLogicAbort::NothingToMock(mockall.clone()));
+            }
+        }
 
-            let trait_impl = ItemImpl {
-                attrs: Vec::new(),
-                defaultness: None,
-                unsafety: None,
-                impl_token: Impl::default(),
-                generics: generics_unbound_formal.clone(),
-                trait_: Some((None, trait_path, For::default())),
-                self_ty: Box::new(impl_ty.clone()),
-                brace_token: Brace::default(),
-                items: functions,
-            };
+        let valid = errors.is_empty();
+        errors.into_iter().for_each(ComponentLogicAbort::emit);
 
-            let builder = self.builder_kind()?;
+        Ok(valid)
+    }
 
-            let mut items = Vec::new();
+    fn function_ty<'f>(function: &'f TraitItemFn, binding: &Binding) -> ComponentResult<&'f Type> {
+        if !binding.kind().is_members() {
+            return Ok(function.sig.output.as_type()?.1);
+        }
 
-            match builder {
-                ComponentBuilderKind::StaticBuilder {
-                    struct_builder,
-                    impl_unset,
-                    impl_builder_set,
-                    impl_static_builder,
-                    impl_component,
-                    impl_static_component,
-                } => {
-                    items.push(Item::Struct(struct_builder));
-                    items.push(Item::Impl(impl_unset));
-                    items.push(Item::Impl(impl_builder_set));
-                    items.push(Item::Impl(impl_static_builder));
-                    items.push(Item::Impl(impl_component));
-                    items.push(Item::Impl(impl_static_component));
-                }
-                ComponentBuilderKind::NonStaticBuilder {
-                    struct_builder,
-                    impl_unset,
-                    impl_builder_unset,
-                    impl_builder_set,
-                    partial_impls,
-                    impl_static_builder,
-                    impl_component,
-                } => {
-                    items.push(Item::Struct(struct_builder));
-                    items.push(Item::Impl(impl_unset));
-                    items.push(Item::Impl(impl_builder_unset));
-                    items.push(Item::Impl(impl_builder_set));
-                    items.extend(partial_impls.into_iter().map(Item::Impl));
-                    items.push(Item::Impl(impl_static_builder));
-                    items.push(Item::Impl(impl_component));
-                }
+        // members bindings are queried via `fn(&self, target: &mut T)`
+        let maybe_target = match (&function.sig.output, function.sig.inputs.iter().nth(1)) {
+            (ReturnType::Default, Some(FnArg::Typed(pat_type)))
+                if function.sig.inputs.len() == 2 =>
+            {
+                pat_type
+                    .ty
+                    .as_reference()
+                    .ok()
+                    .filter(|reference| reference.mutability.is_some())
             }
-
-            items.push(Item::Struct(struct_impl));
-            items.push(Item::Impl(impl_impl));
-            items.push(Item::Impl(trait_impl));
-            items.push(Item::Trait(input_trait));
-
-            items
+            _ => None,
         };
 
-        Ok(items)
+        maybe_target
+            .map(|reference| reference.elem.as_ref())
+            .ok_or_else(|| {
+                ComponentLogicAbort::InvalidMembersSignature(Box::new(function.sig.clone())).into()
+            })
     }
-}
-
-enum ComponentBuilderKind {
-    StaticBuilder {
-        struct_builder: ItemStruct,
-        impl_unset: ItemImpl,
-        impl_builder_set: ItemImpl,
-        impl_static_builder: ItemImpl,
-        impl_component: ItemImpl,
-        impl_static_component: ItemImpl,
-    },
-    NonStaticBuilder {
-        struct_builder: ItemStruct,
-        impl_unset: ItemImpl,
-        impl_builder_unset: ItemImpl,
-        impl_builder_set: ItemImpl,
-        partial_impls: Vec<ItemImpl>,
-        impl_static_builder: ItemImpl,
-        impl_component: ItemImpl,
-    },
-}
-
-impl ComponentBuilderKind {
-    fn evaluate(
-        builder_data: &ComponentBuilderData,
-        data: &ComponentMacroProcessor,
-    ) -> ComponentResult<Self> {
-        let input_trait = data.data.input_trait()?;
-        let trait_visibility = &input_trait.vis;
 
-        let dirk_ty = data.dirk_ty()?;
+    fn impl_introspect(&self) -> ComponentResult<ItemImpl> {
+        let impl_ident = self.impl_ident()?;
+        let impl_ty = self.impl_ty()?;
+        let bindings = self.bindings()?;
+        let generics_unbound_formal = self.generics_unbound()?;
 
-        let impl_path = data.impl_path()?;
-        let impl_ty = data.impl_ty()?;
+        let span = impl_ident.span();
 
-        let generics_unbound_formal = data.generics_unbound()?;
-        let unbound_generics_mapping = data.unbound_generics()?;
+        let self_ref = |span| {
+            FnArg::Receiver(Receiver {
+                attrs: Vec::new(),
+                reference: Some((And::default(), None)),
+                mutability: None,
+                self_token: SelfValue::default(),
+                colon_token: None,
+                ty: Box::new(Type::Reference(TypeReference {
+                    and_token: And::default(),
+                    lifetime: None,
+                    mutability: None,
+                    elem: Box::new(Type::Path(TypePath {
+                        qself: None,
+                        path: path_self(PathArguments::None, span),
+                    })),
+                })),
+            })
+        };
 
-        let builder_ident = builder_data.builder_ident();
-        let builder_path = builder_data.builder_path();
-        let builder_generics = builder_data.builder_generics();
-        let builder_fields = builder_data.builder_fields();
-        let builder_field_values = builder_data.builder_field_values();
-        let builder_statements = builder_data.builder_statements();
+        let component_name_fn = {
+            let mut inputs = Punctuated::new();
+            inputs.push(self_ref(span));
 
-        let instance_binds = builder_data.instance_binds();
+            let ty = Type::Reference(TypeReference {
+                and_token: And::default(),
+                lifetime: Some(Lifetime::new("'static", span)),
+                mutability: None,
+                elem: Box::new(Type::Path(TypePath {
+                    qself: None,
+                    path: Path::from(Ident::new("str", span)),
+                })),
+            });
 
-        let struct_builder = {
-            let fields_named = FieldsNamed {
+            let expr = Expr::Lit(ExprLit {
+                attrs: Vec::new(),
+                lit: Lit::Str(LitStr::new(&impl_ident.to_string(), span)),
+            });
+            let block = Block {
                 brace_token: Brace::default(),
-                named: builder_fields,
+                stmts: vec![Stmt::Expr(expr, None)],
             };
-            ItemStruct {
-                attrs: Vec::new(),
-                vis: trait_visibility.clone(),
-                struct_token: Struct::default(),
-                ident: builder_ident.clone(),
-                generics: builder_generics,
-                fields: Fields::Named(fields_named),
-                semi_token: None,
-            }
-        };
 
-        let builder_kind = {
-            let instance_binds = instance_binds.clone().into_iter().peekable();
+            mk_fn(
+                Ident::new("component_name", span),
+                Visibility::Inherited,
+                Generics::default(),
+                inputs,
+                ty,
+                block,
+            )
+        };
 
-            let (unset_generics, set_generics) = {
-                let mut instance_binds = instance_binds.clone();
-                if instance_binds.peek().is_none() {
-                    (PathArguments::None, PathArguments::None)
-                } else {
-                    let instance_binds = instance_binds.clone();
+        let bindings_fn = {
+            let mut inputs = Punctuated::new();
+            inputs.push(self_ref(span));
 
-                    let mut unset_args = Punctuated::new();
-                    let mut set_args = Punctuated::new();
+            let ty = wrap_type(type_binding_descriptor(PathArguments::None, span), type_vec);
 
-                    for (_ident, binding) in instance_binds {
-                        let ty = binding.ty()?;
+            let elems = sorted_bindings(bindings)
+                .into_iter()
+                .map(|(ident, binding)| get_binding_descriptor(ident, binding))
+                .collect::<ComponentResult<_>>()?;
+            let expr_array = ExprArray {
+                attrs: Vec::new(),
+                bracket_token: Bracket::default(),
+                elems,
+            };
 
-                        let set_generics = {
-                            let mut args = Punctuated::new();
-                            let generic_arg = GenericArgument::Type(ty.clone());
-                            args.push(generic_arg);
+            let mut args = Punctuated::new();
+            args.push(Expr::Array(expr_array));
+            let expr_call = ExprCall {
+                attrs: Vec::new(),
+                func: Box::new(Expr::Path(ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: path_vec_from(PathArguments::None, span),
+                })),
+                paren_token: Paren::default(),
+                args,
+            };
+            let block = Block {
+                brace_token: Brace::default(),
+                stmts: vec![Stmt::Expr(Expr::Call(expr_call), None)],
+            };
 
-                            let angle_bracketed = AngleBracketedGenericArguments {
-                                colon2_token: None,
-                                lt_token: Lt::default(),
-                                args,
-                                gt_token: Gt::default(),
-                            };
+            mk_fn(
+                Ident::new("bindings", span),
+                Visibility::Inherited,
+                Generics::default(),
+                inputs,
+                ty,
+                block,
+            )
+        };
 
-                            PathArguments::AngleBracketed(angle_bracketed)
-                        };
+        Ok(ItemImpl {
+            attrs: Vec::new(),
+            defaultness: None,
+            unsafety: None,
+            impl_token: Impl::default(),
+            generics: generics_unbound_formal.clone(),
+            trait_: Some((
+                None,
+                path_introspect(PathArguments::None, span),
+                For::default(),
+            )),
+            self_ty: Box::new(impl_ty.clone()),
+            brace_token: Brace::default(),
+            items: vec![component_name_fn, bindings_fn],
+        })
+    }
 
-                        let unset_arg =
-                            GenericArgument::Type(type_unset(PathArguments::None, ty.span()));
-                        let set_arg = GenericArgument::Type(type_set(set_generics, ty.span()));
+    fn impl_debug(&self) -> ComponentResult<ItemImpl> {
+        let impl_ident = self.impl_ident()?;
+        let impl_ty = self.impl_ty()?;
+        let generics_unbound_formal = self.generics_unbound()?;
 
-                        // handle unset_args
-                        unset_args.push(unset_arg.clone());
+        let span = impl_ident.span();
 
-                        // handle set_args
-                        set_args.push(set_arg.clone());
-                    }
+        let fmt_fn = {
+            let formatter_ident = Ident::new("f", span);
 
-                    let unset = AngleBracketedGenericArguments {
-                        colon2_token: None,
+            let mut inputs = Punctuated::new();
+            inputs.push(FnArg::Receiver(Receiver {
+                attrs: Vec::new(),
+                reference: Some((And::default(), None)),
+                mutability: None,
+                self_token: SelfValue::default(),
+                colon_token: None,
+                ty: Box::new(Type::Reference(TypeReference {
+                    and_token: And::default(),
+                    lifetime: None,
+                    mutability: None,
+                    elem: Box::new(Type::Path(TypePath {
+                        qself: None,
+                        path: path_self(PathArguments::None, span),
+                    })),
+                })),
+            }));
+            inputs.push(FnArg::Typed(PatType {
+                attrs: Vec::new(),
+                pat: Box::new(Pat::Ident(PatIdent {
+                    attrs: Vec::new(),
+                    by_ref: None,
+                    mutability: None,
+                    ident: formatter_ident.clone(),
+                    subpat: None,
+                })),
+                colon_token: Colon::default(),
+                ty: Box::new(Type::Reference(TypeReference {
+                    and_token: And::default(),
+                    lifetime: None,
+                    mutability: Some(Mut::default()),
+                    elem: Box::new(type_formatter(PathArguments::None, span)),
+                })),
+            }));
+
+            let mut args = Punctuated::new();
+            args.push(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: path_small_self(PathArguments::None, span),
+            }));
+            args.push(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: Path::from(formatter_ident),
+            }));
+            let expr_call = ExprCall {
+                attrs: Vec::new(),
+                func: Box::new(Expr::Path(ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: path_introspect_fmt_bindings(PathArguments::None, span),
+                })),
+                paren_token: Paren::default(),
+                args,
+            };
+            let block = Block {
+                brace_token: Brace::default(),
+                stmts: vec![Stmt::Expr(Expr::Call(expr_call), None)],
+            };
+
+            mk_fn(
+                Ident::new("fmt", span),
+                Visibility::Inherited,
+                Generics::default(),
+                inputs,
+                type_fmt_result(PathArguments::None, span),
+                block,
+            )
+        };
+
+        Ok(ItemImpl {
+            attrs: Vec::new(),
+            defaultness: None,
+            unsafety: None,
+            impl_token: Impl::default(),
+            generics: generics_unbound_formal.clone(),
+            trait_: Some((None, path_debug(PathArguments::None, span), For::default())),
+            self_ty: Box::new(impl_ty.clone()),
+            brace_token: Brace::default(),
+            items: vec![fmt_fn],
+        })
+    }
+
+    fn impl_dependency_graph(&self) -> ComponentResult<ItemImpl> {
+        let trait_ident = self.trait_ident()?;
+        let dirk_ty = self.dirk_ty()?;
+        let bindings = self.bindings()?;
+
+        let graph = DependencyGraph::new(trait_ident, bindings);
+        graph.export(trait_ident);
+
+        let span = trait_ident.span();
+
+        let mk_const = |name: &str, value: String| {
+            let ty = {
+                let reference = TypeReference {
+                    and_token: And::default(),
+                    lifetime: Some(Lifetime::new("'static", span)),
+                    mutability: None,
+                    elem: Box::new(Type::Path(TypePath {
+                        qself: None,
+                        path: Path::from(Ident::new("str", span)),
+                    })),
+                };
+                Type::Reference(reference)
+            };
+
+            let expr = Expr::Lit(ExprLit {
+                attrs: Vec::new(),
+                lit: Lit::Str(LitStr::new(&value, span)),
+            });
+
+            ImplItem::Const(ImplItemConst {
+                attrs: Vec::new(),
+                vis: Visibility::Public(Pub::default()),
+                defaultness: None,
+                const_token: Const::default(),
+                ident: Ident::new(name, span),
+                generics: Generics::default(),
+                colon_token: Colon::default(),
+                ty,
+                eq_token: Eq::default(),
+                expr,
+                semi_token: Semi::default(),
+            })
+        };
+
+        let items = vec![
+            mk_const("DEPENDENCY_GRAPH_DOT", graph.to_dot()),
+            mk_const("DEPENDENCY_GRAPH_MERMAID", graph.to_mermaid()),
+            mk_const("DEPENDENCY_GRAPH_JSON", graph.to_json()),
+        ];
+
+        Ok(ItemImpl {
+            attrs: Vec::new(),
+            defaultness: None,
+            unsafety: None,
+            impl_token: Impl::default(),
+            generics: Generics::default(),
+            trait_: None,
+            self_ty: Box::new(dirk_ty),
+            brace_token: Brace::default(),
+            items,
+        })
+    }
+
+    fn builder_kind(&self) -> ComponentResult<ComponentBuilderKind> {
+        let builder_data =
+            ComponentBuilderData::new(self.bindings()?, self.trait_ident()?, self.builder_ident()?);
+        ComponentBuilderKind::evaluate(&builder_data, self)
+    }
+
+    /// `type DirkXOverrides = Overrides;` in tests and `type DirkXOverrides = NoOverrides;` otherwise
+    ///
+    /// Outside of tests, components neither store nor wrap providers replacing their automatic bindings.
+    fn overrides_aliases(&self) -> ComponentResult<Vec<Item>> {
+        let trait_ident = self.trait_ident()?;
+        let span = trait_ident.span();
+
+        let mk_alias = |ty: Type, cfg: Option<proc_macro2::TokenStream>| {
+            let mut attrs = vec![mk_attribute("doc", quote! { hidden }, span)];
+            attrs.extend(cfg.map(|cfg| mk_attribute("cfg", cfg, span)));
+            Item::Type(ItemType {
+                attrs,
+                vis: syn::Visibility::Inherited,
+                type_token: syn::token::Type(span),
+                ident: get_overrides_ty_ident(trait_ident),
+                generics: Generics::default(),
+                eq_token: Eq(span),
+                ty: Box::new(ty),
+                semi_token: Semi(span),
+            })
+        };
+
+        let overrides_ty = type_overrides(PathArguments::None, span);
+        Ok(if cfg!(feature = "testing") {
+            vec![mk_alias(overrides_ty, None)]
+        } else {
+            vec![
+                mk_alias(overrides_ty, Some(quote! { test })),
+                mk_alias(
+                    type_no_overrides(PathArguments::None, span),
+                    Some(quote! { not(test) }),
+                ),
+            ]
+        })
+    }
+
+    /// `override_...(...)` methods of the builder, replacing automatic bindings, e.g., by mocks in tests
+    ///
+    /// Each method takes an instance of the type of the binding it replaces.
+    /// If that type contains generic parameters of the component, the method is only implemented once all instances are set, such that the parameters are those of the component.
+    fn impl_overrides(&self) -> ComponentResult<Vec<ItemImpl>> {
+        let bindings = self.bindings()?;
+        let unbound_generics = self.unbound_generics()?;
+
+        let builder_data =
+            ComponentBuilderData::new(bindings, self.trait_ident()?, self.builder_ident()?);
+        let (builder_generics, builder_ty) = builder_data.opaque_builder(None);
+
+        let mut items = Vec::new();
+        let mut generic_items = Vec::new();
+
+        for (ident, binding) in bindings.iter().sorted() {
+            let Some(automatic) = binding.kind().as_automatic() else {
+                continue;
+            };
+            let Some(override_fn) = automatic.override_fn() else {
+                continue;
+            };
+
+            let span = ident.span();
+            let arg_ty = automatic.override_arg_ty()?;
+
+            // Whether generic parameters of the component occur in the type of the binding
+            let is_generic = {
+                let mut idents = Vec::new();
+                collect_idents(automatic.ty()?.to_token_stream(), &mut idents);
+
+                unbound_generics
+                    .keys()
+                    .any(|generic_ident| idents.contains(generic_ident))
+            };
+
+            let mut inputs = Punctuated::new();
+            inputs.push(FnArg::Receiver(Receiver {
+                attrs: Vec::new(),
+                reference: None,
+                mutability: Some(Mut::default()),
+                self_token: SelfValue::default(),
+                colon_token: None,
+                ty: Box::new(Type::Path(TypePath {
+                    qself: None,
+                    path: path_self(PathArguments::None, span),
+                })),
+            }));
+            inputs.push(FnArg::Typed(PatType {
+                attrs: Vec::new(),
+                pat: Box::new(Pat::Ident(PatIdent {
+                    attrs: Vec::new(),
+                    by_ref: None,
+                    mutability: None,
+                    ident: (*ident).clone(),
+                    subpat: None,
+                })),
+                colon_token: Colon::default(),
+                ty: Box::new(arg_ty),
+            }));
+
+            let override_call = {
+                let mut args = Punctuated::new();
+                args.push(Expr::Lit(ExprLit {
+                    attrs: Vec::new(),
+                    lit: Lit::Str(LitStr::new(&ident.to_string(), span)),
+                }));
+                args.push(Expr::Path(ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: Path::from((*ident).clone()),
+                }));
+
+                Expr::MethodCall(ExprMethodCall {
+                    attrs: Vec::new(),
+                    receiver: Box::new(ComponentBuilderData::overrides_of_self(span)),
+                    dot_token: Dot::default(),
+                    method: Ident::new(override_fn, span),
+                    turbofish: None,
+                    paren_token: Paren::default(),
+                    args,
+                })
+            };
+
+            let self_expr = Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: path_small_self(PathArguments::None, span),
+            });
+
+            let block = Block {
+                brace_token: Brace::default(),
+                stmts: vec![
+                    Stmt::Expr(override_call, Some(Semi::default())),
+                    Stmt::Expr(self_expr, None),
+                ],
+            };
+
+            let mut override_fn = mk_fn(
+                Ident::new(&format!("override_{ident}"), span),
+                syn::Visibility::Inherited,
+                Generics::default(),
+                inputs,
+                Type::Path(TypePath {
+                    qself: None,
+                    path: path_self(PathArguments::None, span),
+                }),
+                block,
+            );
+
+            if let ImplItem::Fn(function) = &mut override_fn {
+                function.attrs.extend(test_only_attrs(span));
+            }
+
+            if is_generic {
+                generic_items.push(override_fn);
+            } else {
+                items.push(override_fn);
+            }
+        }
+
+        let mut impls = Vec::new();
+        if !generic_items.is_empty() {
+            let (_unset_args, set_args) = builder_data.status_args()?;
+            impls.push(ItemImpl {
+                attrs: Vec::new(),
+                defaultness: None,
+                unsafety: None,
+                impl_token: Impl::default(),
+                generics: self.generics_unbound()?.clone(),
+                trait_: None,
+                self_ty: Box::new(builder_data.builder_ty(set_args)),
+                brace_token: Brace::default(),
+                items: generic_items,
+            });
+        }
+
+        impls.push(ItemImpl {
+            attrs: Vec::new(),
+            defaultness: None,
+            unsafety: None,
+            impl_token: Impl::default(),
+            generics: builder_generics,
+            trait_: None,
+            self_ty: Box::new(builder_ty),
+            brace_token: Brace::default(),
+            items,
+        });
+
+        Ok(impls)
+    }
+
+    /// `config(...)` method of the builder, setting the configuration from which `config_bind(...)`s are deserialized
+    ///
+    /// Only available while no configuration has been set, `build()` is only available afterwards.
+    fn impl_config(&self) -> ComponentResult<Option<ItemImpl>> {
+        let builder_data =
+            ComponentBuilderData::new(self.bindings()?, self.trait_ident()?, self.builder_ident()?);
+        if !builder_data.has_config() {
+            return Ok(None);
+        }
+
+        let span = self.trait_ident()?.span();
+        let (builder_generics, builder_ty) =
+            builder_data.opaque_builder(Some(type_unset(PathArguments::None, span)));
+        let (_builder_generics, builder_ty_set) =
+            builder_data.opaque_builder(Some(ComponentBuilderData::config_set_ty(span)));
+        let config_ident = Ident::new("config", span);
+
+        let arg_ty = {
+            let mut args = Punctuated::new();
+            args.push(GenericArgument::Type(type_config(
+                PathArguments::None,
+                span,
+            )));
+            let arguments = PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                colon2_token: None,
+                lt_token: Lt::default(),
+                args,
+                gt_token: Gt::default(),
+            });
+
+            let mut bounds = Punctuated::new();
+            bounds.push(TypeParamBound::Trait(TraitBound {
+                paren_token: None,
+                modifier: syn::TraitBoundModifier::None,
+                lifetimes: None,
+                path: path_into(arguments, span),
+            }));
+            Type::ImplTrait(TypeImplTrait {
+                impl_token: Impl::default(),
+                bounds,
+            })
+        };
+
+        let mut inputs = Punctuated::new();
+        inputs.push(FnArg::Receiver(Receiver {
+            attrs: Vec::new(),
+            reference: None,
+            mutability: None,
+            self_token: SelfValue::default(),
+            colon_token: None,
+            ty: Box::new(Type::Path(TypePath {
+                qself: None,
+                path: path_self(PathArguments::None, span),
+            })),
+        }));
+        inputs.push(FnArg::Typed(PatType {
+            attrs: Vec::new(),
+            pat: Box::new(Pat::Ident(PatIdent {
+                attrs: Vec::new(),
+                by_ref: None,
+                mutability: None,
+                ident: config_ident.clone(),
+                subpat: None,
+            })),
+            colon_token: Colon::default(),
+            ty: Box::new(arg_ty),
+        }));
+
+        let mut stmts = Vec::new();
+
+        // `let <ident> = self.<ident>;` for every instance and the overrides
+        for (ident, _binding) in builder_data.instance_binds() {
+            stmts.push(ComponentBuilderData::local(
+                (*ident).clone(),
+                ComponentBuilderData::field_of_self((*ident).clone()),
+            ));
+        }
+        stmts.push(ComponentBuilderData::overrides_local(
+            span,
+            ComponentBuilderData::overrides_of_self(span),
+        ));
+
+        // `let dirk_config = dirk_framework::component::builder::Set(std::convert::Into::into(config));`
+        let set_config = {
+            let mut args = Punctuated::new();
+            args.push(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: Path::from(config_ident.clone()),
+            }));
+            let into_call = Expr::Call(ExprCall {
+                attrs: Vec::new(),
+                func: Box::new(Expr::Path(ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: path_into_into(PathArguments::None, span),
+                })),
+                paren_token: Paren::default(),
+                args,
+            });
+
+            let mut args = Punctuated::new();
+            args.push(into_call);
+            Expr::Call(ExprCall {
+                attrs: Vec::new(),
+                func: Box::new(Expr::Path(ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: path_set(PathArguments::None, span),
+                })),
+                paren_token: Paren::default(),
+                args,
+            })
+        };
+        stmts.push(ComponentBuilderData::config_local(span, set_config));
+
+        stmts.push(Stmt::Expr(
+            Expr::Struct(ExprStruct {
+                attrs: Vec::new(),
+                qself: None,
+                path: builder_data.builder_path(),
+                brace_token: Brace::default(),
+                fields: builder_data.builder_field_values(),
+                dot2_token: None,
+                rest: None,
+            }),
+            None,
+        ));
+
+        let block = Block {
+            brace_token: Brace::default(),
+            stmts,
+        };
+
+        let config_fn = mk_fn(
+            config_ident,
+            syn::Visibility::Inherited,
+            Generics::default(),
+            inputs,
+            builder_ty_set,
+            block,
+        );
+
+        Ok(Some(ItemImpl {
+            attrs: Vec::new(),
+            defaultness: None,
+            unsafety: None,
+            impl_token: Impl::default(),
+            generics: builder_generics,
+            trait_: None,
+            self_ty: Box::new(builder_ty),
+            brace_token: Brace::default(),
+            items: vec![config_fn],
+        }))
+    }
+
+    /// Scoped instance bindings of a generic type `T: Trait`, which are mocked by `MockTrait`, as generated by mockall
+    ///
+    /// Empty, unless the component opts into mocking via `#[component(mockall, ...)]`
+    fn mocked_binds(&self) -> ComponentResult<Vec<(&Ident, Type)>> {
+        let mut mocked_binds = Vec::new();
+        if self.data.input_macro()?.options.mockall.is_none() {
+            return Ok(mocked_binds);
+        }
+
+        let unbound_generics = self.unbound_generics()?;
+
+        for (ident, binding) in self.bindings()?.iter().sorted() {
+            let Some(ManualBindingKind::ScopedInstance { ty, .. }) = binding.kind().as_manual()
+            else {
+                continue;
+            };
+
+            let Some(GenericParam::Type(type_param)) = ty
+                .as_path()
+                .ok()
+                .and_then(|type_path| type_path.path.get_ident())
+                .and_then(|ty_ident| unbound_generics.get(ty_ident))
+            else {
+                continue;
+            };
+
+            let trait_bounds = type_param
+                .bounds
+                .iter()
+                .filter_map(|bound| match bound {
+                    TypeParamBound::Trait(trait_bound) => Some(trait_bound),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            let [trait_bound] = trait_bounds.as_slice() else {
+                continue;
+            };
+
+            let mut path = trait_bound.path.clone();
+            let Some(last) = path.segments.last_mut() else {
+                continue;
+            };
+            last.ident = Ident::new(&format!("Mock{}", last.ident), last.ident.span());
+
+            mocked_binds.push((*ident, Type::Path(TypePath { qself: None, path })));
+        }
+
+        Ok(mocked_binds)
+    }
+
+    /// `DirkXMocks`, created via `DirkX::mocked()`, holding mocks to set expectations on before passing them to the builder
+    ///
+    /// The mocks are shared with the builder, `DirkXMockHandles` allows to access them after building the component
+    fn mocks(&self) -> ComponentResult<Vec<Item>> {
+        let mocked_binds = self.mocked_binds()?;
+        if !cfg!(feature = "mockall") || mocked_binds.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let trait_ident = self.trait_ident()?;
+        let trait_visibility = &self.data.input_trait()?.vis;
+        let span = trait_ident.span();
+
+        let mocks_ident = {
+            let dirk_ident = self.delegate.dirk_ident()?;
+            Ident::new(&format!("{dirk_ident}Mocks"), dirk_ident.span())
+        };
+        let mocks_ty = Type::Path(TypePath {
+            qself: None,
+            path: Path::from(mocks_ident.clone()),
+        });
+
+        let builder_data =
+            ComponentBuilderData::new(self.bindings()?, trait_ident, self.builder_ident()?);
+
+        let mk_path_expr = |path: Path| {
+            Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path,
+            })
+        };
+
+        let struct_mocks = {
+            let mut named = Punctuated::new();
+            for (ident, mock_ty) in &mocked_binds {
+                named.push(Field {
+                    attrs: Vec::new(),
+                    vis: trait_visibility.clone(),
+                    mutability: syn::FieldMutability::None,
+                    ident: Some((*ident).clone()),
+                    colon_token: Some(Colon::default()),
+                    ty: mock_ty.clone(),
+                });
+            }
+
+            ItemStruct {
+                attrs: test_only_attrs(span),
+                vis: trait_visibility.clone(),
+                struct_token: Struct::default(),
+                ident: mocks_ident.clone(),
+                generics: Generics::default(),
+                fields: Fields::Named(FieldsNamed {
+                    brace_token: Brace::default(),
+                    named,
+                }),
+                semi_token: None,
+            }
+        };
+
+        let impl_mocked = {
+            let mut fields = Punctuated::new();
+            for (ident, mock_ty) in &mocked_binds {
+                let mut path = mock_ty.as_path()?.path.clone();
+                path.segments
+                    .push(PathSegment::from(Ident::new("new", ident.span())));
+
+                fields.push(FieldValue {
+                    attrs: Vec::new(),
+                    member: Member::Named((*ident).clone()),
+                    colon_token: Some(Colon::default()),
+                    expr: Expr::Call(ExprCall {
+                        attrs: Vec::new(),
+                        func: Box::new(mk_path_expr(path)),
+                        paren_token: Paren::default(),
+                        args: Punctuated::new(),
+                    }),
+                });
+            }
+
+            let mocks_struct = Expr::Struct(ExprStruct {
+                attrs: Vec::new(),
+                qself: None,
+                path: Path::from(mocks_ident.clone()),
+                brace_token: Brace::default(),
+                fields,
+                dot2_token: None,
+                rest: None,
+            });
+
+            let block = Block {
+                brace_token: Brace::default(),
+                stmts: vec![Stmt::Expr(mocks_struct, None)],
+            };
+
+            let mocked_fn = mk_fn(
+                Ident::new("mocked", span),
+                syn::Visibility::Inherited,
+                Generics::default(),
+                Punctuated::new(),
+                mocks_ty.clone(),
+                block,
+            );
+
+            ItemImpl {
+                attrs: test_only_attrs(span),
+                defaultness: None,
+                unsafety: None,
+                impl_token: Impl::default(),
+                generics: Generics::default(),
+                trait_: None,
+                self_ty: Box::new(self.dirk_ty()?),
+                brace_token: Brace::default(),
+                items: vec![mocked_fn],
+            }
+        };
+
+        let handles_ident = {
+            let dirk_ident = self.delegate.dirk_ident()?;
+            Ident::new(&format!("{dirk_ident}MockHandles"), dirk_ident.span())
+        };
+        let handles_ty = Type::Path(TypePath {
+            qself: None,
+            path: Path::from(handles_ident.clone()),
+        });
+
+        let struct_handles = {
+            let mut named = Punctuated::new();
+            for (ident, mock_ty) in &mocked_binds {
+                named.push(Field {
+                    attrs: Vec::new(),
+                    vis: trait_visibility.clone(),
+                    mutability: syn::FieldMutability::None,
+                    ident: Some((*ident).clone()),
+                    colon_token: Some(Colon::default()),
+                    ty: wrap_type(wrap_type(mock_ty.clone(), type_refcell), type_rc),
+                });
+            }
+
+            ItemStruct {
+                attrs: test_only_attrs(span),
+                vis: trait_visibility.clone(),
+                struct_token: Struct::default(),
+                ident: handles_ident.clone(),
+                generics: Generics::default(),
+                fields: Fields::Named(FieldsNamed {
+                    brace_token: Brace::default(),
+                    named,
+                }),
+                semi_token: None,
+            }
+        };
+
+        let impl_mocks = {
+            let mk_call = |path: Path, arg: Expr| {
+                let mut args = Punctuated::new();
+                args.push(arg);
+                Expr::Call(ExprCall {
+                    attrs: Vec::new(),
+                    func: Box::new(mk_path_expr(path)),
+                    paren_token: Paren::default(),
+                    args,
+                })
+            };
+            let mk_receiver = || {
+                let mut inputs = Punctuated::new();
+                inputs.push(FnArg::Receiver(Receiver {
+                    attrs: Vec::new(),
+                    reference: None,
+                    mutability: None,
+                    self_token: SelfValue::default(),
+                    colon_token: None,
+                    ty: Box::new(Type::Path(TypePath {
+                        qself: None,
+                        path: path_self(PathArguments::None, span),
+                    })),
+                }));
+                inputs
+            };
+
+            let builder_ty = {
+                let args: Punctuated<GenericArgument, Comma> = builder_data
+                    .instance_binds()
+                    .iter()
+                    .map(|(ident, _binding)| {
+                        let ty = match mocked_binds.iter().find(|(i, _)| i == ident) {
+                            Some((_ident, mock_ty)) => wrap_type(
+                                wrap_type(wrap_type(mock_ty.clone(), type_refcell), type_rc),
+                                type_set,
+                            ),
+                            None => type_unset(PathArguments::None, ident.span()),
+                        };
+                        GenericArgument::Type(ty)
+                    })
+                    .chain(
+                        builder_data
+                            .has_config()
+                            .then(|| GenericArgument::Type(type_unset(PathArguments::None, span))),
+                    )
+                    .collect();
+
+                builder_data.builder_ty(PathArguments::AngleBracketed(
+                    AngleBracketedGenericArguments {
+                        colon2_token: None,
                         lt_token: Lt::default(),
-                        args: unset_args,
+                        args,
                         gt_token: Gt::default(),
+                    },
+                ))
+            };
+
+            let builder_fn = {
+                let mut stmts = Vec::new();
+
+                // `let a = std::rc::Rc::new(std::cell::RefCell::new(self.a));`
+                for (ident, _mock_ty) in &mocked_binds {
+                    let mock = Expr::Field(ExprField {
+                        attrs: Vec::new(),
+                        base: Box::new(mk_path_expr(path_small_self(
+                            PathArguments::None,
+                            ident.span(),
+                        ))),
+                        dot_token: Dot::default(),
+                        member: Member::Named((*ident).clone()),
+                    });
+                    let handle = mk_call(
+                        path_rc_new(PathArguments::None, ident.span()),
+                        mk_call(path_refcell_new(PathArguments::None, ident.span()), mock),
+                    );
+
+                    stmts.push(Stmt::Local(Local {
+                        attrs: Vec::new(),
+                        let_token: Let::default(),
+                        pat: Pat::Ident(PatIdent {
+                            attrs: Vec::new(),
+                            by_ref: None,
+                            mutability: None,
+                            ident: (*ident).clone(),
+                            subpat: None,
+                        }),
+                        init: Some(LocalInit {
+                            eq_token: Eq::default(),
+                            expr: Box::new(handle),
+                            diverge: None,
+                        }),
+                        semi_token: Semi::default(),
+                    }));
+                }
+
+                // `DirkXBuilder::new().a_shared(a.clone()).b_shared(b.clone())`
+                let mut builder = {
+                    let mut path = builder_data.builder_path();
+                    path.segments
+                        .push(PathSegment::from(Ident::new("new", span)));
+                    Expr::Call(ExprCall {
+                        attrs: Vec::new(),
+                        func: Box::new(mk_path_expr(path)),
+                        paren_token: Paren::default(),
+                        args: Punctuated::new(),
+                    })
+                };
+
+                for (ident, _mock_ty) in &mocked_binds {
+                    let mut args = Punctuated::new();
+                    args.push(Expr::MethodCall(ExprMethodCall {
+                        attrs: Vec::new(),
+                        receiver: Box::new(mk_path_expr(Path::from((*ident).clone()))),
+                        dot_token: Dot::default(),
+                        method: Ident::new("clone", ident.span()),
+                        turbofish: None,
+                        paren_token: Paren::default(),
+                        args: Punctuated::new(),
+                    }));
+
+                    builder = Expr::MethodCall(ExprMethodCall {
+                        attrs: Vec::new(),
+                        receiver: Box::new(builder),
+                        dot_token: Dot::default(),
+                        method: Ident::new(&format!("{ident}_shared"), ident.span()),
+                        turbofish: None,
+                        paren_token: Paren::default(),
+                        args,
+                    });
+                }
+
+                // `DirkXMockHandles { a, b }`
+                let handles = Expr::Struct(ExprStruct {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: Path::from(handles_ident.clone()),
+                    brace_token: Brace::default(),
+                    fields: mocked_binds
+                        .iter()
+                        .map(|(ident, _mock_ty)| FieldValue {
+                            attrs: Vec::new(),
+                            member: Member::Named((*ident).clone()),
+                            colon_token: None,
+                            expr: mk_path_expr(Path::from((*ident).clone())),
+                        })
+                        .collect(),
+                    dot2_token: None,
+                    rest: None,
+                });
+
+                let mut elems = Punctuated::new();
+                elems.push(builder);
+                elems.push(handles);
+                stmts.push(Stmt::Expr(
+                    Expr::Tuple(ExprTuple {
+                        attrs: Vec::new(),
+                        paren_token: Paren::default(),
+                        elems,
+                    }),
+                    None,
+                ));
+
+                let mut elems = Punctuated::new();
+                elems.push(builder_ty.clone());
+                elems.push(handles_ty.clone());
+
+                mk_fn(
+                    Ident::new("builder", span),
+                    syn::Visibility::Inherited,
+                    Generics::default(),
+                    mk_receiver(),
+                    Type::Tuple(TypeTuple {
+                        paren_token: Paren::default(),
+                        elems,
+                    }),
+                    Block {
+                        brace_token: Brace::default(),
+                        stmts,
+                    },
+                )
+            };
+
+            // `fn build<T>(self) -> (T, DirkXMockHandles) where DirkXBuilder<...>: Builder<T>`, for components without further instance bindings
+            let build_fn = {
+                let ty_param = Ident::new("T", span);
+                let ty_param_ty = Type::Path(TypePath {
+                    qself: None,
+                    path: Path::from(ty_param.clone()),
+                });
+
+                let mut params = Punctuated::new();
+                params.push(GenericParam::Type(TypeParam::from(ty_param)));
+
+                let mut args = Punctuated::new();
+                args.push(GenericArgument::Type(ty_param_ty.clone()));
+                let mut bounds = Punctuated::new();
+                bounds.push(TypeParamBound::Trait(TraitBound {
+                    paren_token: None,
+                    modifier: syn::TraitBoundModifier::None,
+                    lifetimes: None,
+                    path: path_builder(
+                        PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                            colon2_token: None,
+                            lt_token: Lt::default(),
+                            args,
+                            gt_token: Gt::default(),
+                        }),
+                        span,
+                    ),
+                }));
+                let mut predicates = Punctuated::new();
+                predicates.push(WherePredicate::Type(PredicateType {
+                    lifetimes: None,
+                    bounded_ty: builder_ty,
+                    colon_token: Colon::default(),
+                    bounds,
+                }));
+
+                let generics = Generics {
+                    lt_token: Some(Lt::default()),
+                    params,
+                    gt_token: Some(Gt::default()),
+                    where_clause: Some(WhereClause {
+                        where_token: Where::default(),
+                        predicates,
+                    }),
+                };
+
+                let builder_ident = Ident::new("builder", span);
+                let handles_ident = Ident::new("handles", span);
+                let mk_pat_ident = |ident: &Ident| {
+                    Pat::Ident(PatIdent {
+                        attrs: Vec::new(),
+                        by_ref: None,
+                        mutability: None,
+                        ident: ident.clone(),
+                        subpat: None,
+                    })
+                };
+
+                // `let (builder, handles) = self.builder();`
+                let mut elems = Punctuated::new();
+                elems.push(mk_pat_ident(&builder_ident));
+                elems.push(mk_pat_ident(&handles_ident));
+                let destructure = Stmt::Local(Local {
+                    attrs: Vec::new(),
+                    let_token: Let::default(),
+                    pat: Pat::Tuple(PatTuple {
+                        attrs: Vec::new(),
+                        paren_token: Paren::default(),
+                        elems,
+                    }),
+                    init: Some(LocalInit {
+                        eq_token: Eq::default(),
+                        expr: Box::new(Expr::MethodCall(ExprMethodCall {
+                            attrs: Vec::new(),
+                            receiver: Box::new(mk_path_expr(path_small_self(
+                                PathArguments::None,
+                                span,
+                            ))),
+                            dot_token: Dot::default(),
+                            method: builder_ident.clone(),
+                            turbofish: None,
+                            paren_token: Paren::default(),
+                            args: Punctuated::new(),
+                        })),
+                        diverge: None,
+                    }),
+                    semi_token: Semi::default(),
+                });
+
+                // `(dirk_framework::component::builder::Builder::build(builder), handles)`
+                let mut build_path = path_builder(PathArguments::None, span);
+                build_path
+                    .segments
+                    .push(PathSegment::from(Ident::new("build", span)));
+                let mut elems = Punctuated::new();
+                elems.push(mk_call(build_path, mk_path_expr(Path::from(builder_ident))));
+                elems.push(mk_path_expr(Path::from(handles_ident)));
+                let built = Expr::Tuple(ExprTuple {
+                    attrs: Vec::new(),
+                    paren_token: Paren::default(),
+                    elems,
+                });
+
+                let mut elems = Punctuated::new();
+                elems.push(ty_param_ty);
+                elems.push(handles_ty.clone());
+
+                mk_fn(
+                    Ident::new("build", span),
+                    syn::Visibility::Inherited,
+                    generics,
+                    mk_receiver(),
+                    Type::Tuple(TypeTuple {
+                        paren_token: Paren::default(),
+                        elems,
+                    }),
+                    Block {
+                        brace_token: Brace::default(),
+                        stmts: vec![destructure, Stmt::Expr(built, None)],
+                    },
+                )
+            };
+
+            ItemImpl {
+                attrs: test_only_attrs(span),
+                defaultness: None,
+                unsafety: None,
+                impl_token: Impl::default(),
+                generics: Generics::default(),
+                trait_: None,
+                self_ty: Box::new(mocks_ty),
+                brace_token: Brace::default(),
+                items: vec![builder_fn, build_fn],
+            }
+        };
+
+        Ok(vec![
+            Item::Struct(struct_mocks),
+            Item::Struct(struct_handles),
+            Item::Impl(impl_mocked),
+            Item::Impl(impl_mocks),
+        ])
+    }
+
+    pub(crate) fn process(self) -> ComponentResult<Vec<Item>> {
+        if self.validate()? {
+            let mut items = self.generate()?;
+            items.extend(self.lints()?);
+
+            // Denied lints and errors emitted while generating (e.g., cycles) discard the returned tokens as well
+            set_dummy(quote! { #(#items)* });
+
+            return Ok(items);
+        }
+
+        // Tokens returned after emitting an error are discarded, hence the stub is passed as dummy
+        let stub = match self.generate() {
+            Ok(items) => quote! { #(#items)* },
+            Err(_) => {
+                let items = self.stub()?;
+                quote! { #(#items)* }
+            }
+        };
+        set_dummy(stub);
+
+        Ok(Vec::new())
+    }
+
+    /// The input trait along with a component and a builder whose functions are not implemented, avoiding follow-up errors where they are used
+    ///
+    /// The functions of the component return the types of their bindings rather than the (possibly erroneous) types given by the trait, which is hence not implemented.
+    /// Unless all of these types are independent of the generic parameters of the trait, only the input trait is returned.
+    fn stub(&self) -> ComponentResult<Vec<Item>> {
+        let input_macro = self.data.input_macro()?;
+        let input_trait = self.data.input_trait()?;
+
+        let Some(functions) = self.stub_functions() else {
+            return Ok(vec![Item::Trait(input_trait.clone())]);
+        };
+
+        let dirk_ident = self.delegate.dirk_ident()?;
+        let builder_ident = self.builder_ident()?;
+
+        let span = dirk_ident.span();
+        let vis = input_trait.vis.clone();
+
+        let self_receiver = FnArg::Receiver(Receiver {
+            attrs: Vec::new(),
+            reference: None,
+            mutability: None,
+            self_token: SelfValue::default(),
+            colon_token: None,
+            ty: Box::new(Type::Path(TypePath {
+                qself: None,
+                path: path_self(PathArguments::None, span),
+            })),
+        });
+        let self_ty = Type::Path(TypePath {
+            qself: None,
+            path: path_self(PathArguments::None, span),
+        });
+        let ty = |ident: &Ident| {
+            Type::Path(TypePath {
+                qself: None,
+                path: Path::from(ident.clone()),
+            })
+        };
+        let mk_impl = |ident: &Ident, items| {
+            Item::Impl(ItemImpl {
+                attrs: Vec::new(),
+                defaultness: None,
+                unsafety: None,
+                impl_token: Impl::default(),
+                generics: Generics::default(),
+                trait_: None,
+                self_ty: Box::new(ty(ident)),
+                brace_token: Brace::default(),
+                items,
+            })
+        };
+
+        let impl_component = {
+            let create_fn = mk_fn(
+                Ident::new("create", span),
+                vis.clone(),
+                Generics::default(),
+                Punctuated::new(),
+                self_ty.clone(),
+                get_stub_block(span),
+            );
+            let builder_fn = mk_fn(
+                Ident::new("builder", span),
+                vis.clone(),
+                Generics::default(),
+                Punctuated::new(),
+                ty(&builder_ident),
+                get_stub_block(span),
+            );
+
+            let mut items = vec![create_fn, builder_fn];
+            items.extend(functions);
+
+            mk_impl(dirk_ident, items)
+        };
+
+        let struct_builder = Item::Struct(ItemStruct {
+            attrs: Vec::new(),
+            vis: vis.clone(),
+            struct_token: Struct::default(),
+            ident: builder_ident.clone(),
+            generics: Generics::default(),
+            fields: Fields::Unit,
+            semi_token: Some(Semi::default()),
+        });
+
+        let impl_builder = {
+            // Any binding may be passed, regardless of whether it is an instance binding
+            let mut items = input_macro
+                .bindings
+                .iter()
+                .map(|binding| {
+                    let ident = binding.identifier();
+                    let param_ident = Ident::new("T", ident.span());
+
+                    let mut params = Punctuated::new();
+                    params.push(GenericParam::Type(TypeParam::from(param_ident.clone())));
+                    let generics = Generics {
+                        lt_token: Some(Lt::default()),
+                        params,
+                        gt_token: Some(Gt::default()),
+                        where_clause: None,
                     };
 
-                    let set = AngleBracketedGenericArguments {
-                        colon2_token: None,
-                        lt_token: Lt::default(),
-                        args: set_args,
-                        gt_token: Gt::default(),
+                    let mut inputs = Punctuated::new();
+                    inputs.push(self_receiver.clone());
+                    inputs.push(FnArg::Typed(PatType {
+                        attrs: Vec::new(),
+                        pat: Box::new(Pat::Ident(PatIdent {
+                            attrs: Vec::new(),
+                            by_ref: None,
+                            mutability: None,
+                            ident: ident.clone(),
+                            subpat: None,
+                        })),
+                        colon_token: Colon::default(),
+                        ty: Box::new(ty(&param_ident)),
+                    }));
+
+                    mk_fn(
+                        ident.clone(),
+                        vis.clone(),
+                        generics,
+                        inputs,
+                        self_ty.clone(),
+                        get_stub_block(span),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            let mut inputs = Punctuated::new();
+            inputs.push(self_receiver.clone());
+            items.push(mk_fn(
+                Ident::new("build", span),
+                vis.clone(),
+                Generics::default(),
+                inputs,
+                ty(dirk_ident),
+                get_stub_block(span),
+            ));
+
+            mk_impl(&builder_ident, items)
+        };
+
+        Ok(vec![
+            Item::Trait(input_trait.clone()),
+            impl_component,
+            struct_builder,
+            impl_builder,
+        ])
+    }
+
+    /// The functions of the stubbed component, querying the bindings of the same name
+    fn stub_functions(&self) -> Option<Vec<ImplItem>> {
+        let input_trait = self.data.input_trait().ok()?;
+        let bindings = self.bindings().ok()?;
+
+        let params = input_trait
+            .generics
+            .params
+            .iter()
+            .filter_map(|param| param.as_type().ok().map(|param| &param.ident))
+            .collect::<Vec<_>>();
+
+        let functions = input_trait
+            .items
+            .iter()
+            .filter_map(|item| item.as_fn().ok())
+            .filter_map(|function| {
+                let binding = bindings.get(&function.sig.ident)?;
+                let span = function.sig.ident.span();
+
+                let mut inputs = Punctuated::new();
+                inputs.push(FnArg::Receiver(Receiver {
+                    attrs: Vec::new(),
+                    reference: Some((And::default(), None)),
+                    mutability: None,
+                    self_token: SelfValue::default(),
+                    colon_token: None,
+                    ty: Box::new(Type::Reference(TypeReference {
+                        and_token: And::default(),
+                        lifetime: None,
+                        mutability: None,
+                        elem: Box::new(Type::Path(TypePath {
+                            qself: None,
+                            path: path_self(PathArguments::None, span),
+                        })),
+                    })),
+                }));
+
+                let kind = binding.kind();
+                let output = if kind.is_members() {
+                    // members bindings are queried via `fn(&self, target: &mut T)`
+                    inputs.push(FnArg::Typed(PatType {
+                        attrs: Vec::new(),
+                        pat: Box::new(Pat::Ident(PatIdent {
+                            attrs: Vec::new(),
+                            by_ref: None,
+                            mutability: None,
+                            ident: Ident::new("target", span),
+                            subpat: None,
+                        })),
+                        colon_token: Colon::default(),
+                        ty: Box::new(Type::Reference(TypeReference {
+                            and_token: And::default(),
+                            lifetime: None,
+                            mutability: Some(Mut::default()),
+                            elem: Box::new(kind.ty().ok()?),
+                        })),
+                    }));
+
+                    ReturnType::Default
+                } else {
+                    // Trait objects cannot be returned unwrapped, hence the signature of the trait is kept
+                    let ty = kind
+                        .wrapped_ty()
+                        .ok()
+                        .filter(|ty| ty.as_trait_object().is_err())
+                        .or_else(|| function.sig.output.as_type().ok().map(|(_, ty)| ty.clone()))?;
+
+                    ReturnType::Type(RArrow::default(), Box::new(ty))
+                };
+
+                Some(ImplItem::Fn(ImplItemFn {
+                    attrs: Vec::new(),
+                    vis: input_trait.vis.clone(),
+                    defaultness: None,
+                    sig: Signature {
+                        inputs,
+                        output,
+                        ..function.sig.clone()
+                    },
+                    block: get_stub_block(span),
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        // Types depending on the generic parameters of the trait could not be inferred where the functions are called
+        let mut idents = Vec::new();
+        for function in &functions {
+            collect_idents(function.to_token_stream(), &mut idents);
+        }
+
+        (!idents.iter().any(|ident| params.contains(&ident))).then_some(functions)
+    }
+
+    /// Returns the items reporting warnings, denied lints are emitted as errors instead
+    fn lints(&self) -> ComponentResult<Vec<Item>> {
+        let input_macro = self.data.input_macro()?;
+        let input_trait = self.data.input_trait()?;
+
+        let level = LintLevel::from_env(LINTS_ENV, LintLevel::Warn);
+
+        let queried = input_trait
+            .items
+            .iter()
+            .filter_map(|i| i.as_fn().ok())
+            .map(|f| &f.sig.ident)
+            .collect::<Vec<_>>();
+        let depended_on = input_macro
+            .bindings
+            .iter()
+            .filter_map(|b| b.kind().dependencies())
+            .flatten()
+            .collect::<Vec<_>>();
+
+        let mut items = vec![track_env(LINTS_ENV)];
+
+        for binding in &input_macro.bindings {
+            let ident = binding.identifier();
+            if queried.contains(&ident) || depended_on.contains(&ident) {
+                continue;
+            }
+
+            let msg = format!("Binding `{ident}` is neither queried by a function of the component nor a dependency of another binding");
+            items.extend(lint(level, "unused_binding", ident.span(), &msg));
+        }
+
+        Ok(items)
+    }
+
+    fn generate(&self) -> ComponentResult<Vec<Item>> {
+        let bindings = self.bindings()?;
+
+        let impl_ident = self.impl_ident()?;
+        let impl_ty = self.impl_ty()?;
+
+        let trait_path = self.trait_path()?;
+
+        let (functions, stubbed) = self.functions()?;
+
+        let generics_unbound_formal = self.generics_unbound()?;
+
+        let (
+            providers_signature,
+            providers_actual,
+            providers_formal,
+            providers_instantiation,
+            providers_stubbed,
+        ) = get_providers(bindings, get_overrides_ty(self.trait_ident()?))?;
+
+        let items = {
+            let mut input_trait = self.data.input_trait()?.clone();
+            let trait_visibility = input_trait.vis.clone();
+
+            for item in &mut input_trait.items {
+                if let TraitItem::Fn(function) = item {
+                    if stubbed.contains(&function.sig.ident) {
+                        function.default = Some(get_stub_block(function.sig.ident.span()));
+                        function.semi_token = None;
+                    }
+                }
+            }
+
+            let fields_named = FieldsNamed {
+                brace_token: Brace::default(),
+                named: providers_signature,
+            };
+
+            let struct_impl = ItemStruct {
+                attrs: Vec::new(),
+                vis: trait_visibility,
+                struct_token: Struct::default(),
+                ident: impl_ident.clone(),
+                generics: generics_unbound_formal.clone(),
+                fields: syn::Fields::Named(fields_named),
+                semi_token: None,
+            };
+
+            let impl_impl = {
+                let span = trait_path.span();
+
+                let self_path = path_self(PathArguments::None, span);
+                let type_path = TypePath {
+                    qself: None,
+                    path: self_path.clone(),
+                };
+                let self_ty = Type::Path(type_path);
+
+                // Errors regarding the dependencies of bindings have already been emitted
+                let block = if providers_stubbed {
+                    get_stub_block(span)
+                } else {
+                    let mut stmts = providers_instantiation;
+                    let expr_struct = ExprStruct {
+                        attrs: Vec::new(),
+                        qself: None,
+                        path: self_path,
+                        brace_token: Brace::default(),
+                        fields: providers_actual,
+                        dot2_token: None,
+                        rest: None,
                     };
+                    let self_struct = Expr::Struct(expr_struct);
+                    stmts.push(Stmt::Expr(self_struct, None));
 
-                    (
-                        PathArguments::AngleBracketed(unset),
-                        PathArguments::AngleBracketed(set),
-                    )
+                    Block {
+                        brace_token: Brace::default(),
+                        stmts,
+                    }
+                };
+
+                let new_fn = mk_fn(
+                    Ident::new("new", span),
+                    syn::Visibility::Inherited,
+                    Generics::default(),
+                    providers_formal,
+                    self_ty,
+                    block,
+                );
+
+                ItemImpl {
+                    attrs: Vec::new(),
+                    defaultness: None,
+                    unsafety: None,
+                    impl_token: Impl::default(),
+                    generics: generics_unbound_formal.clone(),
+                    trait_: None,
+                    self_ty: Box::new(impl_ty.clone()),
+                    brace_token: Brace::default(),
+                    items: vec![new_fn],
+                }
+            };
+
+            let trait_impl = ItemImpl {
+                attrs: Vec::new(),
+                defaultness: None,
+                unsafety: None,
+                impl_token: Impl::default(),
+                generics: generics_unbound_formal.clone(),
+                trait_: Some((None, trait_path, For::default())),
+                self_ty: Box::new(impl_ty.clone()),
+                brace_token: Brace::default(),
+                items: functions,
+            };
+
+            let builder = self.builder_kind()?;
+
+            let mut items = Vec::new();
+
+            match builder {
+                ComponentBuilderKind::StaticBuilder {
+                    struct_builder,
+                    impl_unset,
+                    impl_builder_set,
+                    impl_static_builder,
+                    impl_component,
+                    impl_static_component,
+                } => {
+                    items.push(Item::Struct(struct_builder));
+                    items.push(Item::Impl(impl_unset));
+                    items.push(Item::Impl(impl_builder_set));
+                    items.push(Item::Impl(impl_static_builder));
+                    items.push(Item::Impl(impl_component));
+                    items.push(Item::Impl(impl_static_component));
+                }
+                ComponentBuilderKind::NonStaticBuilder {
+                    struct_builder,
+                    impl_unset,
+                    impl_builder_unset,
+                    impl_builder_set,
+                    partial_impls,
+                    impl_static_builder,
+                    impl_component,
+                } => {
+                    items.push(Item::Struct(struct_builder));
+                    items.push(Item::Impl(impl_unset));
+                    items.push(Item::Impl(impl_builder_unset));
+                    items.push(Item::Impl(impl_builder_set));
+                    items.extend(partial_impls.into_iter().map(Item::Impl));
+                    items.push(Item::Impl(impl_static_builder));
+                    items.push(Item::Impl(impl_component));
                 }
+            }
+
+            items.extend(self.overrides_aliases()?);
+            items.extend(self.impl_overrides()?.into_iter().map(Item::Impl));
+            items.extend(self.impl_config()?.map(Item::Impl));
+            items.extend(self.mocks()?);
+            items.push(Item::Struct(struct_impl));
+            items.push(Item::Impl(impl_impl));
+            items.push(Item::Impl(trait_impl));
+            items.push(Item::Impl(self.impl_introspect()?));
+            items.push(Item::Impl(self.impl_debug()?));
+            items.push(Item::Impl(self.impl_dependency_graph()?));
+            items.push(track_env(GRAPH_DIR_ENV));
+            items.push(Item::Trait(input_trait));
+
+            items
+        };
+
+        Ok(items)
+    }
+}
+
+enum ComponentBuilderKind {
+    StaticBuilder {
+        struct_builder: ItemStruct,
+        impl_unset: ItemImpl,
+        impl_builder_set: ItemImpl,
+        impl_static_builder: ItemImpl,
+        impl_component: ItemImpl,
+        impl_static_component: ItemImpl,
+    },
+    NonStaticBuilder {
+        struct_builder: ItemStruct,
+        impl_unset: ItemImpl,
+        impl_builder_unset: ItemImpl,
+        impl_builder_set: ItemImpl,
+        partial_impls: Vec<ItemImpl>,
+        impl_static_builder: ItemImpl,
+        impl_component: ItemImpl,
+    },
+}
+
+impl ComponentBuilderKind {
+    fn evaluate(
+        builder_data: &ComponentBuilderData,
+        data: &ComponentMacroProcessor,
+    ) -> ComponentResult<Self> {
+        let input_trait = data.data.input_trait()?;
+        let trait_visibility = &input_trait.vis;
+
+        let dirk_ty = data.dirk_ty()?;
+
+        let impl_path = data.impl_path()?;
+        let impl_ty = data.impl_ty()?;
+
+        let generics_unbound_formal = data.generics_unbound()?;
+        let unbound_generics_mapping = data.unbound_generics()?;
+
+        let builder_ident = builder_data.builder_ident();
+        let builder_path = builder_data.builder_path();
+        let builder_generics = builder_data.builder_generics();
+        let builder_fields = builder_data.builder_fields();
+        let builder_field_values = builder_data.builder_field_values();
+        let builder_statements = builder_data.builder_statements();
+
+        let instance_binds = builder_data.instance_binds();
+
+        let struct_builder = {
+            let fields_named = FieldsNamed {
+                brace_token: Brace::default(),
+                named: builder_fields,
             };
+            ItemStruct {
+                attrs: Vec::new(),
+                vis: trait_visibility.clone(),
+                struct_token: Struct::default(),
+                ident: builder_ident.clone(),
+                generics: builder_generics,
+                fields: Fields::Named(fields_named),
+                semi_token: None,
+            }
+        };
+
+        let builder_kind = {
+            let instance_binds = instance_binds.clone().into_iter().peekable();
+
+            let (unset_generics, set_generics) = builder_data.status_args()?;
 
             let impl_unset = {
                 let span = builder_ident.span();
@@ -965,12 +2535,39 @@ impl ComponentBuilderKind {
             };
 
             let impl_static_builder = {
-                let instance_binds = instance_binds.clone();
+                let manual_binds = builder_data.manual_binds();
+
+                let mut unwrap_statements = Vec::new();
+                let mut providers_actual: Punctuated<Expr, Comma> = Punctuated::new();
+                let mut binding_errors = Punctuated::new();
+
+                for (ident, binding) in manual_binds {
+                    // `let <ident> = EnvFactory::<T>::from_env(...);`, reporting errors after all variables and sections have been read
+                    if let Some(fallible_factory) = binding.get_fallible_factory(ident) {
+                        let pat = Pat::Ident(PatIdent {
+                            attrs: Vec::new(),
+                            by_ref: None,
+                            mutability: None,
+                            ident: ident.clone(),
+                            subpat: None,
+                        });
+                        unwrap_statements.push(Stmt::Local(Local {
+                            attrs: Vec::new(),
+                            let_token: Let::default(),
+                            pat,
+                            init: Some(LocalInit {
+                                eq_token: Eq::default(),
+                                expr: Box::new(fallible_factory),
+                                diverge: None,
+                            }),
+                            semi_token: Semi::default(),
+                        }));
+                        binding_errors.push(ComponentBuilderData::binding_error_of(ident));
 
-                let mut unwrap_statements = Vec::new();
-                let mut providers_actual: Punctuated<Expr, Comma> = Punctuated::new();
+                        providers_actual.push(binding.get_new_factory(ident));
+                        continue;
+                    }
 
-                for (ident, binding) in instance_binds {
                     let unwrap_statement = {
                         let path = path_set(PathArguments::None, ident.span());
 
@@ -1031,6 +2628,9 @@ impl ComponentBuilderKind {
                     let provider = binding.get_new_factory(ident);
                     providers_actual.push(provider);
                 }
+                providers_actual.push(ComponentBuilderData::overrides_of_self(
+                    builder_ident.span(),
+                ));
 
                 let span = builder_ident.span();
 
@@ -1048,7 +2648,7 @@ impl ComponentBuilderKind {
 
                 let builder_ty = builder_data.builder_ty(set_generics.clone());
 
-                let build_fn = {
+                let mk_inputs = || {
                     let type_path = TypePath {
                         qself: None,
                         path: path_self(PathArguments::None, span),
@@ -1065,8 +2665,10 @@ impl ComponentBuilderKind {
                         ty: Box::new(self_ty),
                     });
                     inputs.push(self_arg);
+                    inputs
+                };
 
-                    let mut stmts = unwrap_statements;
+                let new_call = {
                     let mut path = impl_path.clone();
                     let new_segment = PathSegment::from(Ident::new("new", span));
                     path.segments.push(new_segment);
@@ -1082,22 +2684,79 @@ impl ComponentBuilderKind {
                         paren_token: Paren::default(),
                         args: providers_actual,
                     };
-                    let expr = Expr::Call(expr_call);
-                    let stmt = Stmt::Expr(expr, None);
-                    stmts.push(stmt);
+                    Expr::Call(expr_call)
+                };
+
+                let build_fns = if binding_errors.is_empty() {
+                    let mut stmts = unwrap_statements;
+                    stmts.push(Stmt::Expr(new_call, None));
                     let block = Block {
                         brace_token: Brace::default(),
                         stmts,
                     };
 
-                    mk_fn(
+                    vec![mk_fn(
                         Ident::new("build", span),
                         syn::Visibility::Inherited,
                         Generics::default(),
-                        inputs,
+                        mk_inputs(),
                         impl_ty.clone(),
                         block,
-                    )
+                    )]
+                } else {
+                    // `try_build` reports all missing or malformed environment variables and configuration sections, `build` panics in this case
+                    let mut stmts = unwrap_statements;
+                    stmts.push(ComponentBuilderData::build_check(binding_errors, span));
+                    stmts.push(Stmt::Expr(
+                        ComponentBuilderData::wrap_ok(new_call, span),
+                        None,
+                    ));
+                    let try_block = Block {
+                        brace_token: Brace::default(),
+                        stmts,
+                    };
+
+                    let result_ty = {
+                        let mut args = Punctuated::new();
+                        args.push(GenericArgument::Type(impl_ty.clone()));
+                        args.push(GenericArgument::Type(type_build_error(
+                            PathArguments::None,
+                            span,
+                        )));
+                        type_result(
+                            PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                                colon2_token: None,
+                                lt_token: Lt::default(),
+                                args,
+                                gt_token: Gt::default(),
+                            }),
+                            span,
+                        )
+                    };
+
+                    let build_block = Block {
+                        brace_token: Brace::default(),
+                        stmts: vec![Stmt::Expr(ComponentBuilderData::unwrap_built(span), None)],
+                    };
+
+                    vec![
+                        mk_fn(
+                            Ident::new("build", span),
+                            syn::Visibility::Inherited,
+                            Generics::default(),
+                            mk_inputs(),
+                            impl_ty.clone(),
+                            build_block,
+                        ),
+                        mk_fn(
+                            Ident::new("try_build", span),
+                            syn::Visibility::Inherited,
+                            Generics::default(),
+                            mk_inputs(),
+                            result_ty,
+                            try_block,
+                        ),
+                    ]
                 };
 
                 ItemImpl {
@@ -1109,7 +2768,7 @@ impl ComponentBuilderKind {
                     trait_: Some((None, static_builder_path, For::default())),
                     self_ty: Box::new(builder_ty),
                     brace_token: Brace::default(),
-                    items: vec![build_fn],
+                    items: build_fns,
                 }
             };
 
@@ -1184,7 +2843,7 @@ impl ComponentBuilderKind {
             };
 
             let mut instance_binds = instance_binds.clone();
-            if instance_binds.peek().is_none() {
+            if instance_binds.peek().is_none() && !builder_data.has_config() {
                 let impl_static_component = {
                     let span = builder_ident.span();
 
@@ -1253,10 +2912,12 @@ impl ComponentBuilderKind {
 
                 for (index_set, (ident, binding)) in instance_binds.clone().enumerate() {
                     let ty = binding.ty()?;
+                    let set_ty = binding.set_ty()?;
+                    let (arg_ty, arg_generic_param) = binding.setter_arg_ty()?;
 
                     let set_generics = {
                         let mut args = Punctuated::new();
-                        let generic_arg = GenericArgument::Type(ty.clone());
+                        let generic_arg = GenericArgument::Type(set_ty.clone());
                         args.push(generic_arg);
 
                         let angle_bracketed = AngleBracketedGenericArguments {
@@ -1294,14 +2955,7 @@ impl ComponentBuilderKind {
                             let set_constructor = Expr::Path(expr_path);
 
                             let mut args = Punctuated::new();
-
-                            let expr_path = ExprPath {
-                                attrs: Vec::new(),
-                                qself: None,
-                                path: Path::from(ident.clone()),
-                            };
-                            let arg = Expr::Path(expr_path);
-                            args.push(arg);
+                            args.push(binding.get_set_value(ident));
 
                             let expr_call = ExprCall {
                                 attrs: Vec::new(),
@@ -1421,6 +3075,27 @@ impl ComponentBuilderKind {
                         }
                     }
 
+                    statements_opaque.push(ComponentBuilderData::overrides_local(
+                        ident.span(),
+                        ComponentBuilderData::overrides_of_self(ident.span()),
+                    ));
+                    if builder_data.has_config() {
+                        let opaque_ident = Ident::new(
+                            &format!("S{}", builder_data.instance_binds().len()),
+                            ident.span(),
+                        );
+                        let opaque_param = ComponentBuilderData::opaque_param(opaque_ident);
+                        let opaque_arg = generic_argument_from_generic_param(&opaque_param);
+                        args_pure.push(opaque_param);
+                        args_containing_unset.push(opaque_arg.clone());
+                        args_containing_set.push(opaque_arg);
+
+                        statements_opaque.push(ComponentBuilderData::config_local(
+                            ident.span(),
+                            ComponentBuilderData::config_of_self(ident.span()),
+                        ));
+                    }
+
                     let partial_impl = {
                         let generics_containing_set = {
                             if args_containing_set.is_empty() {
@@ -1472,20 +3147,26 @@ impl ComponentBuilderKind {
                                 .and_then(|p| p.path.get_ident())
                                 .and_then(|ty_ident| unbound_generics_mapping.get(ty_ident));
 
+                            let mut params = Punctuated::new();
                             if let Some(generic_param) = maybe_generic_param {
-                                let mut params = Punctuated::new();
                                 params.push((*generic_param).clone());
+                            }
+                            if let Some(generic_param) = arg_generic_param {
+                                params.push(generic_param);
+                            }
+
+                            if params.is_empty() {
                                 Generics {
-                                    lt_token: Some(Lt::default()),
+                                    lt_token: None,
                                     params,
-                                    gt_token: Some(Gt::default()),
+                                    gt_token: None,
                                     where_clause: None,
                                 }
                             } else {
                                 Generics {
-                                    lt_token: None,
-                                    params: Punctuated::new(),
-                                    gt_token: None,
+                                    lt_token: Some(Lt::default()),
+                                    params,
+                                    gt_token: Some(Gt::default()),
                                     where_clause: None,
                                 }
                             }
@@ -1496,37 +3177,40 @@ impl ComponentBuilderKind {
                         let builder_ty_unset = builder_data.builder_ty(generics_containing_unset);
                         let builder_ty_set = builder_data.builder_ty(generics_containing_set);
 
-                        let mut inputs = Punctuated::new();
-                        let type_path = TypePath {
-                            qself: None,
-                            path: path_self(PathArguments::None, span),
-                        };
-                        let self_ty = Type::Path(type_path);
-                        let self_arg = FnArg::Receiver(Receiver {
-                            attrs: Vec::new(),
-                            reference: None,
-                            mutability: None,
-                            self_token: SelfValue::default(),
-                            colon_token: None,
-                            ty: Box::new(self_ty),
-                        });
-                        inputs.push(self_arg);
-                        let pat_ident = PatIdent {
-                            attrs: Vec::new(),
-                            by_ref: None,
-                            mutability: None,
-                            ident: ident.clone(),
-                            subpat: None,
-                        };
-                        let pat = Pat::Ident(pat_ident);
-                        let pat_type = PatType {
-                            attrs: Vec::new(),
-                            pat: Box::new(pat),
-                            colon_token: Colon::default(),
-                            ty: Box::new(ty.clone()),
+                        let mk_inputs = |ty: Type| {
+                            let mut inputs = Punctuated::new();
+                            let type_path = TypePath {
+                                qself: None,
+                                path: path_self(PathArguments::None, span),
+                            };
+                            let self_ty = Type::Path(type_path);
+                            let self_arg = FnArg::Receiver(Receiver {
+                                attrs: Vec::new(),
+                                reference: None,
+                                mutability: None,
+                                self_token: SelfValue::default(),
+                                colon_token: None,
+                                ty: Box::new(self_ty),
+                            });
+                            inputs.push(self_arg);
+                            let pat_ident = PatIdent {
+                                attrs: Vec::new(),
+                                by_ref: None,
+                                mutability: None,
+                                ident: ident.clone(),
+                                subpat: None,
+                            };
+                            let pat = Pat::Ident(pat_ident);
+                            let pat_type = PatType {
+                                attrs: Vec::new(),
+                                pat: Box::new(pat),
+                                colon_token: Colon::default(),
+                                ty: Box::new(ty),
+                            };
+                            let partial_arg = FnArg::Typed(pat_type);
+                            inputs.push(partial_arg);
+                            inputs
                         };
-                        let partial_arg = FnArg::Typed(pat_type);
-                        inputs.push(partial_arg);
 
                         let mut stmts = statements_opaque;
                         let expr_struct = ExprStruct {
@@ -1546,14 +3230,57 @@ impl ComponentBuilderKind {
                             stmts,
                         };
 
-                        let partial_fn = mk_fn(
-                            ident.clone(),
-                            syn::Visibility::Inherited,
-                            generics_partial,
-                            inputs,
-                            builder_ty_set,
-                            block,
-                        );
+                        let partial_fns = match binding.shared_setter_ident(ident) {
+                            None => vec![mk_fn(
+                                ident.clone(),
+                                syn::Visibility::Inherited,
+                                generics_partial,
+                                mk_inputs(arg_ty),
+                                builder_ty_set,
+                                block,
+                            )],
+                            Some(shared_ident) => {
+                                // `self.<ident>_shared(Rc::new(RefCell::new(<ident>)))`
+                                let mut args = Punctuated::new();
+                                args.push(binding.get_shared_value(ident));
+                                let delegate = Expr::MethodCall(ExprMethodCall {
+                                    attrs: Vec::new(),
+                                    receiver: Box::new(Expr::Path(ExprPath {
+                                        attrs: Vec::new(),
+                                        qself: None,
+                                        path: path_small_self(PathArguments::None, span),
+                                    })),
+                                    dot_token: Dot::default(),
+                                    method: shared_ident.clone(),
+                                    turbofish: None,
+                                    paren_token: Paren::default(),
+                                    args,
+                                });
+                                let delegate_block = Block {
+                                    brace_token: Brace::default(),
+                                    stmts: vec![Stmt::Expr(delegate, None)],
+                                };
+
+                                vec![
+                                    mk_fn(
+                                        ident.clone(),
+                                        syn::Visibility::Inherited,
+                                        generics_partial.clone(),
+                                        mk_inputs(arg_ty),
+                                        builder_ty_set.clone(),
+                                        delegate_block,
+                                    ),
+                                    mk_fn(
+                                        shared_ident,
+                                        syn::Visibility::Inherited,
+                                        generics_partial,
+                                        mk_inputs(set_ty.clone()),
+                                        builder_ty_set,
+                                        block,
+                                    ),
+                                ]
+                            }
+                        };
 
                         ItemImpl {
                             attrs: Vec::new(),
@@ -1564,7 +3291,7 @@ impl ComponentBuilderKind {
                             trait_: None,
                             self_ty: Box::new(builder_ty_unset),
                             brace_token: Brace::default(),
-                            items: vec![partial_fn],
+                            items: partial_fns,
                         }
                     };
 
@@ -1589,22 +3316,23 @@ impl ComponentBuilderKind {
 struct ComponentBuilderData<'data, 'bindings: 'data> {
     bindings: &'data HashMap<&'bindings Ident, &'bindings Binding>,
     trait_ident: &'data Ident,
+    builder_ident: Ident,
 
     instance_binds: OnceCell<Vec<(&'data Ident, &'data ManualBindingKind)>>,
-    builder_ident: OnceCell<Ident>,
 }
 
 impl<'data, 'bindings: 'data> ComponentBuilderData<'data, 'bindings> {
     fn new(
         bindings: &'data HashMap<&'bindings Ident, &'bindings Binding>,
         trait_ident: &'data Ident,
+        builder_ident: Ident,
     ) -> Self {
         Self {
             bindings,
             trait_ident,
+            builder_ident,
 
             instance_binds: OnceCell::new(),
-            builder_ident: OnceCell::new(),
         }
     }
 }
@@ -1616,35 +3344,207 @@ impl<'data, 'bindings: 'data> ComponentBuilderData<'data, 'bindings> {
         Ident::new(&content, ident.span())
     }
 
+    /// All manual bindings, including those not set via the builder, in the order of the providers passed to `new`
+    fn manual_binds(&self) -> Vec<(&'data Ident, &'data ManualBindingKind)> {
+        sorted_bindings(self.bindings)
+            .into_iter()
+            .filter_map(|(i, b)| b.kind().as_manual().map(|m| (i, m)))
+            .collect()
+    }
+
+    /// Whether the builder holds a configuration, from which `config_bind(...)`s are deserialized
+    fn has_config(&self) -> bool {
+        self.manual_binds()
+            .iter()
+            .any(|(_ident, binding)| binding.is_config())
+    }
+
     fn instance_binds(&self) -> &Vec<(&'data Ident, &'data ManualBindingKind)> {
         if let Some(cached) = self.instance_binds.get() {
             return cached;
         }
 
-        let instance_binds = {
-            let bindings = self.bindings;
-
-            bindings
-                .iter()
-                .sorted()
-                .filter_map(|(i, b)| b.kind().as_manual().map(|m| (*i, m)))
-                .collect()
-        };
+        // Same order as the providers passed to `new`, which are instantiated in topological order
+        let instance_binds = self
+            .manual_binds()
+            .into_iter()
+            .filter(|(_i, m)| m.is_set_by_builder())
+            .collect();
 
         self.instance_binds.get_or_init(|| instance_binds)
     }
 
     fn builder_ident(&self) -> &Ident {
-        if let Some(cached) = self.builder_ident.get() {
-            return cached;
+        &self.builder_ident
+    }
+
+    /// The builder with opaque parameters, such that methods are available regardless of which instances have been set
+    ///
+    /// The status of the configuration is opaque as well, unless given by `config_status`.
+    fn opaque_builder(&self, config_status: Option<Type>) -> (Generics, Type) {
+        let span = self.trait_ident.span();
+
+        let mut opaque_params: Punctuated<GenericParam, Comma> = self
+            .instance_binds()
+            .iter()
+            .enumerate()
+            .map(|(index, (ident, _binding))| {
+                Self::opaque_param(Ident::new(&format!("S{index}"), ident.span()))
+            })
+            .collect();
+        let mut args: Punctuated<GenericArgument, Comma> = opaque_params
+            .iter()
+            .map(generic_argument_from_generic_param)
+            .collect();
+
+        if self.has_config() {
+            match config_status {
+                Some(config_status) => args.push(GenericArgument::Type(config_status)),
+                None => {
+                    let param = Self::opaque_param(Ident::new(
+                        &format!("S{}", self.instance_binds().len()),
+                        span,
+                    ));
+                    args.push(generic_argument_from_generic_param(&param));
+                    opaque_params.push(param);
+                }
+            }
+        }
+
+        let builder_ty = if args.is_empty() {
+            self.builder_ty(PathArguments::None)
+        } else {
+            self.builder_ty(PathArguments::AngleBracketed(
+                AngleBracketedGenericArguments {
+                    colon2_token: None,
+                    lt_token: Lt::default(),
+                    args,
+                    gt_token: Gt::default(),
+                },
+            ))
+        };
+
+        let builder_generics = {
+            let (lt, gt) = if opaque_params.is_empty() {
+                (None, None)
+            } else {
+                (Some(Lt::default()), Some(Gt::default()))
+            };
+            Generics {
+                lt_token: lt,
+                params: opaque_params,
+                gt_token: gt,
+                where_clause: None,
+            }
+        };
+
+        (builder_generics, builder_ty)
+    }
+
+    /// Arguments of the builder with all instances unset and set, e.g., `<Unset, Unset>` and `<Set<A>, Set<B>>`
+    fn status_args(&self) -> ComponentResult<(PathArguments, PathArguments)> {
+        let instance_binds = self.instance_binds();
+        if instance_binds.is_empty() && !self.has_config() {
+            return Ok((PathArguments::None, PathArguments::None));
+        }
+
+        let mut unset_args = Punctuated::new();
+        let mut set_args = Punctuated::new();
+
+        for (_ident, binding) in instance_binds {
+            let ty = binding.set_ty()?;
+
+            let set_generics = {
+                let mut args = Punctuated::new();
+                let generic_arg = GenericArgument::Type(ty.clone());
+                args.push(generic_arg);
+
+                let angle_bracketed = AngleBracketedGenericArguments {
+                    colon2_token: None,
+                    lt_token: Lt::default(),
+                    args,
+                    gt_token: Gt::default(),
+                };
+
+                PathArguments::AngleBracketed(angle_bracketed)
+            };
+
+            let unset_arg = GenericArgument::Type(type_unset(PathArguments::None, ty.span()));
+            let set_arg = GenericArgument::Type(type_set(set_generics, ty.span()));
+
+            // handle unset_args
+            unset_args.push(unset_arg.clone());
+
+            // handle set_args
+            set_args.push(set_arg.clone());
+        }
+
+        if self.has_config() {
+            let span = self.builder_ident.span();
+            unset_args.push(GenericArgument::Type(type_unset(PathArguments::None, span)));
+            set_args.push(GenericArgument::Type(Self::config_set_ty(span)));
         }
 
-        let builder_path = {
-            let trait_ident = self.trait_ident;
-            get_dirk_name(trait_ident, Some("Builder"))
+        let unset = AngleBracketedGenericArguments {
+            colon2_token: None,
+            lt_token: Lt::default(),
+            args: unset_args,
+            gt_token: Gt::default(),
+        };
+
+        let set = AngleBracketedGenericArguments {
+            colon2_token: None,
+            lt_token: Lt::default(),
+            args: set_args,
+            gt_token: Gt::default(),
         };
 
-        self.builder_ident.get_or_init(|| builder_path)
+        Ok((
+            PathArguments::AngleBracketed(unset),
+            PathArguments::AngleBracketed(set),
+        ))
+    }
+
+    /// `<ident>: dirk_framework::component::builder::InputStatus`
+    fn opaque_param(ident: Ident) -> GenericParam {
+        let mut bounds = Punctuated::new();
+        bounds.push(TypeParamBound::Trait(TraitBound {
+            paren_token: None,
+            modifier: syn::TraitBoundModifier::None,
+            lifetimes: None,
+            path: path_input_status(PathArguments::None, ident.span()),
+        }));
+        GenericParam::Type(TypeParam {
+            attrs: Vec::new(),
+            ident,
+            colon_token: Some(Colon::default()),
+            bounds,
+            eq_token: None,
+            default: None,
+        })
+    }
+
+    /// Generic parameter of the builder tracking whether `config(...)` has been called
+    fn config_param_ident(&self) -> Ident {
+        Self::param_ident(&get_config_ident(self.trait_ident.span()))
+    }
+
+    /// `dirk_framework::component::builder::Set<dirk_framework::component::config_binds::Config>`
+    fn config_set_ty(span: Span) -> Type {
+        let mut args = Punctuated::new();
+        args.push(GenericArgument::Type(type_config(
+            PathArguments::None,
+            span,
+        )));
+        type_set(
+            PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                colon2_token: None,
+                lt_token: Lt::default(),
+                args,
+                gt_token: Gt::default(),
+            }),
+            span,
+        )
     }
 
     fn builder_path(&self) -> Path {
@@ -1652,9 +3552,7 @@ impl<'data, 'bindings: 'data> ComponentBuilderData<'data, 'bindings> {
     }
 
     fn builder_ty(&self, arguments: PathArguments) -> Type {
-        let trait_ident = self.trait_ident;
-
-        let builder_ident = get_dirk_name(trait_ident, Some("Builder"));
+        let builder_ident = self.builder_ident().clone();
 
         let mut segments = Punctuated::new();
         let segment = PathSegment {
@@ -1703,6 +3601,10 @@ impl<'data, 'bindings: 'data> ComponentBuilderData<'data, 'bindings> {
             generic_params.push(generic_param);
         }
 
+        if self.has_config() {
+            generic_params.push(Self::opaque_param(self.config_param_ident()));
+        }
+
         let (lt, gt) = {
             if generic_params.is_empty() {
                 (None, None)
@@ -1741,6 +3643,32 @@ impl<'data, 'bindings: 'data> ComponentBuilderData<'data, 'bindings> {
             fields.push(field);
         }
 
+        let span = self.trait_ident.span();
+        let overrides_field = Field {
+            attrs: Vec::new(),
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(get_overrides_ident(span)),
+            colon_token: Some(Colon::default()),
+            ty: get_overrides_ty(self.trait_ident),
+        };
+        fields.push(overrides_field);
+
+        if self.has_config() {
+            let config_field = Field {
+                attrs: Vec::new(),
+                vis: syn::Visibility::Inherited,
+                mutability: syn::FieldMutability::None,
+                ident: Some(get_config_ident(span)),
+                colon_token: Some(Colon::default()),
+                ty: Type::Path(TypePath {
+                    qself: None,
+                    path: Path::from(self.config_param_ident()),
+                }),
+            };
+            fields.push(config_field);
+        }
+
         fields
     }
 
@@ -1768,6 +3696,35 @@ impl<'data, 'bindings: 'data> ComponentBuilderData<'data, 'bindings> {
             field_values.push(field_value);
         }
 
+        let overrides_field_value = {
+            let ident = get_overrides_ident(self.trait_ident.span());
+            FieldValue {
+                attrs: Vec::new(),
+                member: Member::Named(ident.clone()),
+                colon_token: None,
+                expr: syn::Expr::Path(ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: Path::from(ident),
+                }),
+            }
+        };
+        field_values.push(overrides_field_value);
+
+        if self.has_config() {
+            let ident = get_config_ident(self.trait_ident.span());
+            field_values.push(FieldValue {
+                attrs: Vec::new(),
+                member: Member::Named(ident.clone()),
+                colon_token: None,
+                expr: syn::Expr::Path(ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: Path::from(ident),
+                }),
+            });
+        }
+
         field_values
     }
 
@@ -1812,6 +3769,231 @@ impl<'data, 'bindings: 'data> ComponentBuilderData<'data, 'bindings> {
             statements.push(statement);
         }
 
+        let overrides_statement = {
+            let span = self.trait_ident.span();
+            let mut path = Path::from(get_overrides_ty_ident(self.trait_ident));
+            path.segments
+                .push(PathSegment::from(Ident::new("default", span)));
+            let expr_call = ExprCall {
+                attrs: Vec::new(),
+                func: Box::new(Expr::Path(ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path,
+                })),
+                paren_token: Paren::default(),
+                args: Punctuated::new(),
+            };
+            Self::overrides_local(span, Expr::Call(expr_call))
+        };
+        statements.push(overrides_statement);
+
+        if self.has_config() {
+            let span = self.trait_ident.span();
+            let expr_unset = Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: path_unset(PathArguments::None, span),
+            });
+            statements.push(Self::config_local(span, expr_unset));
+        }
+
         statements
     }
+
+    /// `<ident>.as_ref().err().map(std::convert::Into::into)`
+    fn binding_error_of(ident: &Ident) -> Expr {
+        let receiver = Expr::MethodCall(ExprMethodCall {
+            attrs: Vec::new(),
+            receiver: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: Path::from(ident.clone()),
+            })),
+            dot_token: Dot::default(),
+            method: Ident::new("as_ref", ident.span()),
+            turbofish: None,
+            paren_token: Paren::default(),
+            args: Punctuated::new(),
+        });
+
+        let receiver = Expr::MethodCall(ExprMethodCall {
+            attrs: Vec::new(),
+            receiver: Box::new(receiver),
+            dot_token: Dot::default(),
+            method: Ident::new("err", ident.span()),
+            turbofish: None,
+            paren_token: Paren::default(),
+            args: Punctuated::new(),
+        });
+
+        let mut args = Punctuated::new();
+        args.push(Expr::Path(ExprPath {
+            attrs: Vec::new(),
+            qself: None,
+            path: path_into_into(PathArguments::None, ident.span()),
+        }));
+        Expr::MethodCall(ExprMethodCall {
+            attrs: Vec::new(),
+            receiver: Box::new(receiver),
+            dot_token: Dot::default(),
+            method: Ident::new("map", ident.span()),
+            turbofish: None,
+            paren_token: Paren::default(),
+            args,
+        })
+    }
+
+    /// `dirk_framework::component::builder::check([a.as_ref().err().map(...), ...])?;`
+    fn build_check(binding_errors: Punctuated<Expr, Comma>, span: Span) -> Stmt {
+        let errors = Expr::Array(ExprArray {
+            attrs: Vec::new(),
+            bracket_token: Bracket::default(),
+            elems: binding_errors,
+        });
+
+        let mut args = Punctuated::new();
+        args.push(errors);
+        let check = Expr::Call(ExprCall {
+            attrs: Vec::new(),
+            func: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: path_builder_check(PathArguments::None, span),
+            })),
+            paren_token: Paren::default(),
+            args,
+        });
+
+        Stmt::Expr(
+            Expr::Try(ExprTry {
+                attrs: Vec::new(),
+                expr: Box::new(check),
+                question_token: Question::default(),
+            }),
+            Some(Semi::default()),
+        )
+    }
+
+    /// `std::result::Result::Ok(<expr>)`
+    fn wrap_ok(expr: Expr, span: Span) -> Expr {
+        let mut args = Punctuated::new();
+        args.push(expr);
+        Expr::Call(ExprCall {
+            attrs: Vec::new(),
+            func: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: path_ok(PathArguments::None, span),
+            })),
+            paren_token: Paren::default(),
+            args,
+        })
+    }
+
+    /// `dirk_framework::component::builder::unwrap_built(Self::try_build(self))`
+    fn unwrap_built(span: Span) -> Expr {
+        let mut path = path_self(PathArguments::None, span);
+        path.segments
+            .push(PathSegment::from(Ident::new("try_build", span)));
+
+        let mut args = Punctuated::new();
+        args.push(Expr::Path(ExprPath {
+            attrs: Vec::new(),
+            qself: None,
+            path: path_small_self(PathArguments::None, span),
+        }));
+        let try_build = Expr::Call(ExprCall {
+            attrs: Vec::new(),
+            func: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path,
+            })),
+            paren_token: Paren::default(),
+            args,
+        });
+
+        let mut args = Punctuated::new();
+        args.push(try_build);
+        Expr::Call(ExprCall {
+            attrs: Vec::new(),
+            func: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: path_builder_unwrap_built(PathArguments::None, span),
+            })),
+            paren_token: Paren::default(),
+            args,
+        })
+    }
+
+    /// `self.dirk_overrides`, moving the overrides out of a builder
+    fn overrides_of_self(span: Span) -> Expr {
+        Self::field_of_self(get_overrides_ident(span))
+    }
+
+    /// `let dirk_overrides = expr;`
+    fn overrides_local(span: Span, expr: Expr) -> Stmt {
+        Self::local(get_overrides_ident(span), expr)
+    }
+
+    /// `self.dirk_config`, moving the configuration out of a builder
+    fn config_of_self(span: Span) -> Expr {
+        Self::field_of_self(get_config_ident(span))
+    }
+
+    /// `let dirk_config = expr;`
+    fn config_local(span: Span, expr: Expr) -> Stmt {
+        Self::local(get_config_ident(span), expr)
+    }
+
+    /// `self.<ident>`
+    fn field_of_self(ident: Ident) -> Expr {
+        let expr_field = ExprField {
+            attrs: Vec::new(),
+            base: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: Path::from(Ident::new("self", ident.span())),
+            })),
+            dot_token: Dot::default(),
+            member: Member::Named(ident),
+        };
+        Expr::Field(expr_field)
+    }
+
+    /// `let <ident> = expr;`
+    fn local(ident: Ident, expr: Expr) -> Stmt {
+        let pat_ident = PatIdent {
+            attrs: Vec::new(),
+            by_ref: None,
+            mutability: None,
+            ident,
+            subpat: None,
+        };
+
+        let local = Local {
+            attrs: Vec::new(),
+            let_token: Let::default(),
+            pat: Pat::Ident(pat_ident),
+            init: Some(LocalInit {
+                eq_token: Eq::default(),
+                expr: Box::new(expr),
+                diverge: None,
+            }),
+            semi_token: Semi::default(),
+        };
+        Stmt::Local(local)
+    }
+}
+
+/// Attributes of items only available in tests, i.e., in unit tests or with the `testing` feature enabled
+fn test_only_attrs(span: Span) -> Vec<Attribute> {
+    let mut attrs = Vec::new();
+    if !cfg!(feature = "testing") {
+        attrs.push(mk_attribute("cfg", quote! { test }, span));
+    }
+    attrs.push(mk_attribute("allow", quote! { dead_code }, span));
+    attrs
 }
@@ -1,33 +1,79 @@
-use std::collections::HashMap;
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
 
+use convert_case::{Case, Casing};
 use itertools::Itertools;
-use proc_macro2::Ident;
+use proc_macro2::{Ident, Span};
 
 use syn::{
     punctuated::Punctuated,
-    token::{Colon, Comma, Dot, Dyn, Eq, Impl, Let, Paren, Semi},
-    Expr, ExprCall, ExprField, ExprMethodCall, ExprPath, Field, FieldValue, FnArg, GenericArgument,
-    GenericParam, Lifetime, Local, LocalInit, Member, Pat, PatIdent, PatType, Path, PathArguments,
-    PathSegment, Stmt, TraitBound, Type, TypeImplTrait, TypeParamBound, TypePath, TypeTraitObject,
+    token::{
+        And, Brace, Bracket, Colon, Comma, Dot, Dyn, Eq, Impl, Let, Mut, Not, Or, Paren, Plus,
+        Semi, Star,
+    },
+    Block, Expr, ExprArray, ExprCall, ExprClosure, ExprField, ExprLit, ExprMacro, ExprMethodCall,
+    ExprPath, ExprReference, ExprStruct, ExprUnary, Field, FieldValue, FnArg, GenericArgument,
+    GenericParam, Lifetime, Lit, LitStr, Local, LocalInit, Macro, MacroDelimiter, Member, Pat,
+    PatIdent, PatType, Path, PathArguments, PathSegment, ReturnType, Stmt, TraitBound, Type,
+    TypeImplTrait, TypeParamBound, TypePath, TypeTraitObject, UnOp,
 };
 
 use crate::{
     expectable::TypeExpectable,
-    syntax::wrap_type,
-    util::{path_rc_new, type_provider, type_rc},
+    syntax::{type_to_string, wrap_type},
+    util::{
+        path_binding_descriptor, path_binding_kind, path_is_materialized, path_none, path_rc_new,
+        path_small_self, path_some, path_unimplemented, type_members_injector, type_provider,
+        type_rc,
+    },
 };
 
 use super::{
     binding::bindable::FactoryBindable, error::ComponentLogicEmit, Binding, ComponentResult,
 };
 
-pub(crate) fn get_dirk_name(base: &Ident, suffix: Option<&str>) -> Ident {
-    let suffix = suffix.unwrap_or("");
-    let name = format!("Dirk{base}{suffix}");
+pub(crate) fn get_dirk_name(base: &Ident) -> Ident {
+    let name = format!("Dirk{base}");
     Ident::new(&name, base.span())
 }
 
+/// Body of a function that is only generated to avoid follow-up errors, i.e., `{ std::unimplemented!() }`
+pub(crate) fn get_stub_block(span: Span) -> Block {
+    let expr_macro = ExprMacro {
+        attrs: Vec::new(),
+        mac: Macro {
+            path: path_unimplemented(PathArguments::None, span),
+            bang_token: Not::default(),
+            delimiter: MacroDelimiter::Paren(Paren::default()),
+            tokens: proc_macro2::TokenStream::new(),
+        },
+    };
+
+    Block {
+        brace_token: Brace::default(),
+        stmts: vec![Stmt::Expr(Expr::Macro(expr_macro), None)],
+    }
+}
+
 pub(crate) fn get_provider_call(ident: &Ident) -> Expr {
+    get_provider_method_call(ident, "get", Punctuated::new())
+}
+
+pub(crate) fn get_members_injector_call(ident: &Ident, target: &Ident) -> Expr {
+    let mut args = Punctuated::new();
+    let target_expr = ExprPath {
+        attrs: Vec::new(),
+        qself: None,
+        path: Path::from(target.clone()),
+    };
+    args.push(Expr::Path(target_expr));
+
+    get_provider_method_call(ident, "inject_members", args)
+}
+
+fn get_provider_method_call(ident: &Ident, method: &str, args: Punctuated<Expr, Comma>) -> Expr {
     let provider_ident = Ident::new(&format!("{ident}_provider"), ident.span());
 
     let mut segments = Punctuated::new();
@@ -58,58 +104,199 @@ pub(crate) fn get_provider_call(ident: &Ident) -> Expr {
     };
     let receiver = Expr::Field(expr_field);
 
-    let get_ident = Ident::new("get", ident.span());
+    let method_ident = Ident::new(method, ident.span());
 
     let method_call = ExprMethodCall {
         attrs: Vec::new(),
         receiver: Box::new(receiver),
         dot_token: Dot::default(),
-        method: get_ident,
+        method: method_ident,
         turbofish: None,
         paren_token: Paren::default(),
-        args: Punctuated::new(),
+        args,
     };
 
     Expr::MethodCall(method_call)
 }
 
-pub(crate) fn get_providers<'bindings>(
+/// Orders the bindings topologically, i.e., every binding comes after all bindings it depends on
+///
+/// Ties are broken by the order of declaration. Bindings that are part of (or depend on) a cycle are appended in
+/// the order of their declaration, such that the cycle is reported when processing them.
+pub(crate) fn sorted_bindings<'bindings>(
     bindings: &HashMap<&'bindings Ident, &'bindings Binding>,
-) -> ComponentResult<(
+) -> Vec<(&'bindings Ident, &'bindings Binding)> {
+    let mut pending = HashMap::new();
+    let mut dependents: HashMap<&Ident, Vec<&Ident>> = HashMap::new();
+
+    for (ident, binding) in bindings {
+        let dependencies = binding
+            .kind()
+            .dependencies()
+            .into_iter()
+            .flatten()
+            .filter_map(|d| bindings.get_key_value(d).map(|(d, _)| *d))
+            .unique()
+            .collect_vec();
+
+        for dependency in &dependencies {
+            dependents.entry(dependency).or_default().push(ident);
+        }
+        pending.insert(*ident, dependencies.len());
+    }
+
+    let mut ready = pending
+        .iter()
+        .filter(|(_, count)| **count == 0)
+        .map(|(ident, _)| Reverse((bindings[ident].index(), *ident)))
+        .collect::<BinaryHeap<_>>();
+
+    let mut res = Vec::with_capacity(bindings.len());
+
+    while let Some(Reverse((_, ident))) = ready.pop() {
+        pending.remove(ident);
+        res.push((ident, bindings[ident]));
+
+        for dependent in dependents.get(ident).into_iter().flatten() {
+            if let Some(count) = pending.get_mut(dependent) {
+                *count -= 1;
+                if *count == 0 {
+                    ready.push(Reverse((bindings[dependent].index(), *dependent)));
+                }
+            }
+        }
+    }
+
+    let cyclic = pending
+        .keys()
+        .map(|ident| (*ident, bindings[ident]))
+        .sorted_by_key(|(_, binding)| binding.index());
+    res.extend(cyclic);
+
+    res
+}
+
+/// Finds the shortest cycle starting at `source` via `dependency`
+///
+/// Returns every binding on the cycle, alongside the entry in its list of dependencies leading to the next binding
+fn find_cycle(
+    bindings: &HashMap<&Ident, &Binding>,
+    source: &Ident,
+    dependency: &Ident,
+) -> Option<Vec<(Ident, Ident)>> {
+    let dependencies_of = |ident: &Ident| {
+        bindings
+            .get(ident)
+            .and_then(|b| b.kind().dependencies())
+            .into_iter()
+            .flatten()
+    };
+
+    // Breadth-first search from `dependency` back to `source`, remembering the entry each binding was reached by
+    let mut reached_by: HashMap<&Ident, &Ident> = HashMap::new();
+    let mut queue = std::collections::VecDeque::from([dependency]);
+    let mut found = dependency == source;
+
+    while let Some(current) = queue.pop_front() {
+        if found {
+            break;
+        }
+        for next in dependencies_of(current) {
+            if next == dependency || reached_by.contains_key(next) {
+                continue;
+            }
+            reached_by.insert(next, current);
+            if next == source {
+                found = true;
+                break;
+            }
+            queue.push_back(next);
+        }
+    }
+
+    if !found {
+        return None;
+    }
+
+    // Walk back from `source` to `dependency`, then close the cycle via the entry at `source`
+    let mut path = vec![source];
+    let mut current = source;
+    while current != dependency {
+        current = reached_by.get(current)?;
+        path.push(current);
+    }
+    path.reverse();
+    path.rotate_right(1);
+
+    let entry = |from: &Ident, to: &Ident| {
+        dependencies_of(from)
+            .find(|d| *d == to)
+            .cloned()
+            .unwrap_or_else(|| to.clone())
+    };
+
+    let cycle = path
+        .iter()
+        .enumerate()
+        .map(|(index, from)| {
+            let to = path[(index + 1) % path.len()];
+            let declared = bindings.get(*from).map_or(*from, |b| b.identifier());
+            (declared.clone(), entry(from, to))
+        })
+        .collect();
+
+    Some(cycle)
+}
+
+/// Fields of the component, values in its constructor, parameters of its constructor, statements instantiating the providers and whether an error has been emitted, such that the constructor needs to be stubbed
+type Providers = (
     Punctuated<Field, Comma>,
     Punctuated<FieldValue, Comma>,
     Punctuated<FnArg, Comma>,
     Vec<Stmt>,
-)> {
+    bool,
+);
+
+pub(crate) fn get_providers<'bindings>(
+    bindings: &HashMap<&'bindings Ident, &'bindings Binding>,
+    overrides_ty: Type,
+) -> ComponentResult<Providers> {
     let mut fields = Punctuated::new();
     let mut field_values = Punctuated::new();
     let mut fn_args = Punctuated::new();
     let mut statements = Vec::new();
 
     let mut processed_bindings = Vec::new();
+    let mut reported_cycles = Vec::new();
+    let mut stubbed = false;
 
-    let processing_bindings = bindings
-        .iter()
-        .sorted_by(|(_, r), (_, l)| r.cmp(l))
-        .map(|(i, b)| (*i, *b));
+    let mut overridable = false;
 
-    for (ident, binding) in processing_bindings
-        .sorted_by(|(i1, _), (i2, _)| Ord::cmp(i1, i2))
-        .sorted_by(|(_, b1), (_, b2)| Ord::cmp(b1, b2))
-    {
+    for (ident, binding) in sorted_bindings(bindings) {
         processed_bindings.push(ident);
 
         if let Some(dependencies) = binding.kind().dependencies() {
             for dependency in dependencies {
-                if !processed_bindings.contains(&dependency) {
+                if bindings
+                    .get(dependency)
+                    .is_some_and(|d| d.kind().is_members())
+                {
+                    ComponentLogicEmit::MembersBindingAsDependency(dependency.clone()).emit();
+                    stubbed = true;
+                } else if !processed_bindings.contains(&dependency) {
                     if bindings.get(dependency).is_some() {
-                        ComponentLogicEmit::CycleDetected(
-                            binding.identifier().clone(),
-                            dependency.clone(),
-                        )
-                        .emit();
+                        if let Some(cycle) = find_cycle(bindings, ident, dependency) {
+                            let mut key = cycle.iter().map(|(i, _)| i.to_string()).collect_vec();
+                            key.sort();
+                            if !reported_cycles.contains(&key) {
+                                reported_cycles.push(key);
+                                ComponentLogicEmit::CycleDetected(cycle).emit();
+                            }
+                            stubbed = true;
+                        }
                     } else {
                         ComponentLogicEmit::NotFound(dependency.clone()).emit();
+                        stubbed = true;
                     }
                 }
             }
@@ -118,37 +305,16 @@ pub(crate) fn get_providers<'bindings>(
         let ty = binding.kind().wrapped_ty()?;
 
         let provider_ident = Ident::new(&format!("{ident}_provider"), ident.span());
-        let provider_ty = wrap_type(ty, type_provider);
-
-        let provider_bounds = {
-            let mut provider_bounds = Punctuated::new();
-
-            let type_path = provider_ty.as_path()?;
-
-            let trait_bound = TraitBound {
-                paren_token: None,
-                modifier: syn::TraitBoundModifier::None,
-                lifetimes: None,
-                path: type_path.path.clone(),
-            };
-            provider_bounds.push(TypeParamBound::Trait(trait_bound));
-
-            let static_bound = Lifetime {
-                apostrophe: ident.span(),
-                ident: Ident::new("static", ident.span()),
-            };
-            provider_bounds.push(TypeParamBound::Lifetime(static_bound));
-
-            provider_bounds
-        };
+        let is_members = binding.kind().is_members();
+        let (rc_dyn_type, provider_bounds) = get_rc_dyn_provider(ty.clone(), is_members, ident)?;
 
-        let trait_object = TypeTraitObject {
-            dyn_token: Some(Dyn::default()),
-            bounds: provider_bounds.clone(),
+        // The provided type is checked against the kind of the binding, such that its uses are not reported if the kind does not match
+        let local_ty = match binding.kind() {
+            super::binding::BindingKind::Automatic(a) => {
+                get_rc_dyn_provider(a.get_checked_ty(ty)?, is_members, ident)?.0
+            }
+            super::binding::BindingKind::Manual(_) => rc_dyn_type.clone(),
         };
-        let dyn_type = Type::TraitObject(trait_object);
-
-        let rc_dyn_type = wrap_type(dyn_type, type_rc);
 
         let field = Field {
             attrs: Vec::new(),
@@ -168,10 +334,14 @@ pub(crate) fn get_providers<'bindings>(
                 qself: None,
                 path: Path::from(provider_ident.clone()),
             });
+            let expr = match binding.kind() {
+                super::binding::BindingKind::Automatic(a) => a.get_checked_expr(expr)?,
+                super::binding::BindingKind::Manual(_) => expr,
+            };
             FieldValue {
                 attrs: Vec::new(),
                 member,
-                colon_token: None,
+                colon_token: Some(Colon::default()),
                 expr,
             }
         };
@@ -185,7 +355,13 @@ pub(crate) fn get_providers<'bindings>(
             ident: Ident::new(&format!("{ident}_provider"), ident.span()),
             subpat: None,
         };
-        let pat = syn::Pat::Ident(pat_ident);
+        // Typed like the field, such that it is passed to the factories depending on it as `Rc<dyn Provider<T>>`
+        let pat = syn::Pat::Type(PatType {
+            attrs: Vec::new(),
+            pat: Box::new(syn::Pat::Ident(pat_ident)),
+            colon_token: Colon::default(),
+            ty: Box::new(local_ty),
+        });
 
         let path = path_rc_new(PathArguments::None, ident.span());
 
@@ -199,8 +375,12 @@ pub(crate) fn get_providers<'bindings>(
 
         let args = match binding.kind() {
             super::binding::BindingKind::Automatic(a) => {
-                let expr_call = a.get_factory_create_call()?;
-                let call = Expr::Call(expr_call);
+                let mut call = a.get_factory_create_call()?;
+
+                if a.override_fn().is_some() {
+                    overridable = true;
+                    call = get_overridden_factory(ident, call);
+                }
 
                 let mut args = Punctuated::new();
                 args.push(call);
@@ -275,7 +455,259 @@ pub(crate) fn get_providers<'bindings>(
         }
     }
 
-    Ok((fields, field_values, fn_args, statements))
+    let overrides_arg = {
+        let span = Span::call_site();
+        let pat_ident = PatIdent {
+            attrs: Vec::new(),
+            by_ref: None,
+            mutability: overridable.then(Mut::default),
+            ident: get_overrides_ident(span),
+            subpat: None,
+        };
+        let pat_type = PatType {
+            attrs: Vec::new(),
+            pat: Box::new(Pat::Ident(pat_ident)),
+            colon_token: Colon::default(),
+            ty: Box::new(overrides_ty),
+        };
+        FnArg::Typed(pat_type)
+    };
+    fn_args.push(overrides_arg);
+
+    Ok((fields, field_values, fn_args, statements, stubbed))
+}
+
+/// `Rc<dyn Provider<T> + 'static>` (or `MembersInjector<T>`) and its bounds
+fn get_rc_dyn_provider(
+    ty: Type,
+    is_members: bool,
+    ident: &Ident,
+) -> ComponentResult<(Type, Punctuated<TypeParamBound, Plus>)> {
+    let provider_ty = if is_members {
+        wrap_type(ty, type_members_injector)
+    } else {
+        wrap_type(ty, type_provider)
+    };
+
+    let provider_bounds = {
+        let mut provider_bounds = Punctuated::new();
+
+        let type_path = provider_ty.as_path()?;
+
+        let trait_bound = TraitBound {
+            paren_token: None,
+            modifier: syn::TraitBoundModifier::None,
+            lifetimes: None,
+            path: type_path.path.clone(),
+        };
+        provider_bounds.push(TypeParamBound::Trait(trait_bound));
+
+        let static_bound = Lifetime {
+            apostrophe: ident.span(),
+            ident: Ident::new("static", ident.span()),
+        };
+        provider_bounds.push(TypeParamBound::Lifetime(static_bound));
+
+        provider_bounds
+    };
+
+    let trait_object = TypeTraitObject {
+        dyn_token: Some(Dyn::default()),
+        bounds: provider_bounds.clone(),
+    };
+    let dyn_type = Type::TraitObject(trait_object);
+
+    Ok((wrap_type(dyn_type, type_rc), provider_bounds))
+}
+
+/// Identifier of the providers replacing automatic bindings, both in the builder and the constructor of a component
+pub(crate) fn get_overrides_ident(span: Span) -> Ident {
+    Ident::new("dirk_overrides", span)
+}
+
+/// `DirkXOverrides`, the type of the providers replacing automatic bindings of component `X`, only collected in tests
+pub(crate) fn get_overrides_ty_ident(base: &Ident) -> Ident {
+    Ident::new(&format!("{}Overrides", get_dirk_name(base)), base.span())
+}
+
+pub(crate) fn get_overrides_ty(base: &Ident) -> Type {
+    Type::Path(TypePath {
+        qself: None,
+        path: Path::from(get_overrides_ty_ident(base)),
+    })
+}
+
+/// Identifier of the configuration held by the builder, from which `config_bind(...)`s are deserialized
+pub(crate) fn get_config_ident(span: Span) -> Ident {
+    Ident::new("dirk_config", span)
+}
+
+/// `dirk_overrides.take_or("ident", || factory)`
+fn get_overridden_factory(ident: &Ident, factory: Expr) -> Expr {
+    let span = ident.span();
+
+    let receiver = ExprPath {
+        attrs: Vec::new(),
+        qself: None,
+        path: Path::from(get_overrides_ident(span)),
+    };
+
+    let closure = ExprClosure {
+        attrs: Vec::new(),
+        lifetimes: None,
+        constness: None,
+        movability: None,
+        asyncness: None,
+        capture: None,
+        or1_token: Or(span),
+        inputs: Punctuated::new(),
+        or2_token: Or(span),
+        output: ReturnType::Default,
+        body: Box::new(factory),
+    };
+
+    let mut args = Punctuated::new();
+    args.push(mk_str_lit(&ident.to_string(), span));
+    args.push(Expr::Closure(closure));
+
+    Expr::MethodCall(ExprMethodCall {
+        attrs: Vec::new(),
+        receiver: Box::new(Expr::Path(receiver)),
+        dot_token: Dot::default(),
+        method: Ident::new("take_or", span),
+        turbofish: None,
+        paren_token: Paren::default(),
+        args,
+    })
+}
+
+fn mk_str_lit(value: &str, span: Span) -> Expr {
+    Expr::Lit(ExprLit {
+        attrs: Vec::new(),
+        lit: Lit::Str(LitStr::new(value, span)),
+    })
+}
+
+pub(crate) fn get_binding_descriptor(ident: &Ident, binding: &Binding) -> ComponentResult<Expr> {
+    let span = ident.span();
+    let kind = binding.kind();
+
+    let mk_field_value = |name: &str, expr: Expr| FieldValue {
+        attrs: Vec::new(),
+        member: Member::Named(Ident::new(name, span)),
+        colon_token: Some(Colon::default()),
+        expr,
+    };
+
+    let kind_expr = {
+        let mut path = path_binding_kind(PathArguments::None, span);
+        path.segments.push(PathSegment {
+            ident: Ident::new(&kind.kind_name().to_case(Case::Pascal), span),
+            arguments: PathArguments::None,
+        });
+        Expr::Path(ExprPath {
+            attrs: Vec::new(),
+            qself: None,
+            path,
+        })
+    };
+
+    let dependencies_expr = {
+        let elems = kind
+            .dependencies()
+            .into_iter()
+            .flatten()
+            .map(|dependency| mk_str_lit(&dependency.to_string(), dependency.span()))
+            .collect();
+        let expr_array = ExprArray {
+            attrs: Vec::new(),
+            bracket_token: Bracket::default(),
+            elems,
+        };
+        Expr::Reference(ExprReference {
+            attrs: Vec::new(),
+            and_token: And::default(),
+            mutability: None,
+            expr: Box::new(Expr::Array(expr_array)),
+        })
+    };
+
+    let materialized_expr = if kind.is_scoped() {
+        let provider_ident = Ident::new(&format!("{ident}_provider"), span);
+        let self_provider = Expr::Field(ExprField {
+            attrs: Vec::new(),
+            base: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: path_small_self(PathArguments::None, span),
+            })),
+            dot_token: Dot::default(),
+            member: Member::Named(provider_ident),
+        });
+        let deref = Expr::Unary(ExprUnary {
+            attrs: Vec::new(),
+            op: UnOp::Deref(Star::default()),
+            expr: Box::new(self_provider),
+        });
+        let reference = Expr::Reference(ExprReference {
+            attrs: Vec::new(),
+            and_token: And::default(),
+            mutability: None,
+            expr: Box::new(deref),
+        });
+
+        let mut args = Punctuated::new();
+        args.push(reference);
+        let is_materialized_call = Expr::Call(ExprCall {
+            attrs: Vec::new(),
+            func: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: path_is_materialized(PathArguments::None, span),
+            })),
+            paren_token: Paren::default(),
+            args,
+        });
+
+        let mut args = Punctuated::new();
+        args.push(is_materialized_call);
+        Expr::Call(ExprCall {
+            attrs: Vec::new(),
+            func: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: path_some(PathArguments::None, span),
+            })),
+            paren_token: Paren::default(),
+            args,
+        })
+    } else {
+        Expr::Path(ExprPath {
+            attrs: Vec::new(),
+            qself: None,
+            path: path_none(PathArguments::None, span),
+        })
+    };
+
+    let mut fields = Punctuated::new();
+    fields.push(mk_field_value("name", mk_str_lit(&ident.to_string(), span)));
+    fields.push(mk_field_value("kind", kind_expr));
+    fields.push(mk_field_value(
+        "type_name",
+        mk_str_lit(&type_to_string(&kind.ty()?), span),
+    ));
+    fields.push(mk_field_value("dependencies", dependencies_expr));
+    fields.push(mk_field_value("materialized", materialized_expr));
+
+    Ok(Expr::Struct(ExprStruct {
+        attrs: Vec::new(),
+        qself: None,
+        path: path_binding_descriptor(PathArguments::None, span),
+        brace_token: Brace::default(),
+        fields,
+        dot2_token: None,
+        rest: None,
+    }))
 }
 
 pub(crate) fn generic_argument_from_generic_param(input: &GenericParam) -> GenericArgument {
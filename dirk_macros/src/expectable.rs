@@ -16,7 +16,7 @@ use syn::{
 use crate::errors::ExpectableError;
 
 #[derive(Debug)]
-pub(crate) struct UnexpectedImplItemKind(ImplItem);
+pub(crate) struct UnexpectedImplItemKind(Box<ImplItem>);
 impl ExpectableError for UnexpectedImplItemKind {
     fn abort(&self) -> ! {
         abort!(
@@ -45,75 +45,75 @@ impl ImplItemExpectable for ImplItem {
         if let ImplItem::Const(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedImplItemKind(self.clone()))
+        Err(UnexpectedImplItemKind(Box::new(self.clone())))
     }
 
     fn as_fn(&self) -> Result<&ImplItemFn, UnexpectedImplItemKind> {
         if let ImplItem::Fn(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedImplItemKind(self.clone()))
+        Err(UnexpectedImplItemKind(Box::new(self.clone())))
     }
 
     fn as_type(&self) -> Result<&ImplItemType, UnexpectedImplItemKind> {
         if let ImplItem::Type(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedImplItemKind(self.clone()))
+        Err(UnexpectedImplItemKind(Box::new(self.clone())))
     }
 
     fn as_macro(&self) -> Result<&ImplItemMacro, UnexpectedImplItemKind> {
         if let ImplItem::Macro(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedImplItemKind(self.clone()))
+        Err(UnexpectedImplItemKind(Box::new(self.clone())))
     }
 
     fn as_verbatim(&self) -> Result<&TokenStream, UnexpectedImplItemKind> {
         if let ImplItem::Verbatim(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedImplItemKind(self.clone()))
+        Err(UnexpectedImplItemKind(Box::new(self.clone())))
     }
 
     fn as_const_mut(&mut self) -> Result<&mut ImplItemConst, UnexpectedImplItemKind> {
         if let ImplItem::Const(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedImplItemKind(self.clone()))
+        Err(UnexpectedImplItemKind(Box::new(self.clone())))
     }
 
     fn as_fn_mut(&mut self) -> Result<&mut ImplItemFn, UnexpectedImplItemKind> {
         if let ImplItem::Fn(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedImplItemKind(self.clone()))
+        Err(UnexpectedImplItemKind(Box::new(self.clone())))
     }
 
     fn as_type_mut(&mut self) -> Result<&mut ImplItemType, UnexpectedImplItemKind> {
         if let ImplItem::Type(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedImplItemKind(self.clone()))
+        Err(UnexpectedImplItemKind(Box::new(self.clone())))
     }
 
     fn as_macro_mut(&mut self) -> Result<&mut ImplItemMacro, UnexpectedImplItemKind> {
         if let ImplItem::Macro(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedImplItemKind(self.clone()))
+        Err(UnexpectedImplItemKind(Box::new(self.clone())))
     }
 
     fn as_verbatim_mut(&mut self) -> Result<&mut TokenStream, UnexpectedImplItemKind> {
         if let ImplItem::Verbatim(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedImplItemKind(self.clone()))
+        Err(UnexpectedImplItemKind(Box::new(self.clone())))
     }
 }
 
 #[derive(Debug)]
-pub(crate) struct UnexpectedTypeKind(Type);
+pub(crate) struct UnexpectedTypeKind(Box<Type>);
 impl ExpectableError for UnexpectedTypeKind {
     fn abort(&self) -> ! {
         abort!(
@@ -162,215 +162,215 @@ impl TypeExpectable for Type {
         if let Type::Array(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_bare_fn(&self) -> Result<&TypeBareFn, UnexpectedTypeKind> {
         if let Type::BareFn(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_group(&self) -> Result<&TypeGroup, UnexpectedTypeKind> {
         if let Type::Group(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_impl_trait(&self) -> Result<&TypeImplTrait, UnexpectedTypeKind> {
         if let Type::ImplTrait(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_infer(&self) -> Result<&TypeInfer, UnexpectedTypeKind> {
         if let Type::Infer(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_macro(&self) -> Result<&TypeMacro, UnexpectedTypeKind> {
         if let Type::Macro(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_never(&self) -> Result<&TypeNever, UnexpectedTypeKind> {
         if let Type::Never(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_paren(&self) -> Result<&TypeParen, UnexpectedTypeKind> {
         if let Type::Paren(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_path(&self) -> Result<&TypePath, UnexpectedTypeKind> {
         if let Type::Path(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_ptr(&self) -> Result<&TypePtr, UnexpectedTypeKind> {
         if let Type::Ptr(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_reference(&self) -> Result<&TypeReference, UnexpectedTypeKind> {
         if let Type::Reference(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_slice(&self) -> Result<&TypeSlice, UnexpectedTypeKind> {
         if let Type::Slice(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_trait_object(&self) -> Result<&TypeTraitObject, UnexpectedTypeKind> {
         if let Type::TraitObject(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_tuple(&self) -> Result<&TypeTuple, UnexpectedTypeKind> {
         if let Type::Tuple(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_verbatim(&self) -> Result<&TokenStream, UnexpectedTypeKind> {
         if let Type::Verbatim(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_array_mut(&mut self) -> Result<&mut TypeArray, UnexpectedTypeKind> {
         if let Type::Array(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_bare_fn_mut(&mut self) -> Result<&mut TypeBareFn, UnexpectedTypeKind> {
         if let Type::BareFn(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_group_mut(&mut self) -> Result<&mut TypeGroup, UnexpectedTypeKind> {
         if let Type::Group(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_impl_trait_mut(&mut self) -> Result<&mut TypeImplTrait, UnexpectedTypeKind> {
         if let Type::ImplTrait(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_infer_mut(&mut self) -> Result<&mut TypeInfer, UnexpectedTypeKind> {
         if let Type::Infer(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_macro_mut(&mut self) -> Result<&mut TypeMacro, UnexpectedTypeKind> {
         if let Type::Macro(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_never_mut(&mut self) -> Result<&mut TypeNever, UnexpectedTypeKind> {
         if let Type::Never(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_paren_mut(&mut self) -> Result<&mut TypeParen, UnexpectedTypeKind> {
         if let Type::Paren(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_path_mut(&mut self) -> Result<&mut TypePath, UnexpectedTypeKind> {
         if let Type::Path(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_ptr_mut(&mut self) -> Result<&mut TypePtr, UnexpectedTypeKind> {
         if let Type::Ptr(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_reference_mut(&mut self) -> Result<&mut TypeReference, UnexpectedTypeKind> {
         if let Type::Reference(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_slice_mut(&mut self) -> Result<&mut TypeSlice, UnexpectedTypeKind> {
         if let Type::Slice(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_trait_object_mut(&mut self) -> Result<&mut TypeTraitObject, UnexpectedTypeKind> {
         if let Type::TraitObject(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_tuple_mut(&mut self) -> Result<&mut TypeTuple, UnexpectedTypeKind> {
         if let Type::Tuple(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 
     fn as_verbatim_mut(&mut self) -> Result<&mut TokenStream, UnexpectedTypeKind> {
         if let Type::Verbatim(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTypeKind(self.clone()))
+        Err(UnexpectedTypeKind(Box::new(self.clone())))
     }
 }
 
 #[derive(Debug)]
-pub(crate) struct UnexpectedPatKind(Pat);
+pub(crate) struct UnexpectedPatKind(Box<Pat>);
 impl ExpectableError for UnexpectedPatKind {
     fn abort(&self) -> ! {
         abort!(
@@ -423,243 +423,243 @@ impl PatExpectable for Pat {
         if let Pat::Const(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_ident(&self) -> Result<&PatIdent, UnexpectedPatKind> {
         if let Pat::Ident(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_lit(&self) -> Result<&PatLit, UnexpectedPatKind> {
         if let Pat::Lit(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_macro(&self) -> Result<&PatMacro, UnexpectedPatKind> {
         if let Pat::Macro(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_or(&self) -> Result<&PatOr, UnexpectedPatKind> {
         if let Pat::Or(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_paren(&self) -> Result<&PatParen, UnexpectedPatKind> {
         if let Pat::Paren(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_path(&self) -> Result<&PatPath, UnexpectedPatKind> {
         if let Pat::Path(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_range(&self) -> Result<&PatRange, UnexpectedPatKind> {
         if let Pat::Range(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_reference(&self) -> Result<&PatReference, UnexpectedPatKind> {
         if let Pat::Reference(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_rest(&self) -> Result<&PatRest, UnexpectedPatKind> {
         if let Pat::Rest(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_slice(&self) -> Result<&PatSlice, UnexpectedPatKind> {
         if let Pat::Slice(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_struct(&self) -> Result<&PatStruct, UnexpectedPatKind> {
         if let Pat::Struct(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_tuple(&self) -> Result<&PatTuple, UnexpectedPatKind> {
         if let Pat::Tuple(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_tuple_struct(&self) -> Result<&PatTupleStruct, UnexpectedPatKind> {
         if let Pat::TupleStruct(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_type(&self) -> Result<&PatType, UnexpectedPatKind> {
         if let Pat::Type(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_verbatim(&self) -> Result<&TokenStream, UnexpectedPatKind> {
         if let Pat::Verbatim(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_wild(&self) -> Result<&PatWild, UnexpectedPatKind> {
         if let Pat::Wild(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_const_mut(&mut self) -> Result<&mut PatConst, UnexpectedPatKind> {
         if let Pat::Const(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_ident_mut(&mut self) -> Result<&mut PatIdent, UnexpectedPatKind> {
         if let Pat::Ident(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_lit_mut(&mut self) -> Result<&mut PatLit, UnexpectedPatKind> {
         if let Pat::Lit(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_macro_mut(&mut self) -> Result<&mut PatMacro, UnexpectedPatKind> {
         if let Pat::Macro(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_or_mut(&mut self) -> Result<&mut PatOr, UnexpectedPatKind> {
         if let Pat::Or(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_paren_mut(&mut self) -> Result<&mut PatParen, UnexpectedPatKind> {
         if let Pat::Paren(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_path_mut(&mut self) -> Result<&mut PatPath, UnexpectedPatKind> {
         if let Pat::Path(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_range_mut(&mut self) -> Result<&mut PatRange, UnexpectedPatKind> {
         if let Pat::Range(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_reference_mut(&mut self) -> Result<&mut PatReference, UnexpectedPatKind> {
         if let Pat::Reference(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_rest_mut(&mut self) -> Result<&mut PatRest, UnexpectedPatKind> {
         if let Pat::Rest(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_slice_mut(&mut self) -> Result<&mut PatSlice, UnexpectedPatKind> {
         if let Pat::Slice(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_struct_mut(&mut self) -> Result<&mut PatStruct, UnexpectedPatKind> {
         if let Pat::Struct(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_tuple_mut(&mut self) -> Result<&mut PatTuple, UnexpectedPatKind> {
         if let Pat::Tuple(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_tuple_struct_mut(&mut self) -> Result<&mut PatTupleStruct, UnexpectedPatKind> {
         if let Pat::TupleStruct(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_type_mut(&mut self) -> Result<&mut PatType, UnexpectedPatKind> {
         if let Pat::Type(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_verbatim_mut(&mut self) -> Result<&mut TokenStream, UnexpectedPatKind> {
         if let Pat::Verbatim(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 
     fn as_wild_mut(&mut self) -> Result<&mut PatWild, UnexpectedPatKind> {
         if let Pat::Wild(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPatKind(self.clone()))
+        Err(UnexpectedPatKind(Box::new(self.clone())))
     }
 }
 
 #[derive(Debug)]
-pub(crate) struct UnexpectedFnArgKind(FnArg);
+pub(crate) struct UnexpectedFnArgKind(Box<FnArg>);
 impl ExpectableError for UnexpectedFnArgKind {
     fn abort(&self) -> ! {
         abort!(
@@ -682,33 +682,33 @@ impl FnArgExpectable for FnArg {
         if let FnArg::Receiver(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedFnArgKind(self.clone()))
+        Err(UnexpectedFnArgKind(Box::new(self.clone())))
     }
 
     fn as_typed(&self) -> Result<&PatType, UnexpectedFnArgKind> {
         if let FnArg::Typed(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedFnArgKind(self.clone()))
+        Err(UnexpectedFnArgKind(Box::new(self.clone())))
     }
 
     fn as_receiver_mut(&mut self) -> Result<&mut Receiver, UnexpectedFnArgKind> {
         if let FnArg::Receiver(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedFnArgKind(self.clone()))
+        Err(UnexpectedFnArgKind(Box::new(self.clone())))
     }
 
     fn as_typed_mut(&mut self) -> Result<&mut PatType, UnexpectedFnArgKind> {
         if let FnArg::Typed(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedFnArgKind(self.clone()))
+        Err(UnexpectedFnArgKind(Box::new(self.clone())))
     }
 }
 
 #[derive(Debug)]
-pub(crate) struct UnexpectedTraitItemKind(TraitItem);
+pub(crate) struct UnexpectedTraitItemKind(Box<TraitItem>);
 impl ExpectableError for UnexpectedTraitItemKind {
     fn abort(&self) -> ! {
         abort!(
@@ -737,75 +737,75 @@ impl TraitItemExpectable for TraitItem {
         if let TraitItem::Const(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTraitItemKind(self.clone()))
+        Err(UnexpectedTraitItemKind(Box::new(self.clone())))
     }
 
     fn as_fn(&self) -> Result<&TraitItemFn, UnexpectedTraitItemKind> {
         if let TraitItem::Fn(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTraitItemKind(self.clone()))
+        Err(UnexpectedTraitItemKind(Box::new(self.clone())))
     }
 
     fn as_type(&self) -> Result<&TraitItemType, UnexpectedTraitItemKind> {
         if let TraitItem::Type(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTraitItemKind(self.clone()))
+        Err(UnexpectedTraitItemKind(Box::new(self.clone())))
     }
 
     fn as_macro(&self) -> Result<&TraitItemMacro, UnexpectedTraitItemKind> {
         if let TraitItem::Macro(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTraitItemKind(self.clone()))
+        Err(UnexpectedTraitItemKind(Box::new(self.clone())))
     }
 
     fn as_verbatim(&self) -> Result<&TokenStream, UnexpectedTraitItemKind> {
         if let TraitItem::Verbatim(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTraitItemKind(self.clone()))
+        Err(UnexpectedTraitItemKind(Box::new(self.clone())))
     }
 
     fn as_const_mut(&mut self) -> Result<&mut TraitItemConst, UnexpectedTraitItemKind> {
         if let TraitItem::Const(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTraitItemKind(self.clone()))
+        Err(UnexpectedTraitItemKind(Box::new(self.clone())))
     }
 
     fn as_fn_mut(&mut self) -> Result<&mut TraitItemFn, UnexpectedTraitItemKind> {
         if let TraitItem::Fn(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTraitItemKind(self.clone()))
+        Err(UnexpectedTraitItemKind(Box::new(self.clone())))
     }
 
     fn as_type_mut(&mut self) -> Result<&mut TraitItemType, UnexpectedTraitItemKind> {
         if let TraitItem::Type(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTraitItemKind(self.clone()))
+        Err(UnexpectedTraitItemKind(Box::new(self.clone())))
     }
 
     fn as_macro_mut(&mut self) -> Result<&mut TraitItemMacro, UnexpectedTraitItemKind> {
         if let TraitItem::Macro(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTraitItemKind(self.clone()))
+        Err(UnexpectedTraitItemKind(Box::new(self.clone())))
     }
 
     fn as_verbatim_mut(&mut self) -> Result<&mut TokenStream, UnexpectedTraitItemKind> {
         if let TraitItem::Verbatim(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedTraitItemKind(self.clone()))
+        Err(UnexpectedTraitItemKind(Box::new(self.clone())))
     }
 }
 
 #[derive(Debug)]
-pub(crate) struct UnexpectedReturnTypeKind(ReturnType);
+pub(crate) struct UnexpectedReturnTypeKind(Box<ReturnType>);
 impl ExpectableError for UnexpectedReturnTypeKind {
     fn abort(&self) -> ! {
         abort!(
@@ -825,19 +825,19 @@ impl ReturnTypeExpectable for ReturnType {
         if let ReturnType::Type(arrow, ty) = self {
             return Ok((arrow, ty));
         }
-        Err(UnexpectedReturnTypeKind(self.clone()))
+        Err(UnexpectedReturnTypeKind(Box::new(self.clone())))
     }
 
     fn as_type_mut(&mut self) -> Result<(&mut RArrow, &mut Box<Type>), UnexpectedReturnTypeKind> {
         if let ReturnType::Type(arrow, ty) = self {
             return Ok((arrow, ty));
         }
-        Err(UnexpectedReturnTypeKind(self.clone()))
+        Err(UnexpectedReturnTypeKind(Box::new(self.clone())))
     }
 }
 
 #[derive(Debug)]
-pub(crate) struct UnexpectedGenericArgumentKind(GenericArgument);
+pub(crate) struct UnexpectedGenericArgumentKind(Box<GenericArgument>);
 impl ExpectableError for UnexpectedGenericArgumentKind {
     fn abort(&self) -> ! {
         abort!(
@@ -868,89 +868,89 @@ impl GenericArgumentExpectable for GenericArgument {
         if let GenericArgument::Lifetime(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericArgumentKind(self.clone()))
+        Err(UnexpectedGenericArgumentKind(Box::new(self.clone())))
     }
 
     fn as_type(&self) -> Result<&Type, UnexpectedGenericArgumentKind> {
         if let GenericArgument::Type(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericArgumentKind(self.clone()))
+        Err(UnexpectedGenericArgumentKind(Box::new(self.clone())))
     }
 
     fn as_const(&self) -> Result<&Expr, UnexpectedGenericArgumentKind> {
         if let GenericArgument::Const(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericArgumentKind(self.clone()))
+        Err(UnexpectedGenericArgumentKind(Box::new(self.clone())))
     }
 
     fn as_assoc_type(&self) -> Result<&AssocType, UnexpectedGenericArgumentKind> {
         if let GenericArgument::AssocType(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericArgumentKind(self.clone()))
+        Err(UnexpectedGenericArgumentKind(Box::new(self.clone())))
     }
 
     fn as_assoc_const(&self) -> Result<&AssocConst, UnexpectedGenericArgumentKind> {
         if let GenericArgument::AssocConst(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericArgumentKind(self.clone()))
+        Err(UnexpectedGenericArgumentKind(Box::new(self.clone())))
     }
 
     fn as_constraint(&self) -> Result<&Constraint, UnexpectedGenericArgumentKind> {
         if let GenericArgument::Constraint(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericArgumentKind(self.clone()))
+        Err(UnexpectedGenericArgumentKind(Box::new(self.clone())))
     }
 
     fn as_lifetime_mut(&mut self) -> Result<&mut Lifetime, UnexpectedGenericArgumentKind> {
         if let GenericArgument::Lifetime(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericArgumentKind(self.clone()))
+        Err(UnexpectedGenericArgumentKind(Box::new(self.clone())))
     }
 
     fn as_type_mut(&mut self) -> Result<&mut Type, UnexpectedGenericArgumentKind> {
         if let GenericArgument::Type(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericArgumentKind(self.clone()))
+        Err(UnexpectedGenericArgumentKind(Box::new(self.clone())))
     }
 
     fn as_const_mut(&mut self) -> Result<&mut Expr, UnexpectedGenericArgumentKind> {
         if let GenericArgument::Const(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericArgumentKind(self.clone()))
+        Err(UnexpectedGenericArgumentKind(Box::new(self.clone())))
     }
 
     fn as_assoc_type_mut(&mut self) -> Result<&mut AssocType, UnexpectedGenericArgumentKind> {
         if let GenericArgument::AssocType(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericArgumentKind(self.clone()))
+        Err(UnexpectedGenericArgumentKind(Box::new(self.clone())))
     }
 
     fn as_assoc_const_mut(&mut self) -> Result<&mut AssocConst, UnexpectedGenericArgumentKind> {
         if let GenericArgument::AssocConst(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericArgumentKind(self.clone()))
+        Err(UnexpectedGenericArgumentKind(Box::new(self.clone())))
     }
 
     fn as_constraint_mut(&mut self) -> Result<&mut Constraint, UnexpectedGenericArgumentKind> {
         if let GenericArgument::Constraint(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericArgumentKind(self.clone()))
+        Err(UnexpectedGenericArgumentKind(Box::new(self.clone())))
     }
 }
 
 #[derive(Debug)]
-pub(crate) struct UnexpectedPathArgumentsKind(pub(crate) PathArguments);
+pub(crate) struct UnexpectedPathArgumentsKind(pub(crate) Box<PathArguments>);
 impl ExpectableError for UnexpectedPathArgumentsKind {
     fn abort(&self) -> ! {
         abort!(
@@ -983,7 +983,7 @@ impl PathArgumentsExpectable for PathArguments {
         if let PathArguments::None = self {
             return Ok(());
         }
-        Err(UnexpectedPathArgumentsKind(self.clone()))
+        Err(UnexpectedPathArgumentsKind(Box::new(self.clone())))
     }
 
     fn as_angle_bracketed(
@@ -992,7 +992,7 @@ impl PathArgumentsExpectable for PathArguments {
         if let PathArguments::AngleBracketed(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPathArgumentsKind(self.clone()))
+        Err(UnexpectedPathArgumentsKind(Box::new(self.clone())))
     }
 
     fn as_parenthesized(
@@ -1001,7 +1001,7 @@ impl PathArgumentsExpectable for PathArguments {
         if let PathArguments::Parenthesized(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPathArgumentsKind(self.clone()))
+        Err(UnexpectedPathArgumentsKind(Box::new(self.clone())))
     }
 
     fn as_angle_bracketed_mut(
@@ -1010,7 +1010,7 @@ impl PathArgumentsExpectable for PathArguments {
         if let PathArguments::AngleBracketed(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPathArgumentsKind(self.clone()))
+        Err(UnexpectedPathArgumentsKind(Box::new(self.clone())))
     }
 
     fn as_parenthesized_mut(
@@ -1019,12 +1019,12 @@ impl PathArgumentsExpectable for PathArguments {
         if let PathArguments::Parenthesized(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedPathArgumentsKind(self.clone()))
+        Err(UnexpectedPathArgumentsKind(Box::new(self.clone())))
     }
 }
 
 #[derive(Debug)]
-pub(crate) struct UnexpectedGenericParamKind(GenericParam);
+pub(crate) struct UnexpectedGenericParamKind(Box<GenericParam>);
 impl ExpectableError for UnexpectedGenericParamKind {
     fn abort(&self) -> ! {
         abort!(
@@ -1049,41 +1049,41 @@ impl GenericParamExpectable for GenericParam {
         if let GenericParam::Lifetime(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericParamKind(self.clone()))
+        Err(UnexpectedGenericParamKind(Box::new(self.clone())))
     }
 
     fn as_type(&self) -> Result<&TypeParam, UnexpectedGenericParamKind> {
         if let GenericParam::Type(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericParamKind(self.clone()))
+        Err(UnexpectedGenericParamKind(Box::new(self.clone())))
     }
 
     fn as_const(&self) -> Result<&ConstParam, UnexpectedGenericParamKind> {
         if let GenericParam::Const(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericParamKind(self.clone()))
+        Err(UnexpectedGenericParamKind(Box::new(self.clone())))
     }
 
     fn as_lifetime_mut(&mut self) -> Result<&mut LifetimeParam, UnexpectedGenericParamKind> {
         if let GenericParam::Lifetime(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericParamKind(self.clone()))
+        Err(UnexpectedGenericParamKind(Box::new(self.clone())))
     }
 
     fn as_type_mut(&mut self) -> Result<&mut TypeParam, UnexpectedGenericParamKind> {
         if let GenericParam::Type(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericParamKind(self.clone()))
+        Err(UnexpectedGenericParamKind(Box::new(self.clone())))
     }
 
     fn as_const_mut(&mut self) -> Result<&mut ConstParam, UnexpectedGenericParamKind> {
         if let GenericParam::Const(inner) = self {
             return Ok(inner);
         }
-        Err(UnexpectedGenericParamKind(self.clone()))
+        Err(UnexpectedGenericParamKind(Box::new(self.clone())))
     }
 }
@@ -0,0 +1,70 @@
+use proc_macro_error::abort;
+use syn::{Fields, ItemStruct};
+
+use crate::{
+    errors::ExpectableError,
+    errors::{InfallibleError, SyntaxError},
+};
+
+pub(crate) type InjectMembersResult<T> = std::result::Result<T, InjectMembersError>;
+
+#[derive(Debug)]
+pub(crate) enum InjectMembersError {
+    Infallible(InfallibleError<InjectMembersSyntaxError>),
+    Logic(Box<InjectMembersLogicError>),
+}
+
+impl_abort!(InjectMembersError);
+impl_from_infallible_error!(InjectMembersError, InjectMembersSyntaxError);
+
+#[derive(Debug)]
+pub(crate) enum InjectMembersSyntaxError {
+    FailedToParseInput(syn::Error),
+    ExpectedStruct(syn::Error),
+    FailedToParseFieldAttribute(syn::Error),
+}
+
+impl SyntaxError for InjectMembersSyntaxError {
+    fn abort(self) -> ! {
+        match self {
+            Self::ExpectedStruct(e) => abort!(
+                e.span(),
+                e.to_string();
+                help = "`#[inject_members]` is expected to be placed on a struct"
+            ),
+            Self::FailedToParseInput(e) => abort!(e.span(), e.to_string()),
+            Self::FailedToParseFieldAttribute(e) => abort!(
+                e.span(),
+                e.to_string();
+                help = "`#[inject]` does not take any arguments"
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum InjectMembersLogicError {
+    ExpectedNamedFields(Fields),
+    NoInjectedFields(Box<ItemStruct>),
+}
+
+impl From<InjectMembersLogicError> for InjectMembersError {
+    fn from(value: InjectMembersLogicError) -> Self {
+        Self::Logic(Box::new(value))
+    }
+}
+
+impl InjectMembersLogicError {
+    fn abort(self) -> ! {
+        match self {
+            InjectMembersLogicError::ExpectedNamedFields(fields) => abort!(
+                fields,
+                "#[inject_members] is supposed to be placed on a struct with named fields"
+            ),
+            InjectMembersLogicError::NoInjectedFields(item_struct) => abort!(
+                item_struct.ident,
+                "#[inject_members] is supposed to be placed on a struct containing at least one field marked `#[inject]`"
+            ),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse::Parse, Path, Token};
+
+use crate::util::with_framework_path;
+
+use self::{
+    error::InjectMembersResult,
+    processor::{InjectMembersMacroData, InjectMembersMacroProcessor},
+};
+
+mod error;
+mod processor;
+
+pub(crate) fn _macro(attr: TokenStream, item: TokenStream) -> InjectMembersResult<TokenStream> {
+    let data = InjectMembersMacroData::new(attr, item);
+    let processor = InjectMembersMacroProcessor::new(&data);
+    let framework = data.framework_path()?;
+
+    with_framework_path(framework, || processor.process()).map(|items| {
+        let expanded = quote! { #(#items)* };
+        TokenStream::from(expanded)
+    })
+}
+
+#[derive(Debug, Default)]
+struct InjectMembersMacroInput {
+    /// Path of the framework, e.g., `crate = platform::di`
+    krate: Option<Path>,
+}
+
+impl Parse for InjectMembersMacroInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let lookahead = input.lookahead1();
+        if !lookahead.peek(Token![crate]) {
+            return Err(lookahead.error());
+        }
+        input.parse::<Token![crate]>()?;
+        input.parse::<Token![=]>()?;
+        let krate = Some(input.call(Path::parse_mod_style)?);
+        input.parse::<Option<Token![,]>>()?;
+
+        if !input.is_empty() {
+            let lookahead = input.lookahead1();
+            return Err(lookahead.error());
+        }
+
+        Ok(Self { krate })
+    }
+}
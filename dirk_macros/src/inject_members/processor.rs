@@ -0,0 +1,810 @@
+use std::cell::OnceCell;
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::{
+        And, Brace, Colon, Comma, Dot, Dyn, Eq, For, Gt, Impl, Let, Lt, Mut, Paren, Pub, SelfValue,
+        Semi, Struct,
+    },
+    AngleBracketedGenericArguments, Block, Expr, ExprAssign, ExprCall, ExprField, ExprMethodCall,
+    ExprPath, ExprStruct, Field, FieldValue, Fields, FieldsNamed, FnArg, GenericParam, Generics,
+    Ident, ImplItem, ImplItemFn, Item, ItemImpl, ItemStruct, Local, LocalInit, Member, Pat,
+    PatIdent, PatTuple, PatType, Path, PathArguments, PathSegment, Receiver, ReturnType, Signature,
+    Stmt, TraitBound, Type, TypeParamBound, TypePath, TypeReference, TypeTraitObject, TypeTuple,
+    VisRestricted, Visibility,
+};
+
+use crate::{
+    errors::InfallibleResult,
+    lint::mk_attribute,
+    provides::syntax::map_generic_params,
+    syntax::{mk_fn, wrap_path, wrap_type},
+    util::{path_crate, path_members_injector, path_self, path_self_new, type_provider, type_rc},
+    FACTORY_PREFIX_MEMBERS,
+};
+
+use super::{
+    error::{InjectMembersLogicError, InjectMembersResult, InjectMembersSyntaxError},
+    InjectMembersMacroInput,
+};
+
+pub(crate) struct InjectMembersMacroData {
+    attr: TokenStream,
+    item: TokenStream,
+
+    input_macro: OnceCell<InjectMembersMacroInput>,
+    input_struct: OnceCell<ItemStruct>,
+}
+
+impl InjectMembersMacroData {
+    pub(crate) fn new(attr: TokenStream, item: TokenStream) -> Self {
+        Self {
+            attr,
+            item,
+            input_macro: OnceCell::new(),
+            input_struct: OnceCell::new(),
+        }
+    }
+}
+
+impl InjectMembersMacroData {
+    pub(crate) fn framework_path(
+        &self,
+    ) -> InfallibleResult<Option<&Path>, InjectMembersSyntaxError> {
+        Ok(self.input_macro()?.krate.as_ref())
+    }
+
+    fn input_macro(&self) -> InfallibleResult<&InjectMembersMacroInput, InjectMembersSyntaxError> {
+        if let Some(cached) = self.input_macro.get() {
+            return Ok(cached);
+        }
+
+        let input_macro = {
+            let attr = self.attr.clone();
+
+            syn::parse::<InjectMembersMacroInput>(attr)
+                .map_err(InjectMembersSyntaxError::FailedToParseInput)?
+        };
+
+        Ok(self.input_macro.get_or_init(|| input_macro))
+    }
+
+    fn input_struct(&self) -> InfallibleResult<&ItemStruct, InjectMembersSyntaxError> {
+        if let Some(cached) = self.input_struct.get() {
+            return Ok(cached);
+        }
+
+        let input_struct = {
+            let item = self.item.clone();
+
+            syn::parse::<ItemStruct>(item).map_err(InjectMembersSyntaxError::ExpectedStruct)?
+        };
+
+        Ok(self.input_struct.get_or_init(|| input_struct))
+    }
+}
+
+pub(crate) struct InjectMembersMacroProcessor<'data> {
+    data: &'data InjectMembersMacroData,
+
+    injected_fields: OnceCell<Vec<(&'data Ident, &'data Type)>>,
+
+    target_ty: OnceCell<Type>,
+    injector_ty: OnceCell<Type>,
+
+    generics: OnceCell<Generics>,
+}
+
+impl<'data> InjectMembersMacroProcessor<'data> {
+    pub(crate) fn new(data: &'data InjectMembersMacroData) -> Self {
+        Self {
+            data,
+
+            injected_fields: OnceCell::new(),
+
+            target_ty: OnceCell::new(),
+            injector_ty: OnceCell::new(),
+
+            generics: OnceCell::new(),
+        }
+    }
+}
+
+impl<'data> InjectMembersMacroProcessor<'data> {
+    fn is_inject_attribute(attr: &syn::Attribute) -> bool {
+        attr.path().is_ident("inject")
+    }
+
+    fn fields_named(&self) -> InjectMembersResult<&'data FieldsNamed> {
+        let input_struct = self.data.input_struct()?;
+
+        match &input_struct.fields {
+            Fields::Named(fields_named) => Ok(fields_named),
+            fields => Err(InjectMembersLogicError::ExpectedNamedFields(fields.clone()))?,
+        }
+    }
+
+    fn injected_fields(&self) -> InjectMembersResult<&Vec<(&'data Ident, &'data Type)>> {
+        if let Some(cached) = self.injected_fields.get() {
+            return Ok(cached);
+        }
+
+        let injected_fields = {
+            let input_struct = self.data.input_struct()?;
+            let fields_named = self.fields_named()?;
+
+            let mut injected_fields = Vec::new();
+
+            for field in &fields_named.named {
+                let mut is_injected = false;
+
+                for attr in field.attrs.iter().filter(|a| Self::is_inject_attribute(a)) {
+                    attr.meta
+                        .require_path_only()
+                        .map_err(InjectMembersSyntaxError::FailedToParseFieldAttribute)?;
+                    is_injected = true;
+                }
+
+                if is_injected {
+                    let ident = field
+                        .ident
+                        .as_ref()
+                        .expect("Named fields are supposed to have an identifier");
+                    injected_fields.push((ident, &field.ty));
+                }
+            }
+
+            if injected_fields.is_empty() {
+                Err(InjectMembersLogicError::NoInjectedFields(Box::new(
+                    input_struct.clone(),
+                )))?;
+            }
+
+            injected_fields
+        };
+
+        Ok(self.injected_fields.get_or_init(|| injected_fields))
+    }
+
+    fn generics(&self) -> InjectMembersResult<&Generics> {
+        if let Some(cached) = self.generics.get() {
+            return Ok(cached);
+        }
+
+        let generics = {
+            let input_struct = self.data.input_struct()?;
+
+            let mut generics = input_struct.generics.clone();
+            for param in &mut generics.params {
+                if let GenericParam::Type(type_param) = param {
+                    type_param.eq_token = None;
+                    type_param.default = None;
+                }
+            }
+
+            generics
+        };
+
+        Ok(self.generics.get_or_init(|| generics))
+    }
+
+    fn generic_args(&self) -> InjectMembersResult<PathArguments> {
+        let generics = self.generics()?;
+
+        if generics.params.is_empty() {
+            return Ok(PathArguments::None);
+        }
+
+        let args = map_generic_params(generics.params.clone());
+        let angle_bracketed = AngleBracketedGenericArguments {
+            colon2_token: None,
+            lt_token: Lt::default(),
+            args,
+            gt_token: Gt::default(),
+        };
+
+        Ok(PathArguments::AngleBracketed(angle_bracketed))
+    }
+
+    fn target_ty(&self) -> InjectMembersResult<&Type> {
+        if let Some(cached) = self.target_ty.get() {
+            return Ok(cached);
+        }
+
+        let target_ty = {
+            let input_struct = self.data.input_struct()?;
+            let generic_args = self.generic_args()?;
+
+            let mut segments = Punctuated::new();
+            let segment = PathSegment {
+                ident: input_struct.ident.clone(),
+                arguments: generic_args,
+            };
+            segments.push(segment);
+
+            let path = Path {
+                leading_colon: None,
+                segments,
+            };
+            let type_path = TypePath { qself: None, path };
+
+            Type::Path(type_path)
+        };
+
+        Ok(self.target_ty.get_or_init(|| target_ty))
+    }
+
+    fn injector_ident(&self) -> InjectMembersResult<Ident> {
+        let input_struct = self.data.input_struct()?;
+        let ident = &input_struct.ident;
+
+        Ok(Ident::new(
+            &format!("{FACTORY_PREFIX_MEMBERS}{ident}"),
+            ident.span(),
+        ))
+    }
+
+    fn injector_ty(&self) -> InjectMembersResult<&Type> {
+        if let Some(cached) = self.injector_ty.get() {
+            return Ok(cached);
+        }
+
+        let injector_ty = {
+            let injector_ident = self.injector_ident()?;
+            let generic_args = self.generic_args()?;
+
+            let mut segments = Punctuated::new();
+            let segment = PathSegment {
+                ident: injector_ident,
+                arguments: generic_args,
+            };
+            segments.push(segment);
+
+            let path = Path {
+                leading_colon: None,
+                segments,
+            };
+            let type_path = TypePath { qself: None, path };
+
+            Type::Path(type_path)
+        };
+
+        Ok(self.injector_ty.get_or_init(|| injector_ty))
+    }
+
+    fn provider_ident(ident: &Ident) -> Ident {
+        Ident::new(&format!("{ident}_provider"), ident.span())
+    }
+
+    fn provider_ty(ty: &Type) -> Type {
+        let provider_type = wrap_type(ty.clone(), type_provider);
+
+        let path = match provider_type {
+            Type::Path(type_path) => type_path.path,
+            _ => unreachable!("A provider type is always a path"),
+        };
+
+        let trait_bound = TraitBound {
+            paren_token: None,
+            modifier: syn::TraitBoundModifier::None,
+            lifetimes: None,
+            path,
+        };
+        let mut bounds = Punctuated::new();
+        bounds.push(TypeParamBound::Trait(trait_bound));
+
+        let trait_object = TypeTraitObject {
+            dyn_token: Some(Dyn::default()),
+            bounds,
+        };
+        let dyn_type = Type::TraitObject(trait_object);
+
+        wrap_type(dyn_type, type_rc)
+    }
+
+    fn output_struct(&self) -> InjectMembersResult<ItemStruct> {
+        let mut output_struct = self.data.input_struct()?.clone();
+
+        if let Fields::Named(fields_named) = &mut output_struct.fields {
+            for field in &mut fields_named.named {
+                field.attrs.retain(|a| !Self::is_inject_attribute(a));
+            }
+        }
+
+        Ok(output_struct)
+    }
+
+    fn providers_args(&self) -> InjectMembersResult<Punctuated<FnArg, Comma>> {
+        let injected_fields = self.injected_fields()?;
+
+        let mut fn_args = Punctuated::new();
+
+        for (ident, ty) in injected_fields {
+            let pat_ident = PatIdent {
+                attrs: Vec::new(),
+                by_ref: None,
+                mutability: None,
+                ident: Self::provider_ident(ident),
+                subpat: None,
+            };
+            let pat_type = PatType {
+                attrs: Vec::new(),
+                pat: Box::new(Pat::Ident(pat_ident)),
+                colon_token: Colon::default(),
+                ty: Box::new(Self::provider_ty(ty)),
+            };
+
+            fn_args.push(FnArg::Typed(pat_type));
+        }
+
+        Ok(fn_args)
+    }
+
+    /// `dependencies: (Rc<dyn Provider<T>>, ...)`, the only argument of `create_checked()`, and `let (<ident>_provider, ...) = dependencies;`
+    fn dependencies_arg(
+        &self,
+        span: Span,
+    ) -> InjectMembersResult<(Punctuated<FnArg, Comma>, Stmt)> {
+        let injected_fields = self.injected_fields()?;
+        let dependencies_ident = Ident::new("dependencies", span);
+
+        let mut elems = Punctuated::new();
+        let mut pats = Punctuated::new();
+        for (ident, ty) in injected_fields {
+            elems.push(Self::provider_ty(ty));
+            pats.push(Pat::Ident(PatIdent {
+                attrs: Vec::new(),
+                by_ref: None,
+                mutability: None,
+                ident: Self::provider_ident(ident),
+                subpat: None,
+            }));
+        }
+        if elems.len() == 1 {
+            elems.push_punct(Comma::default());
+            pats.push_punct(Comma::default());
+        }
+
+        let mut fn_args = Punctuated::new();
+        fn_args.push(FnArg::Typed(PatType {
+            attrs: Vec::new(),
+            pat: Box::new(Pat::Ident(PatIdent {
+                attrs: Vec::new(),
+                by_ref: None,
+                mutability: None,
+                ident: dependencies_ident.clone(),
+                subpat: None,
+            })),
+            colon_token: Colon::default(),
+            ty: Box::new(Type::Tuple(TypeTuple {
+                paren_token: Paren::default(),
+                elems,
+            })),
+        }));
+
+        let destructure = Stmt::Local(Local {
+            attrs: Vec::new(),
+            let_token: Let::default(),
+            pat: Pat::Tuple(PatTuple {
+                attrs: Vec::new(),
+                paren_token: Paren::default(),
+                elems: pats,
+            }),
+            init: Some(LocalInit {
+                eq_token: Eq::default(),
+                expr: Box::new(Expr::Path(ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: Path::from(dependencies_ident),
+                })),
+                diverge: None,
+            }),
+            semi_token: Semi::default(),
+        });
+
+        Ok((fn_args, destructure))
+    }
+
+    fn providers_fields(&self) -> InjectMembersResult<Punctuated<Field, Comma>> {
+        let injected_fields = self.injected_fields()?;
+
+        let mut fields = Punctuated::new();
+
+        for (ident, ty) in injected_fields {
+            let field = Field {
+                attrs: Vec::new(),
+                vis: Visibility::Inherited,
+                mutability: syn::FieldMutability::None,
+                ident: Some(Self::provider_ident(ident)),
+                colon_token: Some(Colon::default()),
+                ty: Self::provider_ty(ty),
+            };
+
+            fields.push(field);
+        }
+
+        Ok(fields)
+    }
+
+    fn providers_field_values(&self) -> InjectMembersResult<Punctuated<FieldValue, Comma>> {
+        let injected_fields = self.injected_fields()?;
+
+        let mut field_values = Punctuated::new();
+
+        for (ident, _ty) in injected_fields {
+            let provider_ident = Self::provider_ident(ident);
+
+            let field_value = FieldValue {
+                attrs: Vec::new(),
+                member: Member::Named(provider_ident.clone()),
+                colon_token: None,
+                expr: Expr::Path(ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: Path::from(provider_ident),
+                }),
+            };
+
+            field_values.push(field_value);
+        }
+
+        Ok(field_values)
+    }
+
+    fn providers_field_exprs(&self) -> InjectMembersResult<Punctuated<Expr, Comma>> {
+        let injected_fields = self.injected_fields()?;
+
+        let mut field_exprs = Punctuated::new();
+
+        for (ident, _ty) in injected_fields {
+            let field_expr = Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: Path::from(Self::provider_ident(ident)),
+            });
+
+            field_exprs.push(field_expr);
+        }
+
+        Ok(field_exprs)
+    }
+
+    fn assignments(&self, target_ident: &Ident) -> InjectMembersResult<Vec<Stmt>> {
+        let injected_fields = self.injected_fields()?;
+
+        let mut stmts = Vec::new();
+
+        for (ident, _ty) in injected_fields {
+            let left = {
+                let expr_path = ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: Path::from(target_ident.clone()),
+                };
+                let expr_field = ExprField {
+                    attrs: Vec::new(),
+                    base: Box::new(Expr::Path(expr_path)),
+                    dot_token: Dot::default(),
+                    member: Member::Named((*ident).clone()),
+                };
+                Expr::Field(expr_field)
+            };
+
+            let right = {
+                let expr_path = ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: Path::from(Ident::new("self", ident.span())),
+                };
+                let expr_field = ExprField {
+                    attrs: Vec::new(),
+                    base: Box::new(Expr::Path(expr_path)),
+                    dot_token: Dot::default(),
+                    member: Member::Named(Self::provider_ident(ident)),
+                };
+                let expr_method_call = ExprMethodCall {
+                    attrs: Vec::new(),
+                    receiver: Box::new(Expr::Field(expr_field)),
+                    dot_token: Dot::default(),
+                    method: Ident::new("get", ident.span()),
+                    turbofish: None,
+                    paren_token: Paren::default(),
+                    args: Punctuated::new(),
+                };
+                Expr::MethodCall(expr_method_call)
+            };
+
+            let expr_assign = ExprAssign {
+                attrs: Vec::new(),
+                left: Box::new(left),
+                eq_token: Eq::default(),
+                right: Box::new(right),
+            };
+
+            stmts.push(Stmt::Expr(Expr::Assign(expr_assign), Some(Semi::default())));
+        }
+
+        Ok(stmts)
+    }
+
+    pub(crate) fn process(self) -> InjectMembersResult<Vec<Item>> {
+        let _input_macro = self.data.input_macro()?;
+        let output_struct = self.output_struct()?;
+
+        let span = output_struct.ident.span();
+
+        let generics = self.generics()?;
+        let target_ty = self.target_ty()?;
+        let injector_ident = self.injector_ident()?;
+        let injector_ty = self.injector_ty()?;
+
+        let providers_args = self.providers_args()?;
+        let providers_fields = self.providers_fields()?;
+        let providers_field_values = self.providers_field_values()?;
+        let providers_field_exprs = self.providers_field_exprs()?;
+
+        let struct_injector = {
+            let vis_restricted = VisRestricted {
+                pub_token: Pub::default(),
+                paren_token: Paren::default(),
+                in_token: None,
+                path: Box::new(path_crate(PathArguments::None, injector_ident.span())),
+            };
+
+            let fields = Fields::Named(FieldsNamed {
+                brace_token: Brace::default(),
+                named: providers_fields,
+            });
+
+            ItemStruct {
+                attrs: Vec::new(),
+                vis: Visibility::Restricted(vis_restricted),
+                struct_token: Struct::default(),
+                ident: injector_ident,
+                generics: generics.clone(),
+                fields,
+                semi_token: None,
+            }
+        };
+
+        let impl_members_injector_for_injector = {
+            let inject_members_fn = {
+                let target_ident = Ident::new("target", span);
+
+                let mut inputs = Punctuated::new();
+                let type_path = TypePath {
+                    qself: None,
+                    path: path_self(PathArguments::None, span),
+                };
+                let self_ty = Type::Path(type_path);
+                let self_ref = Type::Reference(TypeReference {
+                    and_token: And::default(),
+                    lifetime: None,
+                    mutability: None,
+                    elem: Box::new(self_ty),
+                });
+                let self_arg = FnArg::Receiver(Receiver {
+                    attrs: Vec::new(),
+                    reference: Some((And::default(), None)),
+                    mutability: None,
+                    self_token: SelfValue::default(),
+                    colon_token: None,
+                    ty: Box::new(self_ref),
+                });
+                inputs.push(self_arg);
+
+                let target_ref = Type::Reference(TypeReference {
+                    and_token: And::default(),
+                    lifetime: None,
+                    mutability: Some(Mut::default()),
+                    elem: Box::new(target_ty.clone()),
+                });
+                let pat_ident = PatIdent {
+                    attrs: Vec::new(),
+                    by_ref: None,
+                    mutability: None,
+                    ident: target_ident.clone(),
+                    subpat: None,
+                };
+                let target_arg = FnArg::Typed(PatType {
+                    attrs: Vec::new(),
+                    pat: Box::new(Pat::Ident(pat_ident)),
+                    colon_token: Colon::default(),
+                    ty: Box::new(target_ref),
+                });
+                inputs.push(target_arg);
+
+                let block = Block {
+                    brace_token: Brace::default(),
+                    stmts: self.assignments(&target_ident)?,
+                };
+
+                let sig = Signature {
+                    constness: None,
+                    asyncness: None,
+                    unsafety: None,
+                    abi: None,
+                    fn_token: syn::token::Fn::default(),
+                    ident: Ident::new("inject_members", span),
+                    generics: Generics::default(),
+                    paren_token: Paren::default(),
+                    inputs,
+                    variadic: None,
+                    output: ReturnType::Default,
+                };
+
+                ImplItem::Fn(ImplItemFn {
+                    attrs: Vec::new(),
+                    vis: Visibility::Inherited,
+                    defaultness: None,
+                    sig,
+                    block,
+                })
+            };
+
+            let members_injector_path = wrap_path(target_ty.clone(), path_members_injector);
+
+            ItemImpl {
+                attrs: Vec::new(),
+                defaultness: None,
+                unsafety: None,
+                impl_token: Impl::default(),
+                generics: generics.clone(),
+                trait_: Some((None, members_injector_path, For::default())),
+                self_ty: Box::new(injector_ty.clone()),
+                brace_token: Brace::default(),
+                items: vec![inject_members_fn],
+            }
+        };
+
+        let impl_injector = {
+            let new_fn = {
+                let self_path = path_self(PathArguments::None, span);
+                let type_path = TypePath {
+                    qself: None,
+                    path: self_path.clone(),
+                };
+                let self_ty = Type::Path(type_path);
+
+                let expr_struct = ExprStruct {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: self_path,
+                    brace_token: Brace::default(),
+                    fields: providers_field_values,
+                    dot2_token: None,
+                    rest: None,
+                };
+                let stmt = Stmt::Expr(Expr::Struct(expr_struct), None);
+                let block = Block {
+                    brace_token: Brace::default(),
+                    stmts: vec![stmt],
+                };
+
+                mk_fn(
+                    Ident::new("new", span),
+                    Visibility::Inherited,
+                    Generics::default(),
+                    providers_args.clone(),
+                    self_ty,
+                    block,
+                )
+            };
+
+            let create_fn = {
+                let type_path = TypePath {
+                    qself: None,
+                    path: path_self(PathArguments::None, span),
+                };
+                let self_ty = Type::Path(type_path);
+
+                let expr_new = ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: path_self_new(PathArguments::None, span),
+                };
+                let expr_call = ExprCall {
+                    attrs: Vec::new(),
+                    func: Box::new(Expr::Path(expr_new)),
+                    paren_token: Paren::default(),
+                    args: providers_field_exprs.clone(),
+                };
+                let stmt = Stmt::Expr(Expr::Call(expr_call), None);
+                let block = Block {
+                    brace_token: Brace::default(),
+                    stmts: vec![stmt],
+                };
+
+                let visibility = VisRestricted {
+                    pub_token: Pub::default(),
+                    paren_token: Paren::default(),
+                    in_token: None,
+                    path: Box::new(path_crate(PathArguments::None, span)),
+                };
+
+                mk_fn(
+                    Ident::new("create", span),
+                    Visibility::Restricted(visibility),
+                    Generics::default(),
+                    providers_args,
+                    self_ty,
+                    block,
+                )
+            };
+
+            // Called by components, like `create_checked()` of the factories generated by `#[provides]`
+            let create_checked_fn = {
+                let type_path = TypePath {
+                    qself: None,
+                    path: path_self(PathArguments::None, span),
+                };
+                let self_ty = Type::Path(type_path.clone());
+
+                let expr_call = ExprCall {
+                    attrs: Vec::new(),
+                    func: Box::new(Expr::Path(ExprPath {
+                        attrs: Vec::new(),
+                        qself: None,
+                        path: {
+                            let mut path = type_path.path;
+                            path.segments
+                                .push(PathSegment::from(Ident::new("create", span)));
+                            path
+                        },
+                    })),
+                    paren_token: Paren::default(),
+                    args: providers_field_exprs,
+                };
+                let stmt = Stmt::Expr(Expr::Call(expr_call), None);
+                let (dependencies_args, destructure) = self.dependencies_arg(span)?;
+                let block = Block {
+                    brace_token: Brace::default(),
+                    stmts: vec![destructure, stmt],
+                };
+
+                let visibility = VisRestricted {
+                    pub_token: Pub::default(),
+                    paren_token: Paren::default(),
+                    in_token: None,
+                    path: Box::new(path_crate(PathArguments::None, span)),
+                };
+
+                let mut create_checked_fn = mk_fn(
+                    Ident::new("create_checked", span),
+                    Visibility::Restricted(visibility),
+                    Generics::default(),
+                    dependencies_args,
+                    self_ty,
+                    block,
+                );
+                if let ImplItem::Fn(f) = &mut create_checked_fn {
+                    f.attrs.push(mk_attribute("doc", quote! { hidden }, span));
+                }
+                create_checked_fn
+            };
+
+            ItemImpl {
+                attrs: Vec::new(),
+                defaultness: None,
+                unsafety: None,
+                impl_token: Impl::default(),
+                generics: generics.clone(),
+                trait_: None,
+                self_ty: Box::new(injector_ty.clone()),
+                brace_token: Brace::default(),
+                items: vec![new_fn, create_fn, create_checked_fn],
+            }
+        };
+
+        Ok(vec![
+            Item::Struct(output_struct),
+            Item::Struct(struct_injector),
+            Item::Impl(impl_members_injector_for_injector),
+            Item::Impl(impl_injector),
+        ])
+    }
+}
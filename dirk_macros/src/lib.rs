@@ -8,18 +8,28 @@ use proc_macro_error::proc_macro_error;
 mod errors;
 
 mod expectable;
+mod lint;
 mod parse;
 mod syntax;
 mod util;
 
 mod component;
+mod inject_members;
 mod provides;
+mod test;
 mod use_component;
 mod use_provides;
 
 pub(crate) const FACTORY_PREFIX_SINGLETON: &str = "SingletonFactory";
 pub(crate) const FACTORY_PREFIX_SCOPED: &str = "ScopedFactory";
 pub(crate) const FACTORY_PREFIX_STATIC: &str = "StaticFactory";
+pub(crate) const FACTORY_PREFIX_MEMBERS: &str = "MembersInjector";
+/// Prefix of the alias of a factory that does not depend on the kind of injection, imported by `#[use_provides]`
+///
+/// Prefixed like generated components, such that the alias does not collide with types of the user, e.g., `FactoryPool`.
+pub(crate) const FACTORY_PREFIX_ANY: &str = "DirkFactory";
+/// Prefix of the type by which components select the factory of a qualified function, see `dirk_framework::provides::qualifier`
+pub(crate) const FACTORY_PREFIX_QUALIFIED: &str = "DirkQualifiedFactory";
 
 /// Annotates an `impl` block containing a function that provides an instance of a certain type
 ///
@@ -121,6 +131,77 @@ pub(crate) const FACTORY_PREFIX_STATIC: &str = "StaticFactory";
 /// # assert_eq!(provided.read().unwrap().inner(), 42);
 ///```
 ///
+/// # Naming the factory
+/// By default, the generated factory is named after the kind of injection and the provided type, e.g., `ScopedFactoryEngine`.
+/// `#[provides(scoped_inject, factory = EngineFactory)]` names it `EngineFactory` instead, components binding the provided type keep working as they are.
+///
+/// If dirk is re-exported by a different crate, its path needs to be passed as `crate = ...`, e.g., `#[provides(scoped_inject, crate = platform::di)]`.
+///
+/// # Qualifiers
+/// Several differently configured instances of the same type may be provided by marking each of the functions of the impl block with a qualifier.
+/// One factory is generated per function, followed by the qualifier, e.g., `StaticFactoryPool_write` for `#[qualifier(write)]`.
+/// At most one function may be left unqualified, which is bound as usual.
+/// Components select a function via `static_bind(Pool, qualifier = write)` (see [`#[component(...)]`](macro@component)).
+///
+///```
+/// #[provides]
+/// impl Pool {
+///     #[qualifier(read)]
+///     fn replica() -> Self {
+///         Self { url: "postgres://replica" }
+///     }
+///
+///     #[qualifier(write)]
+///     fn primary() -> Self {
+///         Self { url: "postgres://primary" }
+///     }
+/// }
+/// #
+/// # use dirk_framework::provides::Provider;
+/// # use dirk_framework::provides;
+/// #
+/// # struct Pool {
+/// #     url: &'static str,
+/// # }
+/// #
+/// # assert_eq!(StaticFactoryPool_read::create().get().url, "postgres://replica");
+/// # assert_eq!(StaticFactoryPool_write::create().get().url, "postgres://primary");
+///```
+///
+/// # Visibility
+/// Generated factories are `pub(crate)` by default, i.e., the provided type may only be bound by components of the same crate.
+/// `#[provides(scoped_inject, vis = pub)]` makes them public instead, such that other crates may import them via [`#[use_provides(...)]`](macro@use_provides), e.g., `#[use_provides(scoped_inject)] use core_services::Database;`.
+/// Any other visibility restricts them further, e.g., `vis = pub(super)` limits the factory to the parent module of the provided type.
+///
+///```
+/// mod car {
+///     mod engine {
+///         # use dirk_framework::provides;
+///         #
+///         pub(crate) struct Engine {
+///             pub(crate) power: usize,
+///         }
+///
+///         #[provides(scoped_inject, vis = pub(super))]
+///         impl Engine {
+///             fn new() -> Self {
+///                 Self { power: 200 }
+///             }
+///         }
+///     }
+///
+///     // `ScopedFactoryEngine` is visible in `car`, but not outside of it
+///     pub(crate) fn power() -> usize {
+///         # use dirk_framework::provides::Provider;
+///         let factory = engine::ScopedFactoryEngine::create();
+///         let power = factory.get().borrow().power;
+///         power
+///     }
+/// }
+/// #
+/// # assert_eq!(car::power(), 200);
+///```
+///
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn provides(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -132,11 +213,64 @@ pub fn provides(attr: TokenStream, item: TokenStream) -> TokenStream {
     }
 }
 
+/// Annotates a `struct` containing fields that are supposed to be assigned after an instance has been created
+///
+/// Fields marked `#[inject]` are assigned by a members binding (see [`#[component(...)]`](macro@component)), all other fields are left untouched.
+/// This is useful for types whose instances are not created by dirk, e.g., test fixtures or deserialized structs.
+/// If dirk is re-exported by a different crate, its path needs to be passed as `#[inject_members(crate = ...)]`.
+///
+///```
+/// #[inject_members]
+/// struct Handler {
+///     #[inject]
+///     answer: usize,
+///     requests: usize,
+/// }
+/// #
+/// # use dirk_framework::inject_members;
+/// # use dirk_framework::provides::MembersInjector;
+/// #
+/// # pub struct AnswerProvider { }
+/// # impl dirk_framework::provides::Provider<usize> for AnswerProvider {
+/// #     fn get(&self) -> usize {
+/// #         return 42;
+/// #     }
+/// # }
+/// #
+/// # let answer_provider = std::rc::Rc::new(AnswerProvider {});
+/// # let injector = MembersInjectorHandler::create(answer_provider.clone());
+/// # let mut handler = Handler { answer: 0, requests: 7 };
+/// # injector.inject_members(&mut handler);
+/// # assert_eq!(handler.answer, 42);
+/// # assert_eq!(handler.requests, 7);
+///```
+///
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn inject_members(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let res = inject_members::_macro(attr, item);
+
+    match res {
+        Ok(item) => item,
+        Err(e) => e.abort(),
+    }
+}
+
 /// May be used to facilitate injecting or querying types provided in a different module
 ///
 /// There are a few conditions that need to be met in order for this to work:
 /// - The `impl` annotated with a `#[provides(...)]` macro needs to be present in the same module as the type it provides.
-/// - The argument of the `#[use_provides(...)]` macro needs to match the one on the corresponding `#[provides(...)]` macro. If no argument is given, the default `static_inject` is assumed.
+/// - If an argument is given to the `#[use_provides(...)]` macro, it needs to match the one on the corresponding `#[provides(...)]` macro. Without an argument, the factory is imported regardless of how the type is injected.
+/// - Types annotated with `#[inject_members]` may be imported using `#[use_provides(inject_members)]`.
+///
+/// Without an argument, the hidden alias components refer to (e.g., `DirkFactoryEngine`) is imported next to the factory of a static injection (e.g., `StaticFactoryEngine`).
+/// Code calling the factory of a different kind directly needs to pass the kind, e.g., `#[use_provides(scoped_inject)] use engine::Engine;`.
+///
+/// Factories named via `#[provides(factory = ...)]` are additionally imported by their names if the same option is given, e.g., `#[use_provides(factory = EngineFactory)] use engine::Engine;`.
+///
+/// Wildcard imports, e.g., `#[use_provides] use services::*;`, are passed through as they are, as they already import all factories generated in that module, regardless of their kind.
+///
+/// Types provided by a different crate may be imported as well, if their factories are declared public via `#[provides(vis = pub)]`.
 ///
 ///```
 /// #
@@ -296,12 +430,442 @@ pub fn use_provides(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// ## Singleton bindings
 /// `singleton_bind(T)` may be used to declare a singleton binding of type `Arc<RwLock<T>>`.
 ///
+/// If a type is provided by several qualified functions (see [`#[provides(...)]`](macro@provides)), one of them is selected by passing its qualifier, e.g., `write_pool: singleton_bind(Pool, qualifier = write)`.
+/// A qualifier none of the functions is marked with is reported by name.
+///
+/// The factory of a static, scoped or singleton binding is resolved relative to the path of its type, e.g., `static_bind(car::Engine)` uses `car::DirkFactoryEngine`, a hidden alias of the factory generated next to `car::Engine` regardless of its kind.
+/// Hence, types provided in a different module may be bound by a qualified path without a preceding [`#[use_provides(...)]`](macro@use_provides), even if they share their name, e.g., `car::Engine` and `boat::Engine`.
+///
 /// ## Cloned instance bindings
 /// `cloned_instance_bind(T)` may be used to declare a user-provided binding of type `T` where `T: Clone + 'static`, which is cloned every time it is queried or injected.
 ///
 /// ## Scoped instance bindings
 /// `scoped_instance_bind(T)` may be used to declare a user-provided binding of type `Rc<RefCell<T>>` where `T: + 'static`, such that all queried or injected `Rc`s point to the same instance.
 ///
+/// Besides the builder method taking a `T`, a method `<binding>_shared(...)` accepts an already existing `Rc<RefCell<T>>`, such that the caller and the component observe the same instance.
+///
+///```
+/// #[component(numbers: scoped_instance_bind(Vec<usize>))]
+/// trait NumbersComponent {
+///     fn numbers(&self) -> Rc<RefCell<Vec<usize>>>;
+/// }
+/// #
+/// # use std::{cell::RefCell, rc::Rc};
+/// # use dirk_framework::component;
+/// # use dirk_framework::component::{Component, builder::Builder};
+///
+/// let numbers = Rc::new(RefCell::new(vec![1, 2]));
+/// let component = DirkNumbersComponent::builder()
+///     .numbers_shared(numbers.clone())
+///     .build();
+///
+/// component.numbers().borrow_mut().push(3);
+/// assert_eq!(*numbers.borrow(), vec![1, 2, 3]);
+///```
+///
+/// ## Shared instance bindings
+/// `shared_instance_bind(T)` may be used to declare a user-provided binding of type `Rc<T>` where `T: 'static`, such that all queried or injected `Rc`s point to the same, immutable instance.
+/// `arc_instance_bind(T)` is its thread-safe counterpart, declaring a binding of type `Arc<T>`.
+///
+/// In contrast to cloned and scoped instance bindings, neither is the instance cloned on every injection nor wrapped in a `RefCell`, which suits read-only data like configuration.
+///
+///```
+/// #[component(
+///     config: shared_instance_bind(String),
+///     limits: arc_instance_bind(Vec<usize>)
+/// )]
+/// trait ConfigComponent {
+///     fn config(&self) -> Rc<String>;
+///     fn limits(&self) -> Arc<Vec<usize>>;
+/// }
+/// #
+/// # use std::{rc::Rc, sync::Arc};
+/// # use dirk_framework::component;
+/// # use dirk_framework::component::{Component, builder::Builder};
+///
+/// let component = DirkConfigComponent::builder()
+///     .config("verbose".to_string())
+///     .limits(vec![1, 2, 3])
+///     .build();
+///
+/// assert!(Rc::ptr_eq(&component.config(), &component.config()));
+/// assert!(Arc::ptr_eq(&component.limits(), &component.limits()));
+///```
+///
+/// ## Trait object instance bindings
+/// The type of a scoped, shared or arc instance binding may be a trait object, e.g., `shared_instance_bind(dyn Clock)`, which is injected as `Rc<dyn Clock>`.
+/// The builder method accepts any `impl Clock + 'static` and coerces it, `<binding>_shared(...)` accepts an existing `Rc<dyn Clock>` (e.g., created from a `Box<dyn Clock>` via `Rc::from`).
+/// Likewise, `scoped_instance_bind(dyn Storage)` is injected as `Rc<RefCell<dyn Storage>>` and `arc_instance_bind(dyn Clock + Send + Sync)` as `Arc<dyn Clock + Send + Sync>`.
+///
+///```
+/// #[component(clock: shared_instance_bind(dyn Clock))]
+/// trait ClockComponent {
+///     fn clock(&self) -> Rc<dyn Clock>;
+/// }
+/// #
+/// # use std::rc::Rc;
+/// # use dirk_framework::component;
+/// # use dirk_framework::component::{Component, builder::Builder};
+///
+/// trait Clock {
+///     fn hour(&self) -> u8;
+/// }
+///
+/// struct FixedClock(u8);
+///
+/// impl Clock for FixedClock {
+///     fn hour(&self) -> u8 {
+///         self.0
+///     }
+/// }
+///
+/// let component = DirkClockComponent::builder()
+///     .clock(FixedClock(7))
+///     .build();
+///
+/// assert_eq!(component.clock().hour(), 7);
+///```
+///
+/// ## Provider instance bindings
+/// `provider_instance_bind(T)` may be used to declare a binding of type `T`, which is created by a user-provided provider every time it is queried or injected.
+/// The corresponding builder method accepts any `impl Provider<T> + 'static` as well as any closure `Fn() -> T + 'static`.
+///
+///```
+/// #[component(
+///     ticket: provider_instance_bind(usize),
+///     greeting: provider_instance_bind(String)
+/// )]
+/// trait TicketComponent {
+///     fn ticket(&self) -> usize;
+///     fn greeting(&self) -> String;
+/// }
+/// #
+/// # use std::cell::Cell;
+/// # use dirk_framework::component;
+/// # use dirk_framework::component::{Component, builder::Builder};
+/// # use dirk_framework::provides::Provider;
+///
+/// #[derive(Default)]
+/// struct Tickets(Cell<usize>);
+///
+/// impl Provider<usize> for Tickets {
+///     fn get(&self) -> usize {
+///         self.0.set(self.0.get() + 1);
+///         self.0.get()
+///     }
+/// }
+///
+/// let component = DirkTicketComponent::builder()
+///     .ticket(Tickets::default())
+///     .greeting(|| "Hello".to_string())
+///     .build();
+///
+/// assert_eq!(component.ticket(), 1);
+/// assert_eq!(component.ticket(), 2);
+/// assert_eq!(component.greeting(), "Hello");
+///```
+///
+/// ## Environment bindings
+/// `env_bind(T, "VARIABLE")` may be used to declare a binding of type `T`, which is parsed via [`FromStr`](std::str::FromStr) from the environment variable `VARIABLE` when the component is built.
+/// A default value may be specified via `env_bind(T, "VARIABLE", default = <expr>)`, which is used if the variable is not set.
+/// Environment bindings are not set via the builder, but `try_build()` reports all missing or malformed variables at once, whereas `build()` panics in this case.
+///
+///```
+/// #[component(
+///     port: env_bind(u16, "DOC_PORT"),
+///     workers: env_bind(usize, "DOC_WORKERS", default = 4)
+/// )]
+/// trait ServerComponent {
+///     fn port(&self) -> u16;
+///     fn workers(&self) -> usize;
+/// }
+/// #
+/// # use dirk_framework::component;
+/// # use dirk_framework::component::{Component, builder::Builder};
+///
+/// let error = DirkServerComponent::builder().try_build().err().unwrap();
+/// assert_eq!(error.errors().len(), 1);
+///
+/// std::env::set_var("DOC_PORT", "8080");
+/// let component = DirkServerComponent::builder().try_build().unwrap();
+///
+/// assert_eq!(component.port(), 8080);
+/// assert_eq!(component.workers(), 4);
+///```
+///
+/// ## Configuration bindings
+/// With the `serde` feature of `dirk_framework` enabled, `config_bind(T, path = "section")` may be used to declare a binding of type `T: Clone + DeserializeOwned`, which is deserialized from a section of a configuration when the component is built.
+/// Nested sections are separated by `.`, e.g., `path = "database.pool"`, omitting `path` deserializes the whole configuration.
+///
+/// The configuration is passed once to the `config(...)` method of the builder, accepting a `serde_json::Value`, a `toml::Value` or a path to a `.json` or `.toml` file.
+/// Just like instance bindings, it is required: `build()` and `try_build()` are only available once `config(...)` has been called.
+/// Just like for environment bindings, `try_build()` reports all sections that are missing or malformed, naming the binding and its section, whereas `build()` panics in this case.
+///
+///```
+/// #[component(
+///     database: config_bind(Database, path = "database"),
+///     pool: config_bind(Pool, path = "database.pool")
+/// )]
+/// trait ServiceComponent {
+///     fn database(&self) -> Database;
+///     fn pool(&self) -> Pool;
+/// }
+/// #
+/// # use dirk_framework::component;
+/// # use dirk_framework::component::{Component, builder::Builder};
+/// # use serde::Deserialize;
+///
+/// #[derive(Clone, Deserialize)]
+/// struct Database {
+///     url: String,
+/// }
+///
+/// #[derive(Clone, Deserialize)]
+/// struct Pool {
+///     size: usize,
+/// }
+///
+/// let config = serde_json::json!({ "database": { "url": "postgres://localhost" } });
+/// let error = DirkServiceComponent::builder().config(config).try_build().err().unwrap();
+/// assert_eq!(error.errors().len(), 1);
+///
+/// let config = serde_json::json!({
+///     "database": { "url": "postgres://localhost", "pool": { "size": 8 } }
+/// });
+/// let component = DirkServiceComponent::builder().config(config).build();
+///
+/// assert_eq!(component.database().url, "postgres://localhost");
+/// assert_eq!(component.pool().size, 8);
+///```
+///
+/// ## Members bindings
+/// `members_bind(T)` may be used to declare a binding assigning all fields marked `#[inject]` of an existing instance of `T` (see [`#[inject_members]`](macro@inject_members)).
+/// Its dependencies are assigned to the marked fields in the order of their declaration.
+///
+/// Instead of returning an instance, the corresponding function takes a `&mut T` (in addition to `&self`).
+/// A members binding cannot be used as a dependency of another binding.
+///
+///```
+/// #[inject_members]
+/// struct Handler {
+///     #[inject]
+///     greeting: String,
+///     requests: usize,
+/// }
+///
+/// #[component(
+///     greeting: cloned_instance_bind(String),
+///     handler: members_bind(Handler) [greeting]
+/// )]
+/// trait HandlerComponent {
+///     fn handler(&self, target: &mut Handler);
+/// }
+/// #
+/// # use dirk_framework::{component, inject_members};
+/// # use dirk_framework::component::{Component, builder::Builder};
+///
+/// let component = DirkHandlerComponent::builder()
+///     .greeting("Hello".to_string())
+///     .build();
+///
+/// let mut handler = Handler { greeting: String::new(), requests: 0 };
+/// component.handler(&mut handler);
+/// assert_eq!(handler.greeting, "Hello");
+///```
+///
+/// # Overriding bindings
+///
+/// In tests, the builder of a component additionally offers an `override_<binding>(...)` method for every static, scoped and singleton binding, replacing it while keeping the rest of the graph intact.
+/// Scoped and singleton bindings are replaced by an instance of their type, wrapped as required, static bindings by a function creating an instance every time it is queried or injected.
+/// Bindings depending on an overridden binding receive the replacing instances as well.
+/// Each method is typed by the binding it replaces, so passing an instance of a different type does not compile.
+/// For bindings whose type involves a generic parameter of the component, the method is available once all instance bindings are set.
+///
+/// These methods are generated behind `#[cfg(test)]`, enabling the `testing` feature of `dirk_framework` (e.g., as a dev-dependency) makes them available to integration tests as well.
+///
+///```
+/// #[component(
+///     answer: static_bind(Answer),
+///     question: static_bind(Question) [answer]
+/// )]
+/// trait QuestionComponent {
+///     fn question(&self) -> Question;
+/// }
+/// #
+/// # use dirk_framework::{component, provides};
+/// # use dirk_framework::component::{builder::Builder, Component};
+/// #
+/// # #[derive(Clone, Debug, PartialEq)]
+/// # struct Answer(usize);
+/// #
+/// # #[provides]
+/// # impl Answer {
+/// #     fn new() -> Self {
+/// #         Answer(42)
+/// #     }
+/// # }
+/// #
+/// # struct Question {
+/// #     answer: Answer
+/// # }
+/// #
+/// # #[provides]
+/// # impl Question {
+/// #     fn new(answer: Answer) -> Self {
+/// #         Question { answer }
+/// #     }
+/// # }
+///
+/// let component = DirkQuestionComponent::builder()
+///     .override_answer(|| Answer(7))
+///     .build();
+///
+/// assert_eq!(component.question().answer, Answer(7));
+///```
+///
+/// # Mocking bindings
+///
+/// With the `mockall` feature of `dirk_framework` enabled, a component may opt into mocking via the `mockall` option, e.g., `#[component(mockall, ...)]`.
+/// Every `scoped_instance_bind(T)` of such a component whose type is a generic parameter `T: Trait` is then mocked by `MockTrait`, as generated by `mockall::automock` or `mockall::mock!`.
+/// For such a component `X`, `DirkX::mocked()` returns a `DirkXMocks` holding a fresh mock for each of these bindings.
+/// After setting expectations on them, `DirkXMocks::build()` builds the component, returning it together with a `DirkXMockHandles`, which holds the shared `Rc<RefCell<MockTrait>>` of each mock.
+/// If the component needs further instance bindings, `DirkXMocks::builder()` instead returns a builder with all mocks set, together with the handles.
+/// The mocks need to be in scope of the component and, like overrides, are only available in tests.
+///
+///```ignore
+/// #[component(
+///     mockall,
+///     heater: scoped_instance_bind(H),
+///     maker: static_bind(CoffeeMaker<H>) [heater]
+/// )]
+/// trait CoffeeShop<H: Heater + 'static> {
+///     fn maker(&self) -> CoffeeMaker<H>;
+/// }
+///
+/// let mut mocks = DirkCoffeeShop::mocked();
+/// mocks.heater.expect_on().once().return_const(());
+///
+/// let (coffee_shop, handles) = mocks.build();
+/// coffee_shop.maker().brew();
+/// handles.heater.borrow_mut().checkpoint();
+///```
+///
+/// # Naming generated types
+///
+/// By default, the component generated for a trait `X` is named `DirkX`, its builder `DirkXBuilder`.
+/// Options preceding the bindings allow to choose different names, the builder defaults to the name of the component followed by `Builder`.
+///
+///```
+/// #[component(
+///     name = AnswerContainer,
+///     builder = AnswerContainerBuilder,
+///     answer: cloned_instance_bind(usize)
+/// )]
+/// trait AnswerComponent {
+///     fn answer(&self) -> usize;
+/// }
+/// #
+/// # use dirk_framework::component;
+/// # use dirk_framework::component::{builder::Builder, Component};
+///
+/// let builder: AnswerContainerBuilder<_> = AnswerContainer::builder();
+/// let component = builder.answer(42).build();
+/// assert_eq!(component.answer(), 42);
+///```
+///
+/// # Re-exported framework
+///
+/// Generated code refers to the framework as `dirk_framework`.
+/// If it is re-exported by a different crate or the dependency is renamed, e.g., `di = { package = "dirk_framework", ... }`, its path needs to be passed as `crate = ...` (the same holds for [`#[provides(...)]`](macro@provides), [`#[inject_members(...)]`](macro@inject_members) and [`#[test(...)]`](macro@test)).
+///
+///```
+/// mod di {
+///     pub use dirk_framework::*;
+/// }
+///
+/// #[di::component(crate = di, answer: cloned_instance_bind(usize))]
+/// trait AnswerComponent {
+///     fn answer(&self) -> usize;
+/// }
+/// #
+/// # use di::component::{builder::Builder, Component};
+///
+/// let component = DirkAnswerComponent::builder().answer(42).build();
+/// assert_eq!(component.answer(), 42);
+///```
+///
+/// # Introspection
+///
+/// Every generated component implements `dirk_framework::component::Introspect`, which allows to enumerate its bindings at runtime, as well as `Debug`.
+/// For scoped (instance) bindings, `materialized` tells whether the shared instance has already been handed out, i.e., queried or injected.
+///
+///```
+/// #[component(
+///     answer: cloned_instance_bind(usize),
+///     numbers: scoped_instance_bind(Vec<usize>)
+/// )]
+/// trait NumbersComponent {
+///     fn numbers(&self) -> std::rc::Rc<std::cell::RefCell<Vec<usize>>>;
+/// }
+/// #
+/// # use dirk_framework::component;
+/// use dirk_framework::component::{builder::Builder, BindingKind, Component, Introspect};
+///
+/// let component = DirkNumbersComponent::builder()
+///     .answer(42)
+///     .numbers(vec![1, 2, 3])
+///     .build();
+///
+/// let bindings = component.bindings();
+/// assert_eq!(bindings[1].name, "numbers");
+/// assert_eq!(bindings[1].kind, BindingKind::ScopedInstance);
+/// assert_eq!(bindings[1].materialized, Some(false));
+///
+/// let numbers = component.numbers();
+/// assert_eq!(component.bindings()[1].materialized, Some(true));
+/// # assert_eq!(*numbers.borrow(), vec![1, 2, 3]);
+/// #
+/// # assert!(format!("{component:?}").starts_with("DirkNumbersComponentImpl"));
+///```
+///
+/// # Dependency graph
+///
+/// The generated type additionally exposes the resolved dependency graph of the component, containing the name, kind, type and dependencies of every binding, in the order in which they are instantiated.
+/// It is available as Graphviz DOT, Mermaid and JSON via the associated constants `DEPENDENCY_GRAPH_DOT`, `DEPENDENCY_GRAPH_MERMAID` and `DEPENDENCY_GRAPH_JSON`.
+///
+///```
+/// #[component(
+///     answer: cloned_instance_bind(usize),
+///     question: static_bind(Question) [answer]
+/// )]
+/// trait QuestionComponent {
+///     fn question(&self) -> Question;
+/// }
+/// #
+/// # use dirk_framework::{component, provides};
+/// #
+/// # struct Question {
+/// #     answer: usize
+/// # }
+/// #
+/// # #[provides]
+/// # impl Question {
+/// #     fn new(answer: usize) -> Self {
+/// #         Question { answer }
+/// #     }
+/// # }
+///
+/// assert!(DirkQuestionComponent::DEPENDENCY_GRAPH_DOT.contains("\"question\" -> \"answer\""));
+/// assert!(DirkQuestionComponent::DEPENDENCY_GRAPH_MERMAID.contains("question --> answer"));
+/// assert!(DirkQuestionComponent::DEPENDENCY_GRAPH_JSON.contains("\"kind\": \"cloned_instance\""));
+///```
+///
+/// Furthermore, the graph is written to `<dir>/<crate>.<module>.<trait name>.{dot,mmd,json}` during compilation, where `<dir>` is given by the environment variable `DIRK_GRAPH_DIR`.
+/// If it is not set, but the crate has a build script, `$OUT_DIR/dirk` is used instead.
+/// The module is derived from the file the component is declared in, e.g., `shop.services.coffee.CoffeeShop.dot` for a component declared in `src/services/coffee.rs` of the crate `shop`.
+/// As inline modules are not taken into account, components of the same name declared in the same file are reported as an error.
+/// Changing `DIRK_GRAPH_DIR` causes the components to be recompiled, such that their graphs are exported to the new directory.
+///
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn component(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -326,6 +890,12 @@ pub fn component(attr: TokenStream, item: TokenStream) -> TokenStream {
 
 /// May be used to facilitate using components defined in a different module
 ///
+/// Components named via `#[component(name = ..., builder = ...)]` are imported by passing the same options, e.g., `#[use_component(name = AppContainer)] use app::App;`.
+///
+/// Wildcard imports, e.g., `#[use_component] use components::*;`, are passed through as they are, as they already import all `Dirk*` types generated in that module.
+///
+/// Generated types share the visibility of the annotated trait, i.e., components declared by a `pub trait` may be imported from a different crate as well.
+///
 ///```
 /// #
 /// mod car {
@@ -374,5 +944,50 @@ pub fn use_component(attr: TokenStream, item: TokenStream) -> TokenStream {
     }
 }
 
+/// Declares a test whose parameters are queried from a component
+///
+/// `#[test(component = X)]` on a function turns it into a `#[test]` without parameters.
+/// Each parameter is assigned by calling the function of the same name on a fresh instance of component `X`, created via `DirkX::create()` (i.e., `X` may not contain instance bindings).
+/// As every test creates its own instance, scoped as well as singleton bindings are not shared between tests.
+/// If the component is named via `#[component(name = ...)]`, the same name needs to be passed, e.g., `#[test(component = X, name = XContainer)]`.
+/// Likewise, a framework re-exported by a different crate is passed as `crate = ...`, e.g., `#[test(component = X, crate = platform::di)]`.
+///
+///```
+/// #[component(heater: scoped_bind(Heater))]
+/// trait TestComponent {
+///     fn heater(&self) -> Rc<RefCell<Heater>>;
+/// }
+/// # use std::{cell::RefCell, rc::Rc};
+/// # use dirk_framework::{component, provides};
+/// #
+/// # struct Heater {
+/// #     on: bool,
+/// # }
+/// #
+/// # #[provides(scoped_inject)]
+/// # impl Heater {
+/// #     fn new() -> Self {
+/// #         Self { on: false }
+/// #     }
+/// # }
+///
+/// #[dirk_framework::test(component = TestComponent)]
+/// fn heats(heater: Rc<RefCell<Heater>>) {
+///     heater.borrow_mut().on = true;
+///     assert!(heater.borrow().on);
+/// }
+///```
+///
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn test(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let res = test::_macro(attr, item);
+
+    match res {
+        Ok(item) => item,
+        Err(e) => e.abort(),
+    }
+}
+
 #[cfg(test)]
 mod tests {}
@@ -0,0 +1,189 @@
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::emit_error;
+use quote::ToTokens;
+use syn::{
+    token::{Brace, Bracket, Colon, Const, Eq, Not, Paren, Pound, Semi, Underscore},
+    Attribute, Block, Expr, ExprBlock, ExprMacro, ExprPath, ExprTuple, Ident, Item, ItemConst,
+    LitStr, Macro, MacroDelimiter, Meta, MetaList, Path, PathArguments, Stmt, Type, TypeReference,
+    TypeTuple, Visibility,
+};
+
+use crate::{
+    syntax::wrap_type,
+    util::{path_option_env, type_option, type_str},
+};
+
+/// Environment variable setting the level of all lints reported by `#[component(...)]` and `#[*_provides]`
+pub(crate) const LINTS_ENV: &str = "DIRK_LINTS";
+
+/// Environment variable opting into reporting types provided by `#[*_provides]`, but not bound by any component
+pub(crate) const LINT_UNBOUND_PROVIDES_ENV: &str = "DIRK_LINT_UNBOUND_PROVIDES";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl LintLevel {
+    pub(crate) fn from_env(var: &str, default: LintLevel) -> Self {
+        match std::env::var(var).as_deref() {
+            Ok("allow") => LintLevel::Allow,
+            Ok("warn") => LintLevel::Warn,
+            Ok("deny") => LintLevel::Deny,
+            _ => default,
+        }
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            LintLevel::Allow => "allow",
+            LintLevel::Warn => "warn",
+            LintLevel::Deny => "deny",
+        }
+    }
+}
+
+/// Reports `msg` at `span` as lint `name`, the returned item needs to be part of the expansion
+///
+/// Proc macros cannot emit warnings on stable, hence a warning is reported by using a `#[deprecated]` constant at `span`.
+pub(crate) fn lint(level: LintLevel, name: &str, span: Span, msg: &str) -> Option<Item> {
+    match level {
+        LintLevel::Allow => None,
+        LintLevel::Warn => Some(deprecated_usage(name, span, msg)),
+        LintLevel::Deny => {
+            emit_error!(span, "{}", msg; note = "`{}` is denied via `{}`", name, LINTS_ENV);
+            None
+        }
+    }
+}
+
+/// `const _: () = { #[deprecated(note = "<msg>")] const <name>: () = (); <name> };`
+fn deprecated_usage(name: &str, span: Span, msg: &str) -> Item {
+    let unit_ty = || {
+        Type::Tuple(TypeTuple {
+            paren_token: Paren::default(),
+            elems: syn::punctuated::Punctuated::new(),
+        })
+    };
+    let unit_expr = || {
+        Expr::Tuple(ExprTuple {
+            attrs: Vec::new(),
+            paren_token: Paren::default(),
+            elems: syn::punctuated::Punctuated::new(),
+        })
+    };
+
+    let ident = Ident::new(name, span);
+
+    let deprecated = {
+        let mut tokens = TokenStream::new();
+        Ident::new("note", span).to_tokens(&mut tokens);
+        Eq::default().to_tokens(&mut tokens);
+        LitStr::new(msg, span).to_tokens(&mut tokens);
+
+        mk_attribute("deprecated", tokens, span)
+    };
+    let allow_naming = mk_attribute(
+        "allow",
+        Ident::new("non_upper_case_globals", span).into_token_stream(),
+        span,
+    );
+
+    let deprecated_const = ItemConst {
+        attrs: vec![deprecated, allow_naming],
+        vis: Visibility::Inherited,
+        const_token: Const::default(),
+        ident: ident.clone(),
+        generics: syn::Generics::default(),
+        colon_token: Colon::default(),
+        ty: Box::new(unit_ty()),
+        eq_token: Eq::default(),
+        expr: Box::new(unit_expr()),
+        semi_token: Semi::default(),
+    };
+
+    let usage = Expr::Path(ExprPath {
+        attrs: Vec::new(),
+        qself: None,
+        path: Path::from(ident),
+    });
+
+    let block = Block {
+        brace_token: Brace::default(),
+        stmts: vec![
+            Stmt::Item(Item::Const(deprecated_const)),
+            Stmt::Expr(usage, None),
+        ],
+    };
+
+    Item::Const(ItemConst {
+        attrs: Vec::new(),
+        vis: Visibility::Inherited,
+        const_token: Const::default(),
+        ident: Ident::from(Underscore::default()),
+        generics: syn::Generics::default(),
+        colon_token: Colon::default(),
+        ty: Box::new(unit_ty()),
+        eq_token: Eq::default(),
+        expr: Box::new(Expr::Block(ExprBlock {
+            attrs: Vec::new(),
+            label: None,
+            block,
+        })),
+        semi_token: Semi::default(),
+    })
+}
+
+/// `const _: Option<&str> = std::option_env!("<var>");`, such that changing `var` causes the expansion to be recompiled
+pub(crate) fn track_env(var: &str) -> Item {
+    let span = Span::call_site();
+
+    let str_ref = Type::Reference(TypeReference {
+        and_token: syn::token::And::default(),
+        lifetime: None,
+        mutability: None,
+        elem: Box::new(type_str(PathArguments::None, span)),
+    });
+    let ty = wrap_type(str_ref, type_option);
+
+    let mut tokens = TokenStream::new();
+    LitStr::new(var, span).to_tokens(&mut tokens);
+
+    let expr = Expr::Macro(ExprMacro {
+        attrs: Vec::new(),
+        mac: Macro {
+            path: path_option_env(PathArguments::None, span),
+            bang_token: Not::default(),
+            delimiter: MacroDelimiter::Paren(Paren::default()),
+            tokens,
+        },
+    });
+
+    Item::Const(ItemConst {
+        attrs: Vec::new(),
+        vis: Visibility::Inherited,
+        const_token: Const::default(),
+        ident: Ident::from(Underscore::default()),
+        generics: syn::Generics::default(),
+        colon_token: Colon::default(),
+        ty: Box::new(ty),
+        eq_token: Eq::default(),
+        expr: Box::new(expr),
+        semi_token: Semi::default(),
+    })
+}
+
+pub(crate) fn mk_attribute(name: &str, tokens: TokenStream, span: Span) -> Attribute {
+    Attribute {
+        pound_token: Pound::default(),
+        style: syn::AttrStyle::Outer,
+        bracket_token: Bracket::default(),
+        meta: Meta::List(MetaList {
+            path: Path::from(Ident::new(name, span)),
+            delimiter: MacroDelimiter::Paren(Paren::default()),
+            tokens,
+        }),
+    }
+}
@@ -1,5 +1,5 @@
-use proc_macro_error::abort;
-use syn::{punctuated::Punctuated, token::Comma, FnArg, ItemImpl, Type};
+use proc_macro_error::{abort, abort_if_dirty, emit_error};
+use syn::{punctuated::Punctuated, token::Comma, FnArg, Ident, ItemImpl, Type};
 
 use crate::{
     errors::ExpectableError,
@@ -11,7 +11,7 @@ pub(crate) type ProvidesResult<T> = std::result::Result<T, ProvidesError>;
 #[derive(Debug)]
 pub(crate) enum ProvidesError {
     Infallible(InfallibleError<ProvidesSyntaxError>),
-    Logic(ProvidesLogicError),
+    Logic(Box<ProvidesLogicError>),
 }
 
 impl_abort!(ProvidesError);
@@ -38,31 +38,57 @@ impl SyntaxError for ProvidesSyntaxError {
 
 #[derive(Debug)]
 pub(crate) enum ProvidesLogicError {
-    InvalidFunctionCount(ItemImpl, usize),
+    InvalidFunctionCount(Box<ItemImpl>, usize),
     InvalidReturnType(Type),
     SingletonWithArgs(Punctuated<FnArg, Comma>),
+    InvalidQualifier(syn::Error),
+    DuplicateQualifier(Ident),
 }
 
 impl From<ProvidesLogicError> for ProvidesError {
     fn from(value: ProvidesLogicError) -> Self {
-        Self::Logic(value)
+        Self::Logic(Box::new(value))
     }
 }
 
 impl ProvidesLogicError {
     fn abort(self) -> ! {
+        self.emit();
+        abort_if_dirty();
+        unreachable!("Emitting a logic error needs to mark the macro invocation as failed")
+    }
+
+    pub(crate) fn emit(self) {
         match self {
             ProvidesLogicError::InvalidFunctionCount(item_impl, len) => {
-                abort!(item_impl, format!("#[*_provides] is supposed to be placed on an impl block containing one single function - found {} functions instead", len))
+                let help = (len > 1).then_some("Each of several provider functions needs to be marked with a qualifier, e.g., `#[qualifier(write)]`");
+                emit_error!(
+                    item_impl,
+                    format!("#[*_provides] is supposed to be placed on an impl block containing one single function, or several functions with distinct qualifiers - found {} functions instead", len);
+                    help =? help
+                );
             }
             ProvidesLogicError::InvalidReturnType(ty) => {
-                abort!(ty, "#[*_provides] is supposed to be placed on an impl block containing a function returning `Self`")
+                emit_error!(ty, "#[*_provides] is supposed to be placed on an impl block containing a function returning `Self`");
             }
             ProvidesLogicError::SingletonWithArgs(args) => {
-                abort!(
+                emit_error!(
                     args,
                     "An instance provided as singleton cannot depend on any arguments."
-                )
+                );
+            }
+            ProvidesLogicError::InvalidQualifier(e) => {
+                emit_error!(
+                    e.span(),
+                    e.to_string();
+                    help = "Expected a qualifier like `#[qualifier(write)]`"
+                );
+            }
+            ProvidesLogicError::DuplicateQualifier(qualifier) => {
+                emit_error!(
+                    qualifier,
+                    format!("Qualifier `{qualifier}` is given to several functions of the same impl block")
+                );
             }
         }
     }
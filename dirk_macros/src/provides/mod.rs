@@ -1,14 +1,16 @@
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use syn::{
-    parse::Parse, token::Dot, Expr, ExprField, ExprPath, Ident, Member, Path, PathArguments, Type,
+    parse::Parse, token::Dot, Expr, ExprField, ExprPath, Ident, Member, Path, PathArguments, Token,
+    Type, Visibility,
 };
 
 use crate::{
     syntax::wrap_type,
     util::{
-        path_arc_new, path_rc_new, path_refcell_new, path_rwlock_new, type_arc, type_rc,
-        type_refcell, type_rwlock,
+        path_arc_new, path_rc_new, path_refcell_new, path_rwlock_new, type_arc, type_kind_scoped,
+        type_kind_singleton, type_kind_static, type_rc, type_refcell, type_rwlock,
+        with_framework_path,
     },
     FACTORY_PREFIX_SCOPED, FACTORY_PREFIX_SINGLETON, FACTORY_PREFIX_STATIC,
 };
@@ -25,13 +27,22 @@ use self::{
 
 mod error;
 mod processor;
-mod syntax;
+pub(crate) mod syntax;
 
 pub(crate) fn _macro(attr: TokenStream, item: TokenStream) -> ProvidesResult<TokenStream> {
     let data = ProvidesMacroData::new(attr, item);
-    let processor = ProvidesMacroProcessor::new(&data);
+    let framework = data.framework_path()?;
 
-    processor.process().map(|items| {
+    let items = with_framework_path(framework, || {
+        let mut items = Vec::new();
+        for index in 0..data.provider_count() {
+            let processor = ProvidesMacroProcessor::new(&data, index);
+            items.extend(processor.process()?);
+        }
+        ProvidesResult::Ok(items)
+    });
+
+    items.map(|items| {
         let expanded = quote! { #(#items)* };
         TokenStream::from(expanded)
     })
@@ -41,6 +52,85 @@ mod kw {
     syn::custom_keyword!(singleton_inject);
     syn::custom_keyword!(scoped_inject);
     syn::custom_keyword!(static_inject);
+    syn::custom_keyword!(factory);
+    syn::custom_keyword!(vis);
+}
+
+/// Arguments of `#[provides(...)]`, i.e., the kind of injection followed by options such as `factory = EngineFactory`
+#[derive(Debug, Default)]
+pub(crate) struct ProvidesMacroArgs {
+    kind: ProvidesMacroInput,
+    factory: Option<Ident>,
+    vis: Option<Visibility>,
+    krate: Option<Path>,
+}
+
+impl Parse for ProvidesMacroArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = Self::default();
+
+        if !input.is_empty() && !input.peek2(Token![=]) {
+            args.kind = input.parse()?;
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(kw::factory) {
+                let kw = input.parse::<kw::factory>()?;
+                if args.factory.is_some() {
+                    return Err(syn::Error::new(kw.span, "duplicate option `factory`"));
+                }
+                input.parse::<Token![=]>()?;
+                args.factory = Some(input.parse()?);
+            } else if lookahead.peek(kw::vis) {
+                let kw = input.parse::<kw::vis>()?;
+                if args.vis.is_some() {
+                    return Err(syn::Error::new(kw.span, "duplicate option `vis`"));
+                }
+                input.parse::<Token![=]>()?;
+                args.vis = Some(input.parse()?);
+            } else if lookahead.peek(Token![crate]) {
+                let kw = input.parse::<Token![crate]>()?;
+                if args.krate.is_some() {
+                    return Err(syn::Error::new(kw.span, "duplicate option `crate`"));
+                }
+                input.parse::<Token![=]>()?;
+                args.krate = Some(input.call(Path::parse_mod_style)?);
+            } else {
+                return Err(lookahead.error());
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+impl ProvidesMacroArgs {
+    pub(crate) fn kind(&self) -> &ProvidesMacroInput {
+        &self.kind
+    }
+
+    /// Name of the generated factory, if chosen by the user
+    pub(crate) fn factory(&self) -> Option<&Ident> {
+        self.factory.as_ref()
+    }
+
+    /// Visibility of the generated factory, if chosen by the user
+    pub(crate) fn vis(&self) -> Option<&Visibility> {
+        self.vis.as_ref()
+    }
+
+    /// Path of the framework, if chosen by the user
+    pub(crate) fn framework_path(&self) -> Option<&Path> {
+        self.krate.as_ref()
+    }
 }
 
 #[allow(dead_code)]
@@ -175,4 +265,13 @@ impl ProvidesMacroInput {
             ProvidesMacroInput::Singleton(_) => FACTORY_PREFIX_SINGLETON,
         }
     }
+
+    /// Type naming the kind of binding the provided type can be bound with
+    pub(crate) fn kind_ty(&self, span: Span) -> Type {
+        match self {
+            ProvidesMacroInput::Static(_) => type_kind_static(PathArguments::None, span),
+            ProvidesMacroInput::Scoped(_) => type_kind_scoped(PathArguments::None, span),
+            ProvidesMacroInput::Singleton(_) => type_kind_singleton(PathArguments::None, span),
+        }
+    }
 }
@@ -3,18 +3,21 @@ use std::{cell::OnceCell, collections::HashMap};
 use itertools::Itertools;
 use proc_macro::TokenStream;
 use proc_macro2::Span;
-use quote::ToTokens;
+use proc_macro_error::set_dummy;
+use quote::{quote, ToTokens};
 use syn::{
     spanned::Spanned,
     token::{
-        And, Brace, Bracket, Colon, Comma, Dot, Dyn, Eq, For, Impl, Or, Paren, Pound, Pub,
-        SelfValue, Semi, Static, Struct,
+        And, As, Brace, Bracket, Colon, Comma, Dot, Eq, For, Impl, Let, Or, Paren, Pound, Pub,
+        RArrow, SelfValue, Semi, Static, Struct, Trait, Use,
     },
-    Attribute, Block, Expr, ExprCall, ExprClosure, ExprField, ExprMethodCall, ExprPath, ExprStruct,
-    Field, FieldValue, Fields, FieldsNamed, FnArg, Generics, Ident, ImplItem, ImplItemFn, ItemImpl,
-    ItemStatic, Member, MetaList, Pat, PatIdent, PatType, Path, PathArguments, Receiver,
-    StaticMutability, Stmt, TraitBound, Type, TypeParamBound, TypePath, TypeReference,
-    TypeTraitObject, VisRestricted, Visibility,
+    Attribute, Block, Expr, ExprCall, ExprClosure, ExprField, ExprLit, ExprMethodCall, ExprPath,
+    ExprStruct, ExprTuple, Field, FieldValue, Fields, FieldsNamed, FnArg, GenericParam, Generics,
+    Ident, ImplItem, ImplItemFn, ImplItemType, Index, ItemFn, ItemImpl, ItemStatic, ItemTrait, Lit,
+    LitBool, LitStr, Local, LocalInit, MacroDelimiter, Member, Meta, MetaList, Pat, PatIdent,
+    PatTuple, PatType, Path, PathArguments, PathSegment, QSelf, Receiver, Signature,
+    StaticMutability, Stmt, TraitBound, TraitItem, TraitItemFn, Type, TypeParam, TypeParamBound,
+    TypePath, TypeReference, TypeTuple, UseRename, UseTree, VisRestricted, Visibility,
 };
 
 use crate::{
@@ -22,24 +25,30 @@ use crate::{
     expectable::{
         FnArgExpectable, ImplItemExpectable, PatExpectable, ReturnTypeExpectable, TypeExpectable,
     },
-    syntax::{mk_fn, wrap_type},
+    lint::{lint, mk_attribute, track_env, LintLevel, LINTS_ENV, LINT_UNBOUND_PROVIDES_ENV},
+    syntax::{collect_idents, mk_fn, qualifier_ty, qualify_ident, type_to_string, wrap_type},
     util::{
-        path_clone, path_crate, path_derive, path_factory_instance_new, path_provider, path_self,
-        path_self_new, path_self_new_instance, path_small_self, type_factory_instance,
-        type_provider, type_rc,
+        path_bindable_via, path_cell_new, path_clone, path_crate, path_derive,
+        path_do_not_recommend, path_factory_instance_new, path_from_dependencies,
+        path_on_unimplemented, path_provider, path_qualified, path_self, path_self_new,
+        path_self_new_instance, path_sized, path_small_self, type_bool, type_cell,
+        type_factory_instance,
     },
+    FACTORY_PREFIX_ANY, FACTORY_PREFIX_QUALIFIED, FACTORY_PREFIX_STATIC,
 };
 
 use syn::{
     punctuated::Punctuated,
     token::{Gt, Lt},
-    AngleBracketedGenericArguments, GenericArgument, Item, ItemStruct,
+    AngleBracketedGenericArguments, GenericArgument, Item, ItemStruct, ItemUse,
 };
 
-use super::syntax::{get_call_path, get_constructor_call};
+use super::syntax::{
+    get_call_path, get_constructor_call, rc_dyn_provider, respan_generic_argument,
+};
 use super::{
-    error::{ProvidesResult, ProvidesSyntaxError},
-    ProvidesMacroInput,
+    error::{ProvidesError, ProvidesResult, ProvidesSyntaxError},
+    ProvidesMacroArgs, ProvidesMacroInput,
 };
 
 use super::{
@@ -51,8 +60,9 @@ pub(crate) struct ProvidesMacroData {
     attr: TokenStream,
     item: TokenStream,
 
-    input_macro: OnceCell<ProvidesMacroInput>,
+    input_macro: OnceCell<ProvidesMacroArgs>,
     input_impl: OnceCell<ItemImpl>,
+    functions: OnceCell<Vec<(usize, Option<Ident>)>>,
 }
 
 impl ProvidesMacroData {
@@ -62,12 +72,21 @@ impl ProvidesMacroData {
             item,
             input_macro: OnceCell::new(),
             input_impl: OnceCell::new(),
+            functions: OnceCell::new(),
         }
     }
 }
 
 impl ProvidesMacroData {
     fn input_macro(&self) -> InfallibleResult<&ProvidesMacroInput, ProvidesSyntaxError> {
+        self.input_args().map(ProvidesMacroArgs::kind)
+    }
+
+    pub(crate) fn framework_path(&self) -> InfallibleResult<Option<&Path>, ProvidesSyntaxError> {
+        self.input_args().map(ProvidesMacroArgs::framework_path)
+    }
+
+    fn input_args(&self) -> InfallibleResult<&ProvidesMacroArgs, ProvidesSyntaxError> {
         if let Some(cached) = self.input_macro.get() {
             return Ok(cached);
         }
@@ -75,7 +94,7 @@ impl ProvidesMacroData {
         let input_macro = {
             let attr = self.attr.clone();
 
-            syn::parse::<ProvidesMacroInput>(attr)
+            syn::parse::<ProvidesMacroArgs>(attr)
                 .map_err(ProvidesSyntaxError::FailedToParseInput)?
         };
 
@@ -95,10 +114,90 @@ impl ProvidesMacroData {
 
         Ok(self.input_impl.get_or_init(|| input_impl))
     }
+
+    /// The annotated impl block without `#[qualifier(...)]` attributes
+    fn output_impl(&self) -> InfallibleResult<ItemImpl, ProvidesSyntaxError> {
+        let mut output_impl = self.input_impl()?.clone();
+
+        for item in &mut output_impl.items {
+            if let ImplItem::Fn(function) = item {
+                function
+                    .attrs
+                    .retain(|attr| !attr.path().is_ident("qualifier"));
+            }
+        }
+
+        Ok(output_impl)
+    }
+
+    /// Number of provider functions, each of which is processed separately
+    pub(crate) fn provider_count(&self) -> usize {
+        self.functions().map_or(1, Vec::len)
+    }
+
+    /// Indices of the provider functions within the annotated impl block, along with their qualifiers
+    ///
+    /// The impl block may contain several functions marked `#[qualifier(...)]`, next to at most one function without.
+    fn functions(&self) -> ProvidesResult<&Vec<(usize, Option<Ident>)>> {
+        if let Some(cached) = self.functions.get() {
+            return Ok(cached);
+        }
+
+        let functions = {
+            let input_impl = self.input_impl()?;
+
+            let mut functions = Vec::new();
+            for (index, item) in input_impl.items.iter().enumerate() {
+                let ImplItem::Fn(function) = item else {
+                    continue;
+                };
+
+                let mut qualifier = None;
+                for attr in &function.attrs {
+                    if !attr.path().is_ident("qualifier") {
+                        continue;
+                    }
+                    if qualifier.is_some() {
+                        let e = syn::Error::new_spanned(attr, "Expected a single qualifier");
+                        return Err(ProvidesLogicError::InvalidQualifier(e))?;
+                    }
+                    let ident = attr
+                        .parse_args::<Ident>()
+                        .map_err(ProvidesLogicError::InvalidQualifier)?;
+                    qualifier = Some(ident);
+                }
+
+                functions.push((index, qualifier));
+            }
+
+            let unqualified = functions.iter().filter(|(_, q)| q.is_none()).count();
+            if unqualified > 1 || functions.is_empty() {
+                return Err(ProvidesLogicError::InvalidFunctionCount(
+                    Box::new(input_impl.clone()),
+                    unqualified,
+                ))?;
+            }
+
+            if let Some(duplicate) = functions
+                .iter()
+                .filter_map(|(_, q)| q.as_ref())
+                .duplicates()
+                .next()
+            {
+                return Err(ProvidesLogicError::DuplicateQualifier(duplicate.clone()))?;
+            }
+
+            functions
+        };
+
+        Ok(self.functions.get_or_init(|| functions))
+    }
 }
 
 pub(crate) struct ProvidesMacroProcessor<'data> {
     data: &'data ProvidesMacroData,
+    /// Index of the processed provider function, see `ProvidesMacroData::functions()`
+    index: usize,
 
     function: OnceCell<&'data ImplItemFn>,
     wrapped_types: OnceCell<HashMap<FnArg, (Ident, Type, PatType)>>,
@@ -116,9 +215,10 @@ pub(crate) struct ProvidesMacroProcessor<'data> {
 }
 
 impl<'data> ProvidesMacroProcessor<'data> {
-    pub(crate) fn new(data: &'data ProvidesMacroData) -> Self {
+    pub(crate) fn new(data: &'data ProvidesMacroData, index: usize) -> Self {
         Self {
             data,
+            index,
 
             function: OnceCell::new(),
             wrapped_types: OnceCell::new(),
@@ -145,27 +245,24 @@ impl<'data> ProvidesMacroProcessor<'data> {
 
         let function = {
             let input_impl = self.data.input_impl()?;
+            let (index, _) = self.data.functions()?[self.index];
 
-            let items = &input_impl.items;
-
-            let function = items
-                .iter()
-                .filter(|f| matches!(f, ImplItem::Fn(_)))
-                .exactly_one()
-                .map_err(|e| {
-                    ProvidesLogicError::InvalidFunctionCount(
-                        input_impl.clone(),
-                        e.try_len()
-                            .expect("Number of functions in an impl block needs to be finite"),
-                    )
-                })?;
-
-            function.as_fn()?
+            input_impl.items[index].as_fn()?
         };
 
         Ok(self.function.get_or_init(|| function))
     }
 
+    /// Qualifier of the processed function, given via `#[qualifier(...)]`
+    fn qualifier(&self) -> ProvidesResult<Option<&Ident>> {
+        Ok(self.data.functions()?[self.index].1.as_ref())
+    }
+
+    /// Whether items generated once per impl block (rather than per function) are generated by this processor
+    fn is_primary(&self) -> bool {
+        self.index == 0
+    }
+
     fn function_ident(&self) -> ProvidesResult<&Ident> {
         let function_ident = {
             let function = self.function()?;
@@ -347,10 +444,14 @@ impl<'data> ProvidesMacroProcessor<'data> {
                 .last_mut()
                 .ok_or_else(|| InfallibleError::EmptyPath(span))?;
 
-            last.ident = Ident::new(
-                &format!("{}{}", input_macro.factory_prefix(), last.ident),
-                last.ident.span(),
-            );
+            let factory_ident = match self.data.input_args()?.factory() {
+                Some(factory) => factory.clone(),
+                None => Ident::new(
+                    &format!("{}{}", input_macro.factory_prefix(), last.ident),
+                    last.ident.span(),
+                ),
+            };
+            last.ident = qualify_ident(&factory_ident, self.qualifier()?);
             last.arguments = generic_args.clone();
 
             factory_ty
@@ -374,6 +475,20 @@ impl<'data> ProvidesMacroProcessor<'data> {
         Ok(ident)
     }
 
+    /// Visibility of the generated items, `pub(crate)` unless chosen via `vis = ...`
+    fn factory_vis(&self, span: Span) -> ProvidesResult<Visibility> {
+        if let Some(vis) = self.data.input_args()?.vis() {
+            return Ok(vis.clone());
+        }
+
+        Ok(Visibility::Restricted(VisRestricted {
+            pub_token: Pub::default(),
+            paren_token: Paren::default(),
+            in_token: None,
+            path: Box::new(path_crate(PathArguments::None, span)),
+        }))
+    }
+
     fn wrapped_types(&self) -> ProvidesResult<&HashMap<FnArg, (Ident, Type, PatType)>> {
         if let Some(cached) = self.wrapped_types.get() {
             return Ok(cached);
@@ -397,32 +512,7 @@ impl<'data> ProvidesMacroProcessor<'data> {
                     )
                 };
 
-                let ty = {
-                    let ty = pat_type.ty.as_ref().clone();
-
-                    let provider_type = wrap_type(ty, type_provider);
-
-                    let type_path = provider_type.as_path()?;
-
-                    let trait_bound = TraitBound {
-                        paren_token: None,
-                        modifier: syn::TraitBoundModifier::None,
-                        lifetimes: None,
-                        path: type_path.path.clone(),
-                    };
-                    let bound = TypeParamBound::Trait(trait_bound);
-
-                    let mut bounds = Punctuated::new();
-                    bounds.push(bound);
-
-                    let trait_object = TypeTraitObject {
-                        dyn_token: Some(Dyn::default()),
-                        bounds,
-                    };
-                    let dyn_type = Type::TraitObject(trait_object);
-
-                    wrap_type(dyn_type, type_rc)
-                };
+                let ty = rc_dyn_provider(pat_type.ty.as_ref().clone());
 
                 let pat_type = {
                     let pat_ident = PatIdent {
@@ -521,19 +611,65 @@ impl<'data> ProvidesMacroProcessor<'data> {
         Ok(field_values)
     }
 
-    fn providers_field_exprs(&self) -> ProvidesResult<Punctuated<Expr, Comma>> {
+    /// Providers stored by the factory, cast from the dependencies passed to `create()`, or from the elements of `self` if `from_tuple`
+    fn providers_field_exprs(&self, from_tuple: bool) -> ProvidesResult<Punctuated<Expr, Comma>> {
         let formal_fields = self.field_args()?;
-        let wrapped_types = self.wrapped_types()?;
 
         let mut field_exprs = Punctuated::new();
 
-        for f in formal_fields {
-            let (ident, _ty, _pat_type) = wrapped_types.get(f).expect("Prepopulated");
+        for (index, f) in formal_fields.iter().enumerate() {
+            let param_ident = &f.as_typed()?.pat.as_ident()?.ident;
+
+            // `<param as FactoryDependency<..>>::cast(param)` or `<param as FactoryDependency<..>>::cast(self.<index>)`
+            let trait_path = self.dependency_trait_path(index, param_ident.span())?;
+            let position = trait_path.segments.len();
+
+            let mut path = trait_path;
+            path.segments.push(PathSegment {
+                ident: Ident::new("cast", param_ident.span()),
+                arguments: PathArguments::None,
+            });
 
-            let field_expr = Expr::Path(ExprPath {
+            let cast = ExprPath {
                 attrs: Vec::new(),
-                qself: None,
-                path: Path::from(ident.clone()),
+                qself: Some(QSelf {
+                    lt_token: Lt::default(),
+                    ty: Box::new(Type::Path(TypePath {
+                        qself: None,
+                        path: Path::from(param_ident.clone()),
+                    })),
+                    position,
+                    as_token: Some(As::default()),
+                    gt_token: Gt::default(),
+                }),
+                path,
+            };
+
+            let mut args = Punctuated::new();
+            if from_tuple {
+                args.push(Expr::Field(ExprField {
+                    attrs: Vec::new(),
+                    base: Box::new(Expr::Path(ExprPath {
+                        attrs: Vec::new(),
+                        qself: None,
+                        path: path_small_self(PathArguments::None, param_ident.span()),
+                    })),
+                    dot_token: Dot::default(),
+                    member: Member::Unnamed(Index::from(index)),
+                }));
+            } else {
+                args.push(Expr::Path(ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: Path::from(param_ident.clone()),
+                }));
+            }
+
+            let field_expr = Expr::Call(ExprCall {
+                attrs: Vec::new(),
+                func: Box::new(Expr::Path(cast)),
+                paren_token: Paren::default(),
+                args,
             });
 
             field_exprs.push(field_expr);
@@ -589,6 +725,690 @@ impl<'data> ProvidesMacroProcessor<'data> {
         Ok(exprs)
     }
 
+    /// Path of the trait checking the type of the dependency passed as parameter `index` to `create()`
+    fn dependency_trait_path(&self, index: usize, span: Span) -> ProvidesResult<Path> {
+        self.factory_trait_path(&format!("Dependency{index}"), span)
+    }
+
+    /// Path of the trait checking the number of dependencies passed to `create_checked()`
+    fn dependencies_trait_path(&self, span: Span) -> ProvidesResult<Path> {
+        self.factory_trait_path("Dependencies", span)
+    }
+
+    /// Path of a trait generated alongside the factory, named `<Factory><suffix>` and generic like the factory
+    fn factory_trait_path(&self, suffix: &str, span: Span) -> ProvidesResult<Path> {
+        let factory_ident = self.factory_ident()?;
+        let generics = self.generics()?;
+
+        let ident = Ident::new(&format!("{factory_ident}{suffix}"), span);
+
+        // Spanned like the trait, such that the whole path points at `span`
+        let args = map_generic_params(generics.params.clone())
+            .into_iter()
+            .map(|arg| respan_generic_argument(arg, span))
+            .collect::<Punctuated<_, _>>();
+        let arguments = if args.is_empty() {
+            PathArguments::None
+        } else {
+            PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                colon2_token: None,
+                lt_token: Lt(span),
+                args,
+                gt_token: Gt(span),
+            })
+        };
+
+        let mut path = Path::from(ident);
+        path.segments
+            .last_mut()
+            .ok_or_else(|| InfallibleError::EmptyPath(span))?
+            .arguments = arguments;
+
+        Ok(path)
+    }
+
+    /// Generic parameters of the impl of the trait returned by `dependencies_trait_path()`, one per dependency, named like the corresponding parameter of the provider function
+    ///
+    /// Bounding each by its dependency trait reports a mismatching dependency at the binding passing it, in terms of the provider function.
+    fn dependencies_generics(&self) -> ProvidesResult<Generics> {
+        let formal_fields = self.field_args()?;
+        let mut generics = self.generics()?.clone();
+
+        for (index, f) in formal_fields.iter().enumerate() {
+            let param_ident = &f.as_typed()?.pat.as_ident()?.ident;
+
+            let mut bounds = Punctuated::new();
+            bounds.push(TypeParamBound::Trait(TraitBound {
+                paren_token: None,
+                modifier: syn::TraitBoundModifier::None,
+                lifetimes: None,
+                path: self.dependency_trait_path(index, param_ident.span())?,
+            }));
+
+            generics.params.push(GenericParam::Type(TypeParam {
+                attrs: Vec::new(),
+                ident: param_ident.clone(),
+                colon_token: Some(Colon::default()),
+                bounds,
+                eq_token: None,
+                default: None,
+            }));
+        }
+
+        if generics.lt_token.is_none() && !generics.params.is_empty() {
+            generics.lt_token = Some(Lt::default());
+            generics.gt_token = Some(Gt::default());
+        }
+
+        Ok(generics)
+    }
+
+    /// `(Rc<dyn Provider<param>>, ...)`, the dependencies implementing the trait returned by `dependencies_trait_path()`
+    fn dependencies_ty(&self) -> ProvidesResult<Type> {
+        let formal_fields = self.field_args()?;
+
+        let mut elems = Punctuated::new();
+        for f in formal_fields {
+            let param_ident = &f.as_typed()?.pat.as_ident()?.ident;
+            elems.push(rc_dyn_provider(Type::Path(TypePath {
+                qself: None,
+                path: Path::from(param_ident.clone()),
+            })));
+        }
+        if elems.len() == 1 {
+            elems.push_punct(Comma::default());
+        }
+
+        Ok(Type::Tuple(TypeTuple {
+            paren_token: Paren::default(),
+            elems,
+        }))
+    }
+
+    /// `(Rc<dyn Provider<T>>, ...)`, the providers stored by the factory, typed like the parameters of the provider function
+    fn providers_ty(&self) -> ProvidesResult<Type> {
+        let formal_fields = self.field_args()?;
+
+        let mut elems = Punctuated::new();
+        for f in formal_fields {
+            elems.push(rc_dyn_provider(f.as_typed()?.ty.as_ref().clone()));
+        }
+        if elems.len() == 1 {
+            elems.push_punct(Comma::default());
+        }
+
+        Ok(Type::Tuple(TypeTuple {
+            paren_token: Paren::default(),
+            elems,
+        }))
+    }
+
+    /// Generic parameters of `create()`, one per dependency, named like the corresponding parameter of the provider function
+    ///
+    /// Bounding each by its dependency trait reports a mismatching dependency at the binding passing it, in terms of the provider function.
+    fn create_generics(&self) -> ProvidesResult<Generics> {
+        let formal_fields = self.field_args()?;
+
+        let mut params = Punctuated::new();
+
+        for (index, f) in formal_fields.iter().enumerate() {
+            let param_ident = &f.as_typed()?.pat.as_ident()?.ident;
+
+            let mut bounds = Punctuated::new();
+            bounds.push(TypeParamBound::Trait(TraitBound {
+                paren_token: None,
+                modifier: syn::TraitBoundModifier::None,
+                lifetimes: None,
+                path: self.dependency_trait_path(index, param_ident.span())?,
+            }));
+
+            params.push(GenericParam::Type(TypeParam {
+                attrs: Vec::new(),
+                ident: param_ident.clone(),
+                colon_token: Some(Colon::default()),
+                bounds,
+                eq_token: None,
+                default: None,
+            }));
+        }
+
+        Ok(Generics {
+            lt_token: Some(Lt::default()),
+            params,
+            gt_token: Some(Gt::default()),
+            where_clause: None,
+        })
+    }
+
+    /// Arguments of `create()`, providing the generic parameters returned by `create_generics()`
+    fn create_args(&self) -> ProvidesResult<Punctuated<FnArg, Comma>> {
+        let formal_fields = self.field_args()?;
+
+        let mut fn_args = Punctuated::new();
+
+        for f in formal_fields {
+            let param_ident = &f.as_typed()?.pat.as_ident()?.ident;
+
+            // Named like the parameter of the provider function, such that arity errors refer to it
+            let pat_type = PatType {
+                attrs: Vec::new(),
+                pat: Box::new(Pat::Ident(PatIdent {
+                    attrs: Vec::new(),
+                    by_ref: None,
+                    mutability: None,
+                    ident: param_ident.clone(),
+                    subpat: None,
+                })),
+                colon_token: Colon::default(),
+                ty: Box::new(rc_dyn_provider(Type::Path(TypePath {
+                    qself: None,
+                    path: Path::from(param_ident.clone()),
+                }))),
+            };
+
+            fn_args.push(FnArg::Typed(pat_type));
+        }
+
+        Ok(fn_args)
+    }
+
+    /// Generic parameter of `create_checked()`, bounded by the trait returned by `dependencies_trait_path()`
+    ///
+    /// Named like the argument, such that arity errors refer to the dependencies passed.
+    fn create_checked_generics(&self, span: Span) -> ProvidesResult<Generics> {
+        let mut bounds = Punctuated::new();
+        bounds.push(TypeParamBound::Trait(TraitBound {
+            paren_token: None,
+            modifier: syn::TraitBoundModifier::None,
+            lifetimes: None,
+            path: self.dependencies_trait_path(span)?,
+        }));
+
+        let mut params = Punctuated::new();
+        params.push(GenericParam::Type(TypeParam {
+            attrs: Vec::new(),
+            ident: Ident::new("dependencies", span),
+            colon_token: Some(Colon::default()),
+            bounds,
+            eq_token: None,
+            default: None,
+        }));
+
+        Ok(Generics {
+            lt_token: Some(Lt::default()),
+            params,
+            gt_token: Some(Gt::default()),
+            where_clause: None,
+        })
+    }
+
+    /// `dependencies: dependencies`, the only argument of `create_checked()`
+    fn create_checked_args(span: Span) -> Punctuated<FnArg, Comma> {
+        let ident = Ident::new("dependencies", span);
+
+        let mut fn_args = Punctuated::new();
+        fn_args.push(FnArg::Typed(PatType {
+            attrs: Vec::new(),
+            pat: Box::new(Pat::Ident(PatIdent {
+                attrs: Vec::new(),
+                by_ref: None,
+                mutability: None,
+                ident: ident.clone(),
+                subpat: None,
+            })),
+            colon_token: Colon::default(),
+            ty: Box::new(Type::Path(TypePath {
+                qself: None,
+                path: Path::from(ident),
+            })),
+        }));
+        fn_args
+    }
+
+    /// `let (param, ...) = dependencies.providers();`, such that the providers are passed to `create()` like the arguments of the provider function
+    fn destructure_dependencies(&self, span: Span) -> ProvidesResult<Stmt> {
+        let formal_fields = self.field_args()?;
+
+        let mut elems = Punctuated::new();
+        for f in formal_fields {
+            let param_ident = &f.as_typed()?.pat.as_ident()?.ident;
+            elems.push(Pat::Ident(PatIdent {
+                attrs: Vec::new(),
+                by_ref: None,
+                mutability: None,
+                ident: param_ident.clone(),
+                subpat: None,
+            }));
+        }
+        if elems.len() == 1 {
+            elems.push_punct(Comma::default());
+        }
+
+        let providers = Expr::MethodCall(ExprMethodCall {
+            attrs: Vec::new(),
+            receiver: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: Path::from(Ident::new("dependencies", span)),
+            })),
+            dot_token: Dot::default(),
+            method: Ident::new("providers", span),
+            turbofish: None,
+            paren_token: Paren::default(),
+            args: Punctuated::new(),
+        });
+
+        Ok(Stmt::Local(Local {
+            attrs: Vec::new(),
+            let_token: Let::default(),
+            pat: Pat::Tuple(PatTuple {
+                attrs: Vec::new(),
+                paren_token: Paren::default(),
+                elems,
+            }),
+            init: Some(LocalInit {
+                eq_token: Eq::default(),
+                expr: Box::new(providers),
+                diverge: None,
+            }),
+            semi_token: Semi::default(),
+        }))
+    }
+
+    /// `create()` accepting dependencies checked via `dependency_items()`
+    fn create_fn(
+        &self,
+        visibility: Visibility,
+        self_ty: Type,
+        block: Block,
+    ) -> ProvidesResult<ImplItem> {
+        let fn_span = self.function_ident()?.span();
+
+        let mut create_fn = mk_fn(
+            Ident::new("create", fn_span),
+            visibility,
+            self.create_generics()?,
+            self.create_args()?,
+            self_ty,
+            block,
+        );
+
+        if let ImplItem::Fn(f) = &mut create_fn {
+            f.attrs.push(mk_attribute(
+                "allow",
+                Ident::new("non_camel_case_types", fn_span).into_token_stream(),
+                fn_span,
+            ));
+        }
+
+        Ok(create_fn)
+    }
+
+    /// `create_checked()`, creating the factory from the dependencies checked via `dependencies_items()`
+    ///
+    /// Components call it instead of `create()`, such that passing a wrong number of dependencies is reported in terms of the provider function.
+    fn create_checked_fn(&self, visibility: Visibility) -> ProvidesResult<ImplItem> {
+        let fn_span = self.function_ident()?.span();
+        let formal_fields = self.field_args()?;
+
+        let self_ty = Type::Path(TypePath {
+            qself: None,
+            path: path_self(PathArguments::None, fn_span),
+        });
+
+        // `Self::create(param, ...)`
+        let mut args = Punctuated::new();
+        for f in formal_fields {
+            let param_ident = &f.as_typed()?.pat.as_ident()?.ident;
+            args.push(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: Path::from(param_ident.clone()),
+            }));
+        }
+        let create_call = Expr::Call(ExprCall {
+            attrs: Vec::new(),
+            func: Box::new(Expr::Path(get_call_path(
+                &TypePath {
+                    qself: None,
+                    path: path_self(PathArguments::None, fn_span),
+                },
+                Ident::new("create", fn_span),
+            ))),
+            paren_token: Paren::default(),
+            args,
+        });
+
+        let block = Block {
+            brace_token: Brace::default(),
+            stmts: vec![
+                self.destructure_dependencies(fn_span)?,
+                Stmt::Expr(create_call, None),
+            ],
+        };
+
+        let mut create_checked_fn = mk_fn(
+            Ident::new("create_checked", fn_span),
+            visibility,
+            self.create_checked_generics(fn_span)?,
+            Self::create_checked_args(fn_span),
+            self_ty,
+            block,
+        );
+
+        if let ImplItem::Fn(f) = &mut create_checked_fn {
+            f.attrs.push(mk_attribute(
+                "doc",
+                Ident::new("hidden", fn_span).into_token_stream(),
+                fn_span,
+            ));
+            f.attrs.push(mk_attribute(
+                "allow",
+                Ident::new("non_camel_case_types", fn_span).into_token_stream(),
+                fn_span,
+            ));
+        }
+
+        Ok(create_checked_fn)
+    }
+
+    /// One trait per dependency, implemented only for the type expected by the provider function
+    ///
+    /// `cast()` turns the provider passed to `create()` into the one stored by the factory.
+    fn dependency_items(&self) -> ProvidesResult<Vec<Item>> {
+        let formal_fields = self.field_args()?;
+        let generics = self.generics()?;
+        let injectable_path = self.injectable_path()?;
+        let function_ident = self.function_ident()?;
+
+        let span = Span::call_site();
+
+        let provider_name = {
+            let injectable_ident = &injectable_path
+                .path
+                .segments
+                .last()
+                .ok_or_else(|| InfallibleError::EmptyPath(injectable_path.span()))?
+                .ident;
+            format!("{injectable_ident}::{function_ident}")
+        };
+
+        let mut items = Vec::new();
+
+        for (index, f) in formal_fields.iter().enumerate() {
+            let pat_type = f.as_typed()?;
+            let param_ident = &pat_type.pat.as_ident()?.ident;
+            let param_ty = pat_type.ty.as_ref();
+
+            let trait_path = self.dependency_trait_path(index, span)?;
+            let trait_ident = trait_path
+                .segments
+                .last()
+                .ok_or_else(|| InfallibleError::EmptyPath(span))?
+                .ident
+                .clone();
+
+            let self_ty = Type::Path(TypePath {
+                qself: None,
+                path: path_self(PathArguments::None, span),
+            });
+
+            let cast_sig = Signature {
+                constness: None,
+                asyncness: None,
+                unsafety: None,
+                abi: None,
+                fn_token: syn::token::Fn::default(),
+                ident: Ident::new("cast", span),
+                generics: Generics::default(),
+                paren_token: Paren::default(),
+                inputs: {
+                    let mut inputs = Punctuated::new();
+                    inputs.push(FnArg::Typed(PatType {
+                        attrs: Vec::new(),
+                        pat: Box::new(Pat::Ident(PatIdent {
+                            attrs: Vec::new(),
+                            by_ref: None,
+                            mutability: None,
+                            ident: Ident::new("provider", span),
+                            subpat: None,
+                        })),
+                        colon_token: Colon::default(),
+                        ty: Box::new(rc_dyn_provider(self_ty)),
+                    }));
+                    inputs
+                },
+                variadic: None,
+                output: syn::ReturnType::Type(
+                    RArrow::default(),
+                    Box::new(rc_dyn_provider(param_ty.clone())),
+                ),
+            };
+
+            let on_unimplemented = {
+                let param_ty = escape_format(&type_to_string(param_ty));
+                let provider_name = escape_format(&provider_name);
+
+                let message = format!(
+                    "Parameter #{} `{param_ident}` of `{provider_name}` expects a dependency of type `{param_ty}`, but a dependency of type `{{Self}}` is passed",
+                    index + 1
+                );
+                let label = format!("passed as `{param_ident}` to `{provider_name}`");
+
+                on_unimplemented(&message, &label, span)
+            };
+
+            let mut supertraits = Punctuated::new();
+            supertraits.push(TypeParamBound::Trait(TraitBound {
+                paren_token: None,
+                modifier: syn::TraitBoundModifier::None,
+                lifetimes: None,
+                path: path_sized(PathArguments::None, span),
+            }));
+
+            let item_trait = ItemTrait {
+                attrs: vec![on_unimplemented],
+                vis: self.factory_vis(span)?,
+                unsafety: None,
+                auto_token: None,
+                restriction: None,
+                trait_token: Trait::default(),
+                ident: trait_ident,
+                generics: generics.clone(),
+                colon_token: Some(Colon::default()),
+                supertraits,
+                brace_token: Brace::default(),
+                items: vec![TraitItem::Fn(TraitItemFn {
+                    attrs: Vec::new(),
+                    sig: cast_sig.clone(),
+                    default: None,
+                    semi_token: Some(Semi::default()),
+                })],
+            };
+
+            let cast_block = Block {
+                brace_token: Brace::default(),
+                stmts: vec![Stmt::Expr(
+                    Expr::Path(ExprPath {
+                        attrs: Vec::new(),
+                        qself: None,
+                        path: Path::from(Ident::new("provider", span)),
+                    }),
+                    None,
+                )],
+            };
+
+            let item_impl = ItemImpl {
+                attrs: Vec::new(),
+                defaultness: None,
+                unsafety: None,
+                impl_token: Impl::default(),
+                generics: generics.clone(),
+                trait_: Some((None, trait_path, For::default())),
+                self_ty: Box::new(param_ty.clone()),
+                brace_token: Brace::default(),
+                items: vec![ImplItem::Fn(ImplItemFn {
+                    attrs: Vec::new(),
+                    vis: Visibility::Inherited,
+                    defaultness: None,
+                    sig: cast_sig,
+                    block: cast_block,
+                })],
+            };
+
+            items.push(Item::Trait(item_trait));
+            items.push(Item::Impl(item_impl));
+        }
+
+        items.extend(self.dependencies_items(&provider_name, span)?);
+
+        Ok(items)
+    }
+
+    /// Trait implemented only for as many dependencies as the provider function has parameters
+    ///
+    /// `providers()` casts each dependency via its trait from `dependency_items()`.
+    fn dependencies_items(&self, provider_name: &str, span: Span) -> ProvidesResult<Vec<Item>> {
+        let formal_fields = self.field_args()?;
+        let generics = self.generics()?;
+
+        let trait_path = self.dependencies_trait_path(span)?;
+        let trait_ident = trait_path
+            .segments
+            .last()
+            .ok_or_else(|| InfallibleError::EmptyPath(span))?
+            .ident
+            .clone();
+
+        let on_unimplemented = {
+            let mut params = Vec::new();
+            let mut idents = Vec::new();
+            for f in formal_fields {
+                let pat_type = f.as_typed()?;
+                let param_ident = &pat_type.pat.as_ident()?.ident;
+                params.push(format!(
+                    "{param_ident}: {}",
+                    escape_format(&type_to_string(&pat_type.ty))
+                ));
+                idents.push(param_ident.to_string());
+            }
+            let provider_name = escape_format(provider_name);
+
+            let count = match formal_fields.len() {
+                1 => "1 dependency".to_string(),
+                n => format!("{n} dependencies"),
+            };
+            let message = format!(
+                "`{provider_name}({})` expects {count}, but a different number is passed",
+                params.join(", ")
+            );
+            let label = format!("expected `[{}]`", idents.join(", "));
+
+            on_unimplemented(&message, &label, span)
+        };
+
+        let providers_sig = Signature {
+            constness: None,
+            asyncness: None,
+            unsafety: None,
+            abi: None,
+            fn_token: syn::token::Fn::default(),
+            ident: Ident::new("providers", span),
+            generics: Generics::default(),
+            paren_token: Paren::default(),
+            inputs: {
+                let mut inputs = Punctuated::new();
+                inputs.push(FnArg::Receiver(Receiver {
+                    attrs: Vec::new(),
+                    reference: None,
+                    mutability: None,
+                    self_token: SelfValue::default(),
+                    colon_token: None,
+                    ty: Box::new(Type::Path(TypePath {
+                        qself: None,
+                        path: path_self(PathArguments::None, span),
+                    })),
+                }));
+                inputs
+            },
+            variadic: None,
+            output: if formal_fields.is_empty() {
+                syn::ReturnType::Default
+            } else {
+                syn::ReturnType::Type(RArrow::default(), Box::new(self.providers_ty()?))
+            },
+        };
+
+        let item_trait = ItemTrait {
+            attrs: vec![on_unimplemented],
+            vis: self.factory_vis(span)?,
+            unsafety: None,
+            auto_token: None,
+            restriction: None,
+            trait_token: Trait::default(),
+            ident: trait_ident,
+            generics: generics.clone(),
+            colon_token: None,
+            supertraits: Punctuated::new(),
+            brace_token: Brace::default(),
+            items: vec![TraitItem::Fn(TraitItemFn {
+                attrs: Vec::new(),
+                sig: providers_sig.clone(),
+                default: None,
+                semi_token: Some(Semi::default()),
+            })],
+        };
+
+        let providers_block = {
+            let mut elems = self.providers_field_exprs(true)?;
+            if elems.len() == 1 {
+                elems.push_punct(Comma::default());
+            }
+
+            let stmts = if elems.is_empty() {
+                Vec::new()
+            } else {
+                vec![Stmt::Expr(
+                    Expr::Tuple(ExprTuple {
+                        attrs: Vec::new(),
+                        paren_token: Paren::default(),
+                        elems,
+                    }),
+                    None,
+                )]
+            };
+
+            Block {
+                brace_token: Brace::default(),
+                stmts,
+            }
+        };
+
+        let item_impl = ItemImpl {
+            attrs: vec![mk_attribute(
+                "allow",
+                Ident::new("non_camel_case_types", span).into_token_stream(),
+                span,
+            )],
+            defaultness: None,
+            unsafety: None,
+            impl_token: Impl::default(),
+            generics: self.dependencies_generics()?,
+            trait_: Some((None, trait_path, For::default())),
+            self_ty: Box::new(self.dependencies_ty()?),
+            brace_token: Brace::default(),
+            items: vec![ImplItem::Fn(ImplItemFn {
+                attrs: Vec::new(),
+                vis: Visibility::Inherited,
+                defaultness: None,
+                sig: providers_sig,
+                block: providers_block,
+            })],
+        };
+
+        Ok(vec![Item::Trait(item_trait), Item::Impl(item_impl)])
+    }
+
     fn constructor_call(&self) -> ProvidesResult<Expr> {
         let input_macro = self.data.input_macro()?;
         let injectable_path = self.injectable_path()?;
@@ -603,9 +1423,49 @@ impl<'data> ProvidesMacroProcessor<'data> {
         Ok(constructor_call)
     }
 
+    /// Emits every logic error found in the annotated impl block, returns whether none was found
+    fn validate(&self) -> ProvidesResult<bool> {
+        let input_macro = self.data.input_macro()?;
+
+        let function = match self.function() {
+            Ok(function) => function,
+            Err(ProvidesError::Logic(e)) => {
+                e.emit();
+                return Ok(false);
+            }
+            Err(e) => return Err(e),
+        };
+
+        let mut errors = Vec::new();
+
+        let fun_ty = function.sig.output.as_type()?.1;
+        if !fun_ty.as_path()?.path.is_ident("Self") {
+            errors.push(ProvidesLogicError::InvalidReturnType(fun_ty.clone()));
+        }
+
+        if let ProvidesMacroInput::Singleton(_) = input_macro {
+            let args = &function.sig.inputs;
+            if !args.is_empty() {
+                errors.push(ProvidesLogicError::SingletonWithArgs(args.clone()));
+            }
+        }
+
+        let valid = errors.is_empty();
+        errors.into_iter().for_each(ProvidesLogicError::emit);
+
+        Ok(valid)
+    }
+
     pub(crate) fn process(self) -> ProvidesResult<Vec<Item>> {
         let input_macro = self.data.input_macro()?;
-        let input_impl = self.data.input_impl()?.clone();
+        let input_impl = self.data.output_impl()?;
+
+        if !self.validate()? {
+            // Tokens returned after emitting an error are discarded, the impl block is kept as dummy
+            let impl_bindable_via = self.impl_bindable_via()?;
+            set_dummy(quote! { #input_impl #impl_bindable_via });
+            return Ok(Vec::new());
+        }
 
         let fn_span = self.function_ident()?.span();
 
@@ -620,19 +1480,14 @@ impl<'data> ProvidesMacroProcessor<'data> {
         let providers_args = self.providers_args()?;
         let providers_fields = self.providers_fields()?;
         let providers_field_values = self.providers_field_values()?;
-        let providers_field_exprs = self.providers_field_exprs()?;
+        let providers_field_exprs = self.providers_field_exprs(false)?;
         let providers_getter = self.providers_getter()?;
 
         let items = {
             match input_macro {
                 ProvidesMacroInput::Static(_) => {
                     let struct_factory = {
-                        let vis_restricted = VisRestricted {
-                            pub_token: Pub::default(),
-                            paren_token: Paren::default(),
-                            in_token: None,
-                            path: Box::new(path_crate(PathArguments::None, factory_ident.span())),
-                        };
+                        let vis_restricted = self.factory_vis(factory_ident.span())?;
 
                         let fields = Fields::Named(FieldsNamed {
                             brace_token: Brace::default(),
@@ -641,7 +1496,7 @@ impl<'data> ProvidesMacroProcessor<'data> {
 
                         ItemStruct {
                             attrs: Vec::new(),
-                            vis: Visibility::Restricted(vis_restricted),
+                            vis: vis_restricted,
                             struct_token: Struct::default(),
                             ident: factory_ident,
                             generics: impl_generics.clone(),
@@ -770,7 +1625,7 @@ impl<'data> ProvidesMacroProcessor<'data> {
                                 attrs: Vec::new(),
                                 func: Box::new(Expr::Path(expr_new)),
                                 paren_token: Paren::default(),
-                                args: providers_field_exprs,
+                                args: providers_field_exprs.clone(),
                             };
                             let expr = Expr::Call(expr_call);
                             let stmt = Stmt::Expr(expr, None);
@@ -779,23 +1634,14 @@ impl<'data> ProvidesMacroProcessor<'data> {
                                 stmts: vec![stmt],
                             };
 
-                            let visibility = VisRestricted {
-                                pub_token: Pub::default(),
-                                paren_token: Paren::default(),
-                                in_token: None,
-                                path: Box::new(path_crate(PathArguments::None, fn_span)),
-                            };
+                            let visibility = self.factory_vis(fn_span)?;
 
-                            mk_fn(
-                                Ident::new("create", fn_span),
-                                Visibility::Restricted(visibility),
-                                Generics::default(),
-                                providers_args.clone(),
-                                self_ty.clone(),
-                                block,
-                            )
+                            self.create_fn(visibility, self_ty.clone(), block)?
                         };
 
+                        let create_checked_fn =
+                            self.create_checked_fn(self.factory_vis(fn_span)?)?;
+
                         let new_instance_fn = {
                             let stmt = Stmt::Expr(constructor_call, None);
                             let block = Block {
@@ -813,7 +1659,7 @@ impl<'data> ProvidesMacroProcessor<'data> {
                             )
                         };
 
-                        let items = vec![new_fn, create_fn, new_instance_fn];
+                        let items = vec![new_fn, create_fn, create_checked_fn, new_instance_fn];
                         ItemImpl {
                             attrs: Vec::new(),
                             defaultness: None,
@@ -831,17 +1677,12 @@ impl<'data> ProvidesMacroProcessor<'data> {
                         Item::Struct(struct_factory),
                         Item::Impl(impl_provider_for_factory),
                         Item::Impl(impl_factory),
-                        Item::Impl(input_impl),
                     ]
                 }
                 ProvidesMacroInput::Scoped(_) => {
+                    // The instance is created along with the factory, handing it out marks it as materialized
                     let struct_factory = {
-                        let vis_restricted = VisRestricted {
-                            pub_token: Pub::default(),
-                            paren_token: Paren::default(),
-                            in_token: None,
-                            path: Box::new(path_crate(PathArguments::None, factory_ident.span())),
-                        };
+                        let vis_restricted = self.factory_vis(factory_ident.span())?;
 
                         let mut singleton_fields = Punctuated::new();
                         let singleton_field = Field {
@@ -853,6 +1694,15 @@ impl<'data> ProvidesMacroProcessor<'data> {
                             ty: injected_ty.clone(),
                         };
                         singleton_fields.push(singleton_field);
+                        let handed_out_field = Field {
+                            attrs: Vec::new(),
+                            vis: Visibility::Inherited,
+                            mutability: syn::FieldMutability::None,
+                            ident: Some(Ident::new("handed_out", fn_span)),
+                            colon_token: Some(Colon::default()),
+                            ty: wrap_type(type_bool(PathArguments::None, fn_span), type_cell),
+                        };
+                        singleton_fields.push(handed_out_field);
 
                         let fields = Fields::Named(FieldsNamed {
                             brace_token: Brace::default(),
@@ -861,7 +1711,7 @@ impl<'data> ProvidesMacroProcessor<'data> {
 
                         ItemStruct {
                             attrs: Vec::new(),
-                            vis: Visibility::Restricted(vis_restricted),
+                            vis: vis_restricted,
                             struct_token: Struct::default(),
                             ident: factory_ident,
                             generics: impl_generics.clone(),
@@ -871,7 +1721,7 @@ impl<'data> ProvidesMacroProcessor<'data> {
                     };
 
                     let impl_provider_for_factory: ItemImpl = {
-                        let get_fn = {
+                        let mk_inputs = || {
                             let mut inputs = Punctuated::new();
                             let type_path = TypePath {
                                 qself: None,
@@ -893,31 +1743,79 @@ impl<'data> ProvidesMacroProcessor<'data> {
                                 ty: Box::new(self_ref),
                             });
                             inputs.push(self_arg);
+                            inputs
+                        };
 
-                            let expr_path = ExprPath {
+                        // `self.<member>`
+                        let self_field = |member: &str| {
+                            Expr::Field(ExprField {
                                 attrs: Vec::new(),
-                                qself: None,
-                                path: path_small_self(PathArguments::None, fn_span),
-                            };
-                            let base = Expr::Path(expr_path);
-                            let member = Member::Named(Ident::new("singleton", fn_span));
-                            let expr_field = ExprField {
+                                base: Box::new(Expr::Path(ExprPath {
+                                    attrs: Vec::new(),
+                                    qself: None,
+                                    path: path_small_self(PathArguments::None, fn_span),
+                                })),
+                                dot_token: Dot::default(),
+                                member: Member::Named(Ident::new(member, fn_span)),
+                            })
+                        };
+
+                        let get_fn = {
+                            // `self.handed_out.set(true);`
+                            let mut args = Punctuated::new();
+                            args.push(Expr::Lit(ExprLit {
                                 attrs: Vec::new(),
-                                base: Box::new(base),
+                                lit: Lit::Bool(LitBool::new(true, fn_span)),
+                            }));
+                            let set = Expr::MethodCall(ExprMethodCall {
+                                attrs: Vec::new(),
+                                receiver: Box::new(self_field("handed_out")),
                                 dot_token: Dot::default(),
-                                member,
-                            };
-                            let receiver = Expr::Field(expr_field);
-                            let expr_method_call = ExprMethodCall {
+                                method: Ident::new("set", fn_span),
+                                turbofish: None,
+                                paren_token: Paren::default(),
+                                args,
+                            });
+
+                            // `self.singleton.clone()`
+                            let expr = Expr::MethodCall(ExprMethodCall {
                                 attrs: Vec::new(),
-                                receiver: Box::new(receiver),
+                                receiver: Box::new(self_field("singleton")),
                                 dot_token: Dot::default(),
                                 method: Ident::new("clone", fn_span),
                                 turbofish: None,
                                 paren_token: Paren::default(),
                                 args: Punctuated::new(),
+                            });
+                            let block = Block {
+                                brace_token: Brace::default(),
+                                stmts: vec![
+                                    Stmt::Expr(set, Some(Semi::default())),
+                                    Stmt::Expr(expr, None),
+                                ],
                             };
-                            let expr = Expr::MethodCall(expr_method_call);
+
+                            mk_fn(
+                                Ident::new("get", fn_span),
+                                Visibility::Inherited,
+                                Generics::default(),
+                                mk_inputs(),
+                                injected_ty.clone(),
+                                block,
+                            )
+                        };
+
+                        let is_materialized_fn = {
+                            // `self.handed_out.get()`
+                            let expr = Expr::MethodCall(ExprMethodCall {
+                                attrs: Vec::new(),
+                                receiver: Box::new(self_field("handed_out")),
+                                dot_token: Dot::default(),
+                                method: Ident::new("get", fn_span),
+                                turbofish: None,
+                                paren_token: Paren::default(),
+                                args: Punctuated::new(),
+                            });
                             let stmt = Stmt::Expr(expr, None);
                             let block = Block {
                                 brace_token: Brace::default(),
@@ -925,11 +1823,11 @@ impl<'data> ProvidesMacroProcessor<'data> {
                             };
 
                             mk_fn(
-                                Ident::new("get", fn_span),
+                                Ident::new("is_materialized", fn_span),
                                 Visibility::Inherited,
                                 Generics::default(),
-                                inputs,
-                                injected_ty.clone(),
+                                mk_inputs(),
+                                type_bool(PathArguments::None, fn_span),
                                 block,
                             )
                         };
@@ -943,7 +1841,7 @@ impl<'data> ProvidesMacroProcessor<'data> {
                             trait_: Some((None, provider_ty, For::default())),
                             self_ty: Box::new(factory_ty.clone()),
                             brace_token: Brace::default(),
-                            items: vec![get_fn],
+                            items: vec![get_fn, is_materialized_fn],
                         }
                     };
 
@@ -981,6 +1879,28 @@ impl<'data> ProvidesMacroProcessor<'data> {
                             };
                             singleton_fields.push(singleton_field);
 
+                            let mut args = Punctuated::new();
+                            args.push(Expr::Lit(ExprLit {
+                                attrs: Vec::new(),
+                                lit: Lit::Bool(LitBool::new(false, fn_span)),
+                            }));
+                            let handed_out_field = FieldValue {
+                                attrs: Vec::new(),
+                                member: Member::Named(Ident::new("handed_out", fn_span)),
+                                colon_token: Some(Colon::default()),
+                                expr: Expr::Call(ExprCall {
+                                    attrs: Vec::new(),
+                                    func: Box::new(Expr::Path(ExprPath {
+                                        attrs: Vec::new(),
+                                        qself: None,
+                                        path: path_cell_new(PathArguments::None, fn_span),
+                                    })),
+                                    paren_token: Paren::default(),
+                                    args,
+                                }),
+                            };
+                            singleton_fields.push(handed_out_field);
+
                             let expr_struct = ExprStruct {
                                 attrs: Vec::new(),
                                 qself: None,
@@ -1023,7 +1943,7 @@ impl<'data> ProvidesMacroProcessor<'data> {
                                 attrs: Vec::new(),
                                 func: Box::new(Expr::Path(expr_new)),
                                 paren_token: Paren::default(),
-                                args: providers_field_exprs,
+                                args: providers_field_exprs.clone(),
                             };
                             let expr = Expr::Call(expr_call);
                             let stmt = Stmt::Expr(expr, None);
@@ -1032,23 +1952,14 @@ impl<'data> ProvidesMacroProcessor<'data> {
                                 stmts: vec![stmt],
                             };
 
-                            let visibility = VisRestricted {
-                                pub_token: Pub::default(),
-                                paren_token: Paren::default(),
-                                in_token: None,
-                                path: Box::new(path_crate(PathArguments::None, fn_span)),
-                            };
+                            let visibility = self.factory_vis(fn_span)?;
 
-                            mk_fn(
-                                Ident::new("create", fn_span),
-                                Visibility::Restricted(visibility),
-                                Generics::default(),
-                                providers_args.clone(),
-                                self_ty.clone(),
-                                block,
-                            )
+                            self.create_fn(visibility, self_ty.clone(), block)?
                         };
 
+                        let create_checked_fn =
+                            self.create_checked_fn(self.factory_vis(fn_span)?)?;
+
                         let new_instance_fn = {
                             let stmt = Stmt::Expr(constructor_call, None);
                             let block = Block {
@@ -1066,7 +1977,7 @@ impl<'data> ProvidesMacroProcessor<'data> {
                             )
                         };
 
-                        let items = vec![new_fn, create_fn, new_instance_fn];
+                        let items = vec![new_fn, create_fn, create_checked_fn, new_instance_fn];
                         ItemImpl {
                             attrs: Vec::new(),
                             defaultness: None,
@@ -1084,7 +1995,6 @@ impl<'data> ProvidesMacroProcessor<'data> {
                         Item::Struct(struct_factory),
                         Item::Impl(impl_provider_for_factory),
                         Item::Impl(impl_factory),
-                        Item::Impl(input_impl),
                     ];
 
                     items
@@ -1103,12 +2013,7 @@ impl<'data> ProvidesMacroProcessor<'data> {
                         get_constructor_call(factory_call, Punctuated::new());
 
                     let struct_factory = {
-                        let vis_restricted = VisRestricted {
-                            pub_token: Pub::default(),
-                            paren_token: Paren::default(),
-                            in_token: None,
-                            path: Box::new(path_crate(PathArguments::None, factory_ident.span())),
-                        };
+                        let vis_restricted = self.factory_vis(factory_ident.span())?;
 
                         let mut singleton_fields = Punctuated::new();
                         let singleton_field = Field {
@@ -1143,7 +2048,7 @@ impl<'data> ProvidesMacroProcessor<'data> {
 
                         ItemStruct {
                             attrs: vec![derive],
-                            vis: Visibility::Restricted(vis_restricted),
+                            vis: vis_restricted,
                             struct_token: Struct::default(),
                             ident: factory_ident.clone(),
                             generics: impl_generics.clone(),
@@ -1305,7 +2210,7 @@ impl<'data> ProvidesMacroProcessor<'data> {
                                 attrs: Vec::new(),
                                 func: Box::new(Expr::Path(expr_new)),
                                 paren_token: Paren::default(),
-                                args: providers_field_exprs,
+                                args: providers_field_exprs.clone(),
                             };
                             let expr = Expr::Call(expr_call);
                             let stmt = Stmt::Expr(expr, None);
@@ -1314,23 +2219,14 @@ impl<'data> ProvidesMacroProcessor<'data> {
                                 stmts: vec![stmt],
                             };
 
-                            let visibility = VisRestricted {
-                                pub_token: Pub::default(),
-                                paren_token: Paren::default(),
-                                in_token: None,
-                                path: Box::new(path_crate(PathArguments::None, fn_span)),
-                            };
+                            let visibility = self.factory_vis(fn_span)?;
 
-                            mk_fn(
-                                Ident::new("create", fn_span),
-                                Visibility::Restricted(visibility),
-                                Generics::default(),
-                                providers_args.clone(),
-                                self_ty.clone(),
-                                block,
-                            )
+                            self.create_fn(visibility, self_ty.clone(), block)?
                         };
 
+                        let create_checked_fn =
+                            self.create_checked_fn(self.factory_vis(fn_span)?)?;
+
                         let new_instance_fn = {
                             let stmt = Stmt::Expr(constructor_call, None);
                             let block = Block {
@@ -1348,7 +2244,7 @@ impl<'data> ProvidesMacroProcessor<'data> {
                             )
                         };
 
-                        let items = vec![new_fn, create_fn, new_instance_fn];
+                        let items = vec![new_fn, create_fn, create_checked_fn, new_instance_fn];
                         ItemImpl {
                             attrs: Vec::new(),
                             defaultness: None,
@@ -1410,12 +2306,535 @@ impl<'data> ProvidesMacroProcessor<'data> {
                         Item::Impl(impl_provider_for_factory),
                         Item::Impl(impl_factory),
                         Item::Static(static_factory_instance),
-                        Item::Impl(input_impl),
                     ]
                 }
             }
         };
 
+        let mut items = items;
+        if self.is_primary() {
+            items.push(Item::Impl(input_impl));
+        }
+        items.extend(self.dependency_items()?);
+        if self.is_primary() {
+            items.push(Item::Impl(self.impl_bindable_via()?));
+            items.push(Item::Struct(self.qualified_tag()?));
+            items.extend(self.qualified_tag_aliases()?);
+        }
+        items.extend(self.qualified_items()?);
+        items.extend(self.factory_aliases()?);
+
+        if self.qualifier()?.is_some() {
+            // `_` separates the qualifier, e.g., `StaticFactoryPool_read`
+            for item in &mut items {
+                if let Item::Struct(ItemStruct { attrs, .. })
+                | Item::Trait(ItemTrait { attrs, .. }) = item
+                {
+                    attrs.push(mk_attribute(
+                        "allow",
+                        quote! { non_camel_case_types },
+                        Span::call_site(),
+                    ));
+                }
+            }
+        }
+
+        let level = LintLevel::from_env(LINT_UNBOUND_PROVIDES_ENV, LintLevel::Allow);
+        if level != LintLevel::Allow {
+            let (marker, marker_call) = self.unbound_marker(level)?;
+
+            for item in &mut items {
+                let Item::Impl(item_impl) = item else {
+                    continue;
+                };
+                if item_impl.trait_.is_some() || *item_impl.self_ty != *factory_ty {
+                    continue;
+                }
+                for impl_item in &mut item_impl.items {
+                    if let ImplItem::Fn(create_fn) = impl_item {
+                        if create_fn.sig.ident == "create" {
+                            create_fn.block.stmts.insert(0, marker_call.clone());
+                        }
+                    }
+                }
+            }
+
+            items.push(marker);
+        }
+        items.push(track_env(LINT_UNBOUND_PROVIDES_ENV));
+        let (lint_items, denied) = self.lints()?;
+        items.extend(lint_items);
+
+        if denied {
+            // Denied lints discard the returned tokens as well
+            set_dummy(quote! { #(#items)* });
+        }
+
         Ok(items)
     }
+
+    /// Identifier of the provided type, after which generated items are named
+    fn provided_ident(&self) -> ProvidesResult<Ident> {
+        let injectable_ty = self.injectable_ty()?;
+        let path = &injectable_ty.as_path()?.path;
+
+        let ident = path
+            .segments
+            .last()
+            .map(|segment| segment.ident.clone())
+            .ok_or_else(|| InfallibleError::EmptyPath(path.span()))?;
+
+        Ok(ident)
+    }
+
+    /// `<vis> struct DirkQualifiedFactoryT;`, by which components select the factory of a qualified function of the impl block via `Qualified`
+    ///
+    /// Generated for impl blocks without qualified functions as well, such that binding them with a qualifier is reported as unknown qualifier.
+    fn qualified_tag(&self) -> ProvidesResult<ItemStruct> {
+        let provided_ident = self.provided_ident()?;
+        let span = provided_ident.span();
+
+        Ok(ItemStruct {
+            attrs: vec![
+                mk_attribute("doc", quote! { hidden }, span),
+                mk_attribute("allow", quote! { dead_code }, span),
+            ],
+            vis: self.factory_vis(span)?,
+            struct_token: Struct::default(),
+            ident: Ident::new(&format!("{FACTORY_PREFIX_QUALIFIED}{provided_ident}"), span),
+            generics: Generics::default(),
+            fields: Fields::Unit,
+            semi_token: Some(Semi::default()),
+        })
+    }
+
+    /// `<vis> use DirkQualifiedFactoryT as DirkFactoryT;` and `<vis> use DirkQualifiedFactoryT as StaticFactoryT;`, unless the impl block generates these names itself
+    ///
+    /// Keeps `#[use_provides] use ...::T;` resolving, which imports `DirkFactoryT` and `StaticFactoryT` next to the tag regardless of the kind of injection.
+    fn qualified_tag_aliases(&self) -> ProvidesResult<Vec<Item>> {
+        let input_macro = self.data.input_macro()?;
+        let has_unqualified = self
+            .data
+            .functions()?
+            .iter()
+            .any(|(_, qualifier)| qualifier.is_none());
+
+        let mut prefixes = Vec::new();
+        if !has_unqualified {
+            prefixes.push(FACTORY_PREFIX_ANY);
+        }
+        if !has_unqualified || !matches!(input_macro, ProvidesMacroInput::Static(_)) {
+            prefixes.push(FACTORY_PREFIX_STATIC);
+        }
+
+        let tag = self.qualified_tag()?;
+        let span = tag.ident.span();
+        let provided_ident = self.provided_ident()?;
+
+        let aliases = prefixes
+            .into_iter()
+            .map(|prefix| {
+                Item::Use(ItemUse {
+                    attrs: vec![
+                        mk_attribute("doc", quote! { hidden }, span),
+                        mk_attribute("allow", quote! { unused_imports }, span),
+                    ],
+                    vis: tag.vis.clone(),
+                    use_token: Use::default(),
+                    leading_colon: None,
+                    tree: UseTree::Rename(UseRename {
+                        ident: tag.ident.clone(),
+                        as_token: As::default(),
+                        rename: Ident::new(&format!("{prefix}{provided_ident}"), span),
+                    }),
+                    semi_token: Semi::default(),
+                })
+            })
+            .collect();
+
+        Ok(aliases)
+    }
+
+    /// `impl<qualifier> Qualified<Q, qualifier, DirkQualifiedFactoryT> for T { type Factory = F; }` and `impl<dependencies: ...> FromDependencies<dependencies> for F`, if the processed function is qualified
+    ///
+    /// Components create the factory `F` via `<<T as Qualified<...>>::Factory as FromDependencies<_>>::from_dependencies(...)`, such that an unknown qualifier is reported as unimplemented `Qualified`.
+    /// The tag is private to the crate by default, such that the impl does not leak the factory.
+    fn qualified_items(&self) -> ProvidesResult<Vec<Item>> {
+        let Some(qualifier) = self.qualifier()? else {
+            return Ok(Vec::new());
+        };
+
+        let injectable_ty = self.injectable_ty()?;
+        let factory_ty = self.factory_ty()?;
+        let generics = self.generics()?;
+        let span = qualifier.span();
+
+        let allow_non_camel_case = mk_attribute("allow", quote! { non_camel_case_types }, span);
+
+        let impl_qualified = {
+            let name_ident = Ident::new("qualifier", span);
+            let tag_ident = Ident::new(
+                &format!("{FACTORY_PREFIX_QUALIFIED}{}", self.provided_ident()?),
+                span,
+            );
+
+            let mut args = Punctuated::new();
+            args.push(GenericArgument::Type(qualifier_ty(qualifier)));
+            args.push(GenericArgument::Type(Type::Path(TypePath {
+                qself: None,
+                path: Path::from(name_ident.clone()),
+            })));
+            args.push(GenericArgument::Type(Type::Path(TypePath {
+                qself: None,
+                path: Path::from(tag_ident),
+            })));
+            let trait_path = path_qualified(
+                PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                    colon2_token: None,
+                    lt_token: Lt(span),
+                    args,
+                    gt_token: Gt(span),
+                }),
+                span,
+            );
+
+            let mut impl_generics = generics.clone();
+            impl_generics
+                .params
+                .push(GenericParam::Type(TypeParam::from(name_ident)));
+            impl_generics.lt_token.get_or_insert_with(Lt::default);
+            impl_generics.gt_token.get_or_insert_with(Gt::default);
+
+            let factory = ImplItem::Type(ImplItemType {
+                attrs: Vec::new(),
+                vis: Visibility::Inherited,
+                defaultness: None,
+                type_token: syn::token::Type(span),
+                ident: Ident::new("Factory", span),
+                generics: Generics::default(),
+                eq_token: Eq(span),
+                ty: factory_ty.clone(),
+                semi_token: Semi(span),
+            });
+
+            ItemImpl {
+                attrs: vec![allow_non_camel_case.clone()],
+                defaultness: None,
+                unsafety: None,
+                impl_token: Impl::default(),
+                generics: impl_generics,
+                trait_: Some((None, trait_path, For::default())),
+                self_ty: Box::new(injectable_ty.clone()),
+                brace_token: Brace::default(),
+                items: vec![factory],
+            }
+        };
+
+        let impl_from_dependencies = {
+            let dependencies_ident = Ident::new("dependencies", span);
+
+            let mut args = Punctuated::new();
+            args.push(GenericArgument::Type(Type::Path(TypePath {
+                qself: None,
+                path: Path::from(dependencies_ident.clone()),
+            })));
+            let trait_path = path_from_dependencies(
+                PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                    colon2_token: None,
+                    lt_token: Lt(span),
+                    args,
+                    gt_token: Gt(span),
+                }),
+                span,
+            );
+
+            // `dependencies: FDependencies`, like the generic parameter of `create_checked()`
+            let mut impl_generics = generics.clone();
+            impl_generics
+                .params
+                .extend(self.create_checked_generics(span)?.params);
+            impl_generics.lt_token.get_or_insert_with(Lt::default);
+            impl_generics.gt_token.get_or_insert_with(Gt::default);
+
+            let mut create_args = Punctuated::new();
+            create_args.push(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: Path::from(dependencies_ident),
+            }));
+            let create_call = Expr::Call(ExprCall {
+                attrs: Vec::new(),
+                func: Box::new(Expr::Path(get_call_path(
+                    &TypePath {
+                        qself: None,
+                        path: path_self(PathArguments::None, span),
+                    },
+                    Ident::new("create_checked", span),
+                ))),
+                paren_token: Paren(span),
+                args: create_args,
+            });
+
+            let from_dependencies_fn = mk_fn(
+                Ident::new("from_dependencies", span),
+                Visibility::Inherited,
+                Generics::default(),
+                Self::create_checked_args(span),
+                Type::Path(TypePath {
+                    qself: None,
+                    path: path_self(PathArguments::None, span),
+                }),
+                Block {
+                    brace_token: Brace::default(),
+                    stmts: vec![Stmt::Expr(create_call, None)],
+                },
+            );
+
+            ItemImpl {
+                attrs: vec![allow_non_camel_case],
+                defaultness: None,
+                unsafety: None,
+                impl_token: Impl::default(),
+                generics: impl_generics,
+                trait_: Some((None, trait_path, For::default())),
+                self_ty: Box::new(factory_ty.clone()),
+                brace_token: Brace::default(),
+                items: vec![from_dependencies_fn],
+            }
+        };
+
+        Ok(vec![
+            Item::Impl(impl_qualified),
+            Item::Impl(impl_from_dependencies),
+        ])
+    }
+
+    /// `<vis> use F as DirkFactoryT;`, by which components and imports refer to the factory `F` regardless of its kind
+    ///
+    /// If the factory is named by the user, `<vis> use F as KindFactoryT;` is added, such that it may still be called by that name.
+    /// Factories of qualified functions are selected via `qualified_items()` instead, such that only the latter alias is added, followed by the qualifier.
+    fn factory_aliases(&self) -> ProvidesResult<Vec<Item>> {
+        let input_args = self.data.input_args()?;
+        let factory_ident = self.factory_ident()?;
+
+        let provided_ident = self.provided_ident()?;
+
+        let span = provided_ident.span();
+        let vis = self.factory_vis(span)?;
+        let qualifier = self.qualifier()?;
+
+        let mut prefixes = Vec::new();
+        if qualifier.is_none() {
+            prefixes.push(FACTORY_PREFIX_ANY);
+        }
+        if input_args.factory().is_some() {
+            prefixes.push(input_args.kind().factory_prefix());
+        }
+
+        let aliases = prefixes
+            .into_iter()
+            .map(|prefix| {
+                let alias = qualify_ident(
+                    &Ident::new(&format!("{prefix}{provided_ident}"), span),
+                    qualifier,
+                );
+
+                Item::Use(ItemUse {
+                    attrs: vec![
+                        mk_attribute("doc", quote! { hidden }, span),
+                        mk_attribute("allow", quote! { unused_imports }, span),
+                    ],
+                    vis: vis.clone(),
+                    use_token: Use::default(),
+                    leading_colon: None,
+                    tree: UseTree::Rename(UseRename {
+                        ident: factory_ident.clone(),
+                        as_token: As::default(),
+                        rename: alias,
+                    }),
+                    semi_token: Semi::default(),
+                })
+            })
+            .collect();
+
+        Ok(aliases)
+    }
+
+    /// `impl BindableVia for T { type Kind = kind; }`, checked by components binding `T`
+    fn impl_bindable_via(&self) -> ProvidesResult<ItemImpl> {
+        let input_macro = self.data.input_macro()?;
+        let input_impl = self.data.input_impl()?;
+        let generics = self.generics()?;
+
+        // Not relying on `injectable_ty`, such that the impl is generated for an invalid impl block as well
+        let injectable_ty = &input_impl.self_ty;
+        let span = injectable_ty.span();
+
+        let trait_path = path_bindable_via(PathArguments::None, span);
+
+        let kind = ImplItem::Type(ImplItemType {
+            attrs: Vec::new(),
+            vis: Visibility::Inherited,
+            defaultness: None,
+            type_token: syn::token::Type(span),
+            ident: Ident::new("Kind", span),
+            generics: Generics::default(),
+            eq_token: Eq(span),
+            ty: input_macro.kind_ty(span),
+            semi_token: Semi(span),
+        });
+
+        // Keeps rustc from listing all provided types when reporting a type that is not provided at all
+        let do_not_recommend = Attribute {
+            pound_token: Pound::default(),
+            style: syn::AttrStyle::Outer,
+            bracket_token: Bracket::default(),
+            meta: Meta::Path(path_do_not_recommend(PathArguments::None, span)),
+        };
+
+        Ok(ItemImpl {
+            attrs: vec![do_not_recommend],
+            defaultness: None,
+            unsafety: None,
+            impl_token: Impl::default(),
+            generics: generics.clone(),
+            trait_: Some((None, trait_path, For::default())),
+            self_ty: injectable_ty.clone(),
+            brace_token: Brace::default(),
+            items: vec![kind],
+        })
+    }
+
+    /// Returns the items reporting warnings and whether any lint has been denied
+    fn lints(&self) -> ProvidesResult<(Vec<Item>, bool)> {
+        let function = self.function()?;
+
+        let level = LintLevel::from_env(LINTS_ENV, LintLevel::Warn);
+
+        let mut used = Vec::new();
+        collect_idents(function.block.to_token_stream(), &mut used);
+
+        let mut items = vec![track_env(LINTS_ENV)];
+        let mut denied = false;
+
+        for arg in &function.sig.inputs {
+            let Some(pat_ident) = arg
+                .as_typed()
+                .ok()
+                .and_then(|pat_type| pat_type.pat.as_ident().ok())
+            else {
+                continue;
+            };
+
+            // Parameters not prefixed by `_` are reported by `unused_variables` unless the lint is denied
+            let ident = &pat_ident.ident;
+            let reported = level == LintLevel::Deny || ident.to_string().starts_with('_');
+            if reported && !used.contains(ident) {
+                let msg = format!("Parameter `{ident}` is never used, hence the dependency passed for it by a component is never received");
+                items.extend(lint(level, "unreceived_dependency", ident.span(), &msg));
+                denied |= level == LintLevel::Deny;
+            }
+        }
+
+        Ok((items, denied))
+    }
+
+    /// Function only called by `create()` of the factory, such that `dead_code` reports it if no component binds the provided type
+    fn unbound_marker(&self, level: LintLevel) -> ProvidesResult<(Item, Stmt)> {
+        let injectable_path = self.injectable_path()?;
+
+        let injectable_ident = &injectable_path
+            .path
+            .segments
+            .last()
+            .ok_or_else(|| InfallibleError::EmptyPath(injectable_path.span()))?
+            .ident;
+        let span = injectable_ident.span();
+
+        let qualifier = self
+            .qualifier()?
+            .map(|qualifier| format!("_{qualifier}"))
+            .unwrap_or_default();
+        let ident = Ident::new(
+            &format!("{injectable_ident}{qualifier}_is_provided_but_never_bound"),
+            span,
+        );
+
+        let marker = ItemFn {
+            attrs: vec![
+                mk_attribute(
+                    level.name(),
+                    Ident::new("dead_code", span).into_token_stream(),
+                    span,
+                ),
+                mk_attribute(
+                    "allow",
+                    Ident::new("non_snake_case", span).into_token_stream(),
+                    span,
+                ),
+            ],
+            vis: Visibility::Inherited,
+            sig: Signature {
+                constness: None,
+                asyncness: None,
+                unsafety: None,
+                abi: None,
+                fn_token: syn::token::Fn(span),
+                ident: ident.clone(),
+                generics: Generics::default(),
+                paren_token: Paren(span),
+                inputs: Punctuated::new(),
+                variadic: None,
+                output: syn::ReturnType::Default,
+            },
+            block: Box::new(Block {
+                brace_token: Brace(span),
+                stmts: Vec::new(),
+            }),
+        };
+
+        let marker_call = Expr::Call(ExprCall {
+            attrs: Vec::new(),
+            func: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: Path::from(ident),
+            })),
+            paren_token: Paren::default(),
+            args: Punctuated::new(),
+        });
+
+        Ok((
+            Item::Fn(marker),
+            Stmt::Expr(marker_call, Some(Semi::default())),
+        ))
+    }
+}
+
+/// Escapes `{` and `}`, which are placeholders in the messages of `#[diagnostic::on_unimplemented]`
+fn escape_format(s: &str) -> String {
+    s.replace('{', "{{").replace('}', "}}")
+}
+
+/// `#[diagnostic::on_unimplemented(message = "<message>", label = "<label>")]`
+fn on_unimplemented(message: &str, label: &str, span: Span) -> Attribute {
+    let mut tokens = proc_macro2::TokenStream::new();
+    Ident::new("message", span).to_tokens(&mut tokens);
+    Eq::default().to_tokens(&mut tokens);
+    LitStr::new(message, span).to_tokens(&mut tokens);
+    Comma::default().to_tokens(&mut tokens);
+    Ident::new("label", span).to_tokens(&mut tokens);
+    Eq::default().to_tokens(&mut tokens);
+    LitStr::new(label, span).to_tokens(&mut tokens);
+
+    Attribute {
+        pound_token: Pound::default(),
+        style: syn::AttrStyle::Outer,
+        bracket_token: Bracket::default(),
+        meta: Meta::List(MetaList {
+            path: path_on_unimplemented(PathArguments::None, span),
+            delimiter: MacroDelimiter::Paren(Paren::default()),
+            tokens,
+        }),
+    }
 }
@@ -2,9 +2,14 @@ use proc_macro2::{Ident, Span};
 use syn::{
     punctuated::Punctuated,
     spanned::Spanned,
-    token::{Comma, Paren},
-    Expr, ExprCall, ExprPath, GenericArgument, GenericParam, Path, PathArguments, PathSegment,
-    Type, TypePath,
+    token::{Comma, Dyn, Paren},
+    Expr, ExprCall, ExprPath, GenericArgument, GenericParam, Lifetime, Path, PathArguments,
+    PathSegment, TraitBound, Type, TypeParamBound, TypePath, TypeTraitObject,
+};
+
+use crate::{
+    syntax::{wrap_path, wrap_type},
+    util::{path_provider, type_rc},
 };
 
 pub(crate) fn get_call_path(ty: &TypePath, call_ident: Ident) -> syn::ExprPath {
@@ -39,6 +44,26 @@ pub(crate) fn get_constructor_call(injected: ExprPath, args: Punctuated<Expr, Co
     Expr::Call(expr_call)
 }
 
+/// `Rc<dyn Provider<ty>>`
+pub(crate) fn rc_dyn_provider(ty: Type) -> Type {
+    let trait_bound = TraitBound {
+        paren_token: None,
+        modifier: syn::TraitBoundModifier::None,
+        lifetimes: None,
+        path: wrap_path(ty, path_provider),
+    };
+
+    let mut bounds = Punctuated::new();
+    bounds.push(TypeParamBound::Trait(trait_bound));
+
+    let trait_object = TypeTraitObject {
+        dyn_token: Some(Dyn::default()),
+        bounds,
+    };
+
+    wrap_type(Type::TraitObject(trait_object), type_rc)
+}
+
 pub(crate) fn get_instance_name(base: &Ident) -> Ident {
     Ident::new(&base.to_string().to_uppercase(), base.span())
 }
@@ -96,3 +121,31 @@ pub(crate) fn map_generic_params(
 
     ret
 }
+
+/// Sets the span of a generic argument as returned by `map_generic_params()`
+pub(crate) fn respan_generic_argument(arg: GenericArgument, span: Span) -> GenericArgument {
+    let respan_path = |path: Path| match path.get_ident() {
+        Some(ident) => Path::from(Ident::new(&ident.to_string(), span)),
+        None => path,
+    };
+
+    match arg {
+        GenericArgument::Lifetime(lifetime) => {
+            GenericArgument::Lifetime(Lifetime::new(&lifetime.to_string(), span))
+        }
+        GenericArgument::Type(Type::Path(type_path)) => {
+            GenericArgument::Type(Type::Path(TypePath {
+                qself: None,
+                path: respan_path(type_path.path),
+            }))
+        }
+        GenericArgument::Const(Expr::Path(expr_path)) => {
+            GenericArgument::Const(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: respan_path(expr_path.path),
+            }))
+        }
+        other => other,
+    }
+}
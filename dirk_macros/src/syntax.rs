@@ -1,12 +1,17 @@
 use proc_macro2::Span;
+use quote::ToTokens;
 use syn::{
+    ext::IdentExt,
     punctuated::Punctuated,
     spanned::Spanned,
     token::{Comma, Fn, Gt, Lt, Paren, RArrow},
-    AngleBracketedGenericArguments, Block, FnArg, GenericArgument, Generics, Ident, ImplItem,
-    ImplItemFn, Path, PathArguments, Signature, Type, Visibility,
+    AngleBracketedGenericArguments, Block, Expr, ExprLit, FnArg, GenericArgument, Generics, Ident,
+    ImplItem, ImplItemFn, Lit, LitChar, Path, PathArguments, Signature, Type, TypeTuple, UseName,
+    UseTree, Visibility,
 };
 
+use crate::util::type_qualifier_char;
+
 pub(crate) fn wrap_type(wrapped: Type, getter_type: fn(PathArguments, Span) -> Type) -> Type {
     let span = wrapped.span();
     let arg = GenericArgument::Type(wrapped);
@@ -69,3 +74,83 @@ pub(crate) fn mk_fn(
         block,
     })
 }
+
+pub(crate) fn type_to_string(ty: &Type) -> String {
+    ty.to_token_stream()
+        .to_string()
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+}
+
+/// Collects all identifiers contained in `tokens`, including nested groups
+pub(crate) fn collect_idents(tokens: proc_macro2::TokenStream, idents: &mut Vec<Ident>) {
+    for token in tokens {
+        match token {
+            proc_macro2::TokenTree::Ident(ident) => idents.push(ident),
+            proc_macro2::TokenTree::Group(group) => collect_idents(group.stream(), idents),
+            proc_macro2::TokenTree::Punct(_) | proc_macro2::TokenTree::Literal(_) => {}
+        }
+    }
+}
+
+/// Replaces the single name imported by `tree` with `ident`, returns whether `tree` imports exactly one name
+pub(crate) fn replace_use_tree_name(tree: &mut UseTree, ident: Ident) -> bool {
+    match tree {
+        UseTree::Path(path) => replace_use_tree_name(&mut path.tree, ident),
+        UseTree::Group(group) if group.items.len() == 1 => {
+            replace_use_tree_name(&mut group.items[0], ident)
+        }
+        UseTree::Name(_) | UseTree::Rename(_) => {
+            *tree = UseTree::Name(UseName { ident });
+            true
+        }
+        UseTree::Group(_) | UseTree::Glob(_) => false,
+    }
+}
+
+/// Appends `qualifier` to `ident`, separated by `_`, e.g., `StaticFactoryPool_write` for `StaticFactoryPool` qualified by `write`
+///
+/// Unlike appending it in pascal case, this does not collide with the factory of a camel case type like `PoolWrite`.
+pub(crate) fn qualify_ident(ident: &Ident, qualifier: Option<&Ident>) -> Ident {
+    match qualifier {
+        Some(qualifier) => Ident::new(&format!("{ident}_{}", qualifier.unraw()), ident.span()),
+        None => ident.clone(),
+    }
+}
+
+/// `(QualifierChar<'w'>, QualifierChar<'r'>, ...)`, the type naming `qualifier` in `Qualified`
+pub(crate) fn qualifier_ty(qualifier: &Ident) -> Type {
+    let span = qualifier.span();
+
+    let mut elems = Punctuated::new();
+    for c in qualifier.unraw().to_string().chars() {
+        let mut args = Punctuated::new();
+        args.push(GenericArgument::Const(Expr::Lit(ExprLit {
+            attrs: Vec::new(),
+            lit: Lit::Char(LitChar::new(c, span)),
+        })));
+
+        elems.push(type_qualifier_char(
+            PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                colon2_token: None,
+                lt_token: Lt(span),
+                args,
+                gt_token: Gt(span),
+            }),
+            span,
+        ));
+    }
+    if elems.len() == 1 {
+        elems.push_punct(Comma(span));
+    }
+
+    Type::Tuple(TypeTuple {
+        paren_token: Paren(span),
+        elems,
+    })
+}
@@ -0,0 +1,69 @@
+use proc_macro_error::abort;
+use syn::{Pat, Receiver};
+
+use crate::{
+    errors::ExpectableError,
+    errors::{InfallibleError, SyntaxError},
+};
+
+pub(crate) type TestResult<T> = std::result::Result<T, TestError>;
+
+#[derive(Debug)]
+pub(crate) enum TestError {
+    Infallible(InfallibleError<TestSyntaxError>),
+    Logic(TestLogicError),
+}
+
+impl_abort!(TestError);
+impl_from_infallible_error!(TestError, TestSyntaxError);
+
+#[derive(Debug)]
+pub(crate) enum TestSyntaxError {
+    FailedToParseInput(syn::Error),
+    ExpectedFn(syn::Error),
+}
+
+impl SyntaxError for TestSyntaxError {
+    fn abort(self) -> ! {
+        match self {
+            Self::FailedToParseInput(e) => abort!(
+                e.span(),
+                e.to_string();
+                help = "Expected `#[test(component = MyComponent)]`"
+            ),
+            Self::ExpectedFn(e) => abort!(
+                e.span(),
+                e.to_string();
+                help = "#[test(...)] is expected to be placed on a function"
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum TestLogicError {
+    FoundReceiver(Receiver),
+    ExpectedIdentPattern(Box<Pat>),
+}
+
+impl From<TestLogicError> for TestError {
+    fn from(value: TestLogicError) -> Self {
+        Self::Logic(value)
+    }
+}
+
+impl TestLogicError {
+    fn abort(self) -> ! {
+        match self {
+            TestLogicError::FoundReceiver(receiver) => abort!(
+                receiver,
+                "#[test(...)] is expected to be placed on a free function"
+            ),
+            TestLogicError::ExpectedIdentPattern(pat) => abort!(
+                pat,
+                "Parameters of a test are expected to be named like the function of the component they are queried from";
+                help = "Use an identifier, e.g., `answer: usize`, as pattern"
+            ),
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::{And, Bracket, Comma, Eq, Gt, Let, Lt, Paren, PathSep, Pound, Semi, Underscore},
+    AngleBracketedGenericArguments, Attribute, Expr, ExprCall, ExprPath, ExprReference, FnArg,
+    GenericArgument, Ident, ItemFn, Local, LocalInit, Meta, Pat, PatIdent, PatType, Path,
+    PathArguments, PathSegment, QSelf, Stmt, Token, Type, TypeInfer, TypePath,
+};
+
+use crate::{
+    component::syntax::get_dirk_name,
+    errors::InfallibleError,
+    util::{path_static_component, with_framework_path},
+};
+
+use self::error::{TestLogicError, TestResult, TestSyntaxError};
+
+mod error;
+
+pub(crate) fn _macro(attr: TokenStream, item: TokenStream) -> TestResult<TokenStream> {
+    let input = syn::parse::<TestMacroInput>(attr).map_err(TestSyntaxError::FailedToParseInput)?;
+    let mut input_fn = syn::parse::<ItemFn>(item).map_err(TestSyntaxError::ExpectedFn)?;
+
+    let component_ident = input.component_ident()?;
+    let span = component_ident.span();
+    let instance_ident = Ident::new("dirk_component", span);
+
+    let create = with_framework_path(input.krate.as_ref(), || {
+        input.create_component(&instance_ident)
+    });
+    let mut stmts = vec![create?];
+
+    for arg in std::mem::take(&mut input_fn.sig.inputs) {
+        let pat_type = match arg {
+            FnArg::Typed(pat_type) => pat_type,
+            FnArg::Receiver(receiver) => Err(TestLogicError::FoundReceiver(receiver))?,
+        };
+
+        let ident = match pat_type.pat.as_ref() {
+            Pat::Ident(PatIdent {
+                ident,
+                subpat: None,
+                ..
+            }) => ident.clone(),
+            _ => Err(TestLogicError::ExpectedIdentPattern(pat_type.pat.clone()))?,
+        };
+
+        stmts.push(input.query_component(&instance_ident, ident, pat_type));
+    }
+
+    let test_attr = Attribute {
+        pound_token: Pound::default(),
+        style: syn::AttrStyle::Outer,
+        bracket_token: Bracket::default(),
+        meta: Meta::Path(Path::from(Ident::new("test", span))),
+    };
+    input_fn.attrs.insert(0, test_attr);
+
+    stmts.append(&mut input_fn.block.stmts);
+    input_fn.block.stmts = stmts;
+
+    let expanded = quote! { #input_fn };
+    Ok(TokenStream::from(expanded))
+}
+
+mod kw {
+    syn::custom_keyword!(component);
+    syn::custom_keyword!(name);
+}
+
+#[derive(Debug)]
+struct TestMacroInput {
+    component: Path,
+    /// Name of the component, if chosen via `#[component(name = ...)]`
+    name: Option<Ident>,
+    /// Path of the framework, e.g., `crate = platform::di`
+    krate: Option<Path>,
+}
+
+impl Parse for TestMacroInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::component>()?;
+        input.parse::<Eq>()?;
+        let component = input.parse()?;
+
+        let mut name = None;
+        let mut krate = None;
+        while input.parse::<Option<Comma>>()?.is_some() && !input.is_empty() {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(kw::name) && name.is_none() {
+                input.parse::<kw::name>()?;
+                input.parse::<Eq>()?;
+                name = Some(input.parse()?);
+            } else if lookahead.peek(Token![crate]) && krate.is_none() {
+                input.parse::<Token![crate]>()?;
+                input.parse::<Eq>()?;
+                krate = Some(input.call(Path::parse_mod_style)?);
+            } else {
+                return Err(lookahead.error());
+            }
+        }
+
+        if !input.is_empty() {
+            let lookahead = input.lookahead1();
+            return Err(lookahead.error());
+        }
+
+        Ok(Self {
+            component,
+            name,
+            krate,
+        })
+    }
+}
+
+impl TestMacroInput {
+    fn component_ident(&self) -> TestResult<&Ident> {
+        let segment = self
+            .component
+            .segments
+            .last()
+            .ok_or_else(|| InfallibleError::EmptyPath(self.component.span()))?;
+
+        Ok(&segment.ident)
+    }
+
+    /// `let dirk_component = <DirkX as dirk_framework::component::StaticComponent<_, _>>::create();`
+    fn create_component(&self, instance_ident: &Ident) -> TestResult<Stmt> {
+        let span = instance_ident.span();
+
+        let dirk_ty = {
+            let mut path = self.component.clone();
+            let segment = path
+                .segments
+                .last_mut()
+                .ok_or_else(|| InfallibleError::EmptyPath(self.component.span()))?;
+            segment.ident = match &self.name {
+                Some(name) => name.clone(),
+                None => get_dirk_name(&segment.ident),
+            };
+            segment.arguments = PathArguments::None;
+
+            Type::Path(TypePath { qself: None, path })
+        };
+
+        let mut path = {
+            let mut args = Punctuated::new();
+            for _ in 0..2 {
+                args.push(GenericArgument::Type(Type::Infer(TypeInfer {
+                    underscore_token: Underscore::default(),
+                })));
+            }
+
+            path_static_component(
+                PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                    colon2_token: None,
+                    lt_token: Lt::default(),
+                    args,
+                    gt_token: Gt::default(),
+                }),
+                span,
+            )
+        };
+        let position = path.segments.len();
+        path.segments
+            .push(PathSegment::from(Ident::new("create", span)));
+
+        let create = Expr::Call(ExprCall {
+            attrs: Vec::new(),
+            func: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: Some(QSelf {
+                    lt_token: Lt::default(),
+                    ty: Box::new(dirk_ty),
+                    position,
+                    as_token: Some(syn::token::As::default()),
+                    gt_token: Gt::default(),
+                }),
+                path,
+            })),
+            paren_token: Paren::default(),
+            args: Punctuated::new(),
+        });
+
+        let pat = Pat::Ident(PatIdent {
+            attrs: Vec::new(),
+            by_ref: None,
+            mutability: None,
+            ident: instance_ident.clone(),
+            subpat: None,
+        });
+
+        Ok(mk_local(pat, create))
+    }
+
+    /// `let <ident>: <ty> = X::<ident>(&dirk_component);`
+    fn query_component(&self, instance_ident: &Ident, ident: Ident, pat_type: PatType) -> Stmt {
+        let mut path = self.component.clone();
+        for segment in &mut path.segments {
+            if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                args.colon2_token = Some(PathSep::default());
+            }
+        }
+        path.segments.push(PathSegment::from(ident));
+
+        let mut args = Punctuated::new();
+        args.push(Expr::Reference(ExprReference {
+            attrs: Vec::new(),
+            and_token: And::default(),
+            mutability: None,
+            expr: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: Path::from(instance_ident.clone()),
+            })),
+        }));
+
+        let query = Expr::Call(ExprCall {
+            attrs: Vec::new(),
+            func: Box::new(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path,
+            })),
+            paren_token: Paren::default(),
+            args,
+        });
+
+        let pat = Pat::Type(PatType {
+            attrs: Vec::new(),
+            ..pat_type
+        });
+
+        mk_local(pat, query)
+    }
+}
+
+fn mk_local(pat: Pat, expr: Expr) -> Stmt {
+    Stmt::Local(Local {
+        attrs: Vec::new(),
+        let_token: Let::default(),
+        pat,
+        init: Some(LocalInit {
+            eq_token: Eq::default(),
+            expr: Box::new(expr),
+            diverge: None,
+        }),
+        semi_token: Semi::default(),
+    })
+}
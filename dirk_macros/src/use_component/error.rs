@@ -1,5 +1,5 @@
 use proc_macro_error::abort;
-use syn::UseGlob;
+use syn::UseTree;
 
 use crate::{
     errors::ExpectableError,
@@ -15,6 +15,7 @@ pub(crate) enum UseComponentError {
 }
 
 impl_abort!(UseComponentError);
+
 impl_from_infallible_error!(UseComponentError, UseComponentSyntaxError);
 
 #[derive(Debug)]
@@ -38,7 +39,7 @@ impl SyntaxError for UseComponentSyntaxError {
 
 #[derive(Debug)]
 pub(crate) enum UseComponentLogicError {
-    FoundGlob(UseGlob),
+    ExpectedSingleComponent(UseTree),
 }
 
 impl From<UseComponentLogicError> for UseComponentError {
@@ -50,9 +51,9 @@ impl From<UseComponentLogicError> for UseComponentError {
 impl UseComponentLogicError {
     fn abort(self) -> ! {
         match self {
-            UseComponentLogicError::FoundGlob(use_glob) => abort!(
-                use_glob,
-                "#[use_component] on wildcard use items is not supported"
+            UseComponentLogicError::ExpectedSingleComponent(use_tree) => abort!(
+                use_tree,
+                "#[use_component(name = ..., builder = ...)] is expected to be placed on a use item importing a single component"
             ),
         }
     }
@@ -5,10 +5,13 @@ use syn::{
     parse::Parse,
     spanned::Spanned,
     token::{Bracket, Paren, Pound},
-    Attribute, Item, ItemUse, MetaList, PathArguments, UseTree,
+    Attribute, Item, ItemUse, MetaList, PathArguments, Token, UseTree,
 };
 
-use crate::util::{path_allow, path_unused_imports};
+use crate::{
+    syntax::replace_use_tree_name,
+    util::{path_allow, path_unused_imports},
+};
 
 use self::error::{UseComponentLogicError, UseComponentResult, UseComponentSyntaxError};
 
@@ -35,56 +38,100 @@ pub(crate) fn _macro(attr: TokenStream, item: TokenStream) -> UseComponentResult
 
     let mut use_dirk = input_use.clone();
     use_dirk.attrs = Vec::new();
-    input.convert_use_tree(&mut use_dirk.tree, "Dirk", "")?;
+    let converted = input.convert_use_tree(&mut use_dirk.tree, "Dirk", "");
 
     let mut use_builder = input_use.clone();
     use_builder.attrs = Vec::new();
-    input.convert_use_tree(&mut use_builder.tree, "Dirk", "Builder")?;
+    input.convert_use_tree(&mut use_builder.tree, "Dirk", "Builder");
     use_builder.attrs.push(allow_attr.clone());
 
-    let items = vec![
-        Item::Use(input_use),
-        Item::Use(use_dirk),
-        Item::Use(use_builder),
-    ];
+    // Components named by the user are imported by their names
+    let builder = input.builder.clone().or_else(|| {
+        input
+            .name
+            .as_ref()
+            .map(|name| Ident::new(&format!("{name}Builder"), name.span()))
+    });
+    for (use_item, ident) in [(&mut use_dirk, &input.name), (&mut use_builder, &builder)] {
+        if let Some(ident) = ident {
+            if !replace_use_tree_name(&mut use_item.tree, ident.clone()) {
+                Err(UseComponentLogicError::ExpectedSingleComponent(
+                    input_use.tree.clone(),
+                ))?;
+            }
+        }
+    }
+
+    let mut items = vec![Item::Use(input_use)];
+    if converted {
+        items.push(Item::Use(use_dirk));
+        items.push(Item::Use(use_builder));
+    }
 
     let expanded = quote! { #(#items)* };
     Ok(TokenStream::from(expanded))
 }
 
-#[allow(dead_code)]
+mod kw {
+    syn::custom_keyword!(name);
+    syn::custom_keyword!(builder);
+}
+
+/// Names of the component and its builder, if chosen via `#[component(name = ..., builder = ...)]`
 #[derive(Debug, Default)]
-struct UseComponentMacroInput {}
+struct UseComponentMacroInput {
+    name: Option<Ident>,
+    builder: Option<Ident>,
+}
 
 impl Parse for UseComponentMacroInput {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        if input.is_empty() {
-            return Ok(Self::default());
+        let mut res = Self::default();
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+            let (option, span) = if lookahead.peek(kw::name) {
+                (&mut res.name, input.parse::<kw::name>()?.span)
+            } else if lookahead.peek(kw::builder) {
+                (&mut res.builder, input.parse::<kw::builder>()?.span)
+            } else {
+                return Err(lookahead.error());
+            };
+
+            if option.is_some() {
+                return Err(syn::Error::new(span, "duplicate option"));
+            }
+            input.parse::<Token![=]>()?;
+            *option = Some(input.parse()?);
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
         }
 
-        let lookahead = input.lookahead1();
-        Err(lookahead.error())
+        Ok(res)
     }
 }
 
 impl UseComponentMacroInput {
+    /// Converts `tree` to import the corresponding generated types, returns whether anything is left to import
+    ///
+    /// Globs are removed, as the original glob import already covers the types generated next to the component.
     #[allow(clippy::only_used_in_recursion)]
-    fn convert_use_tree(
-        &self,
-        tree: &mut UseTree,
-        prefix: &str,
-        postfix: &str,
-    ) -> UseComponentResult<()> {
+    fn convert_use_tree(&self, tree: &mut UseTree, prefix: &str, postfix: &str) -> bool {
         match tree {
             UseTree::Path(path) => self.convert_use_tree(&mut path.tree, prefix, postfix),
-            UseTree::Group(g) => g
-                .items
-                .iter_mut()
-                .try_for_each(|i| self.convert_use_tree(i, prefix, postfix)),
+            UseTree::Group(g) => {
+                g.items = std::mem::take(&mut g.items)
+                    .into_iter()
+                    .filter_map(|mut i| self.convert_use_tree(&mut i, prefix, postfix).then_some(i))
+                    .collect();
+                !g.items.is_empty()
+            }
             UseTree::Name(name) => {
                 let ident = &name.ident;
                 name.ident = Ident::new(&format!("{prefix}{ident}{postfix}"), ident.span());
-                Ok(())
+                true
             }
             UseTree::Rename(use_rename) => {
                 let ident = &use_rename.ident;
@@ -94,9 +141,9 @@ impl UseComponentMacroInput {
                 use_rename.rename =
                     Ident::new(&format!("{prefix}{rename}{postfix}"), rename.span());
 
-                Ok(())
+                true
             }
-            UseTree::Glob(g) => Err(UseComponentLogicError::FoundGlob(g.clone()))?,
+            UseTree::Glob(_) => false,
         }
     }
 }
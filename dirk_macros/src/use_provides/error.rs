@@ -1,5 +1,5 @@
 use proc_macro_error::abort;
-use syn::UseGlob;
+use syn::UseTree;
 
 use crate::{
     errors::ExpectableError,
@@ -15,6 +15,7 @@ pub(crate) enum UseInjectableError {
 }
 
 impl_abort!(UseInjectableError);
+
 impl_from_infallible_error!(UseInjectableError, UseInjectableSyntaxError);
 
 #[derive(Debug)]
@@ -38,7 +39,7 @@ impl SyntaxError for UseInjectableSyntaxError {
 
 #[derive(Debug)]
 pub(crate) enum UseInjectableLogicError {
-    FoundGlob(UseGlob),
+    ExpectedSingleType(UseTree),
 }
 
 impl From<UseInjectableLogicError> for UseInjectableError {
@@ -50,9 +51,9 @@ impl From<UseInjectableLogicError> for UseInjectableError {
 impl UseInjectableLogicError {
     fn abort(self) -> ! {
         match self {
-            UseInjectableLogicError::FoundGlob(use_glob) => abort!(
-                use_glob,
-                "#[use_provides] on wildcard use items is not supported"
+            UseInjectableLogicError::ExpectedSingleType(use_tree) => abort!(
+                use_tree,
+                "#[use_provides(factory = ...)] is expected to be placed on a use item importing a single type"
             ),
         }
     }
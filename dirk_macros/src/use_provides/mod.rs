@@ -1,25 +1,59 @@
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
 use quote::quote;
-use syn::{parse::Parse, Item, ItemUse, UseTree};
+use syn::{
+    parse::Parse, punctuated::Punctuated, token::Brace, Item, ItemUse, Token, UseGroup, UseRename,
+    UseTree,
+};
 
-use crate::{FACTORY_PREFIX_SCOPED, FACTORY_PREFIX_SINGLETON, FACTORY_PREFIX_STATIC};
+use crate::{
+    lint::mk_attribute, syntax::replace_use_tree_name, FACTORY_PREFIX_ANY, FACTORY_PREFIX_MEMBERS,
+    FACTORY_PREFIX_QUALIFIED, FACTORY_PREFIX_SCOPED, FACTORY_PREFIX_SINGLETON,
+    FACTORY_PREFIX_STATIC,
+};
 
 use self::error::{UseInjectableLogicError, UseInjectableResult, UseInjectableSyntaxError};
 
 mod error;
 
 pub(crate) fn _macro(attr: TokenStream, item: TokenStream) -> UseInjectableResult<TokenStream> {
-    let input = syn::parse::<UseInjectMacroInput>(attr)
+    let UseInjectMacroArgs {
+        kind: input,
+        factory,
+    } = syn::parse::<UseInjectMacroArgs>(attr)
         .map_err(UseInjectableSyntaxError::FailedToParseInput)?;
     let input_use = syn::parse::<ItemUse>(item).map_err(UseInjectableSyntaxError::ExpectedUse)?;
 
     let mut use_factories = input_use.clone();
 
     use_factories.attrs = Vec::new();
-    input.convert_use_tree(&mut use_factories.tree)?;
+    let converted = input.convert_use_tree(&mut use_factories.tree);
+    if !matches!(input, UseInjectMacroInput::Members(_)) {
+        // Components only refer to the kind-independent alias, which is unused if the type is not bound
+        let allow_attr = mk_attribute("allow", quote! { unused_imports }, Span::call_site());
+        use_factories.attrs.push(allow_attr);
+    }
 
-    let items = vec![Item::Use(input_use), Item::Use(use_factories)];
+    // Factories named by the user are imported by their names as well
+    let use_factory = factory
+        .map(|factory| {
+            let mut use_factory = input_use.clone();
+            use_factory.attrs = Vec::new();
+            if replace_use_tree_name(&mut use_factory.tree, factory) {
+                Ok(use_factory)
+            } else {
+                Err(UseInjectableLogicError::ExpectedSingleType(
+                    input_use.tree.clone(),
+                ))
+            }
+        })
+        .transpose()?;
+
+    let mut items = vec![Item::Use(input_use)];
+    if converted {
+        items.push(Item::Use(use_factories));
+    }
+    items.extend(use_factory.map(Item::Use));
 
     let expanded = quote! { #(#items)* };
     Ok(TokenStream::from(expanded))
@@ -29,22 +63,54 @@ mod kw {
     syn::custom_keyword!(singleton_inject);
     syn::custom_keyword!(scoped_inject);
     syn::custom_keyword!(static_inject);
+    syn::custom_keyword!(inject_members);
+    syn::custom_keyword!(factory);
+}
+
+/// Arguments of `#[use_provides(...)]`, i.e., the kind of injection followed by `factory = EngineFactory`, if the factory is named by the user
+#[derive(Debug, Default)]
+struct UseInjectMacroArgs {
+    kind: UseInjectMacroInput,
+    factory: Option<Ident>,
+}
+
+impl Parse for UseInjectMacroArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = Self::default();
+
+        if !input.is_empty() && !input.peek2(Token![=]) {
+            args.kind = input.parse()?;
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        if !input.is_empty() {
+            input.parse::<kw::factory>()?;
+            input.parse::<Token![=]>()?;
+            args.factory = Some(input.parse()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+
+        if !input.is_empty() {
+            let lookahead = input.lookahead1();
+            return Err(lookahead.error());
+        }
+
+        Ok(args)
+    }
 }
 
 #[allow(dead_code)]
-#[derive(Debug)]
+#[derive(Debug, Default)]
 enum UseInjectMacroInput {
+    /// No kind given, the factory is imported via its kind-independent alias
+    #[default]
+    Any,
     Scoped(kw::scoped_inject),
     Singleton(kw::singleton_inject),
     Static(kw::static_inject),
-}
-
-impl Default for UseInjectMacroInput {
-    fn default() -> Self {
-        Self::Static(kw::static_inject {
-            span: Span::call_site(),
-        })
-    }
+    Members(kw::inject_members),
 }
 
 impl Parse for UseInjectMacroInput {
@@ -63,6 +129,9 @@ impl Parse for UseInjectMacroInput {
         } else if lookahead.peek(kw::static_inject) {
             let kw = kw::static_inject::parse(input)?;
             Self::Static(kw)
+        } else if lookahead.peek(kw::inject_members) {
+            let kw = kw::inject_members::parse(input)?;
+            Self::Members(kw)
         } else {
             return Err(lookahead.error());
         };
@@ -74,36 +143,75 @@ impl Parse for UseInjectMacroInput {
 impl UseInjectMacroInput {
     fn factory_prefix(&self) -> &'static str {
         match self {
+            UseInjectMacroInput::Any => FACTORY_PREFIX_ANY,
             UseInjectMacroInput::Singleton(_) => FACTORY_PREFIX_SINGLETON,
             UseInjectMacroInput::Scoped(_) => FACTORY_PREFIX_SCOPED,
             UseInjectMacroInput::Static(_) => FACTORY_PREFIX_STATIC,
+            UseInjectMacroInput::Members(_) => FACTORY_PREFIX_MEMBERS,
         }
     }
 
-    fn convert_use_tree(&self, tree: &mut UseTree) -> UseInjectableResult<()> {
+    /// Converts `tree` to import the corresponding factories, returns whether anything is left to import
+    ///
+    /// Globs are removed, as the original glob import already covers the factories generated next to the provided types.
+    fn convert_use_tree(&self, tree: &mut UseTree) -> bool {
         match tree {
             UseTree::Path(path) => self.convert_use_tree(&mut path.tree),
-            UseTree::Group(g) => g
-                .items
-                .iter_mut()
-                .try_for_each(|i| self.convert_use_tree(i)),
+            UseTree::Group(g) => {
+                g.items = std::mem::take(&mut g.items)
+                    .into_iter()
+                    .filter_map(|mut i| self.convert_use_tree(&mut i).then_some(i))
+                    .collect();
+                !g.items.is_empty()
+            }
             UseTree::Name(name) => {
-                let ident = &name.ident;
-                name.ident = Ident::new(&format!("{}{ident}", self.factory_prefix()), ident.span());
-                Ok(())
+                *tree = self.import_factories(&name.ident, &name.ident);
+                true
             }
             UseTree::Rename(use_rename) => {
-                let ident = &use_rename.ident;
-                use_rename.ident =
-                    Ident::new(&format!("{}{ident}", self.factory_prefix()), ident.span());
-
-                let rename = &use_rename.rename;
-                use_rename.rename =
-                    Ident::new(&format!("{}{rename}", self.factory_prefix()), rename.span());
-
-                Ok(())
+                *tree = self.import_factories(&use_rename.ident, &use_rename.rename);
+                true
             }
-            UseTree::Glob(g) => Err(UseInjectableLogicError::FoundGlob(g.clone()))?,
+            UseTree::Glob(_) => false,
         }
     }
+
+    /// `{DirkFactoryT as DirkFactoryR, DirkQualifiedFactoryT as DirkQualifiedFactoryR, ScopedFactoryT as ScopedFactoryR}`, importing `T` as `R`
+    ///
+    /// Components refer to factories by their kind-independent aliases and to factories of qualified functions via `DirkQualifiedFactoryT`.
+    /// The factory named after the kind is imported as well, the static one if no kind is given.
+    /// `#[provides]` keeps the latter resolving for other kinds, by aliasing `DirkQualifiedFactoryT` as `StaticFactoryT`.
+    fn import_factories(&self, ident: &Ident, rename: &Ident) -> UseTree {
+        let prefixes = match self {
+            Self::Any => vec![
+                FACTORY_PREFIX_ANY,
+                FACTORY_PREFIX_QUALIFIED,
+                FACTORY_PREFIX_STATIC,
+            ],
+            Self::Members(_) => vec![FACTORY_PREFIX_MEMBERS],
+            Self::Scoped(_) | Self::Singleton(_) | Self::Static(_) => {
+                vec![
+                    FACTORY_PREFIX_ANY,
+                    FACTORY_PREFIX_QUALIFIED,
+                    self.factory_prefix(),
+                ]
+            }
+        };
+
+        let items = prefixes
+            .into_iter()
+            .map(|prefix| {
+                UseTree::Rename(UseRename {
+                    ident: Ident::new(&format!("{prefix}{ident}"), ident.span()),
+                    as_token: syn::token::As::default(),
+                    rename: Ident::new(&format!("{prefix}{rename}"), rename.span()),
+                })
+            })
+            .collect::<Punctuated<_, _>>();
+
+        UseTree::Group(UseGroup {
+            brace_token: Brace::default(),
+            items,
+        })
+    }
 }
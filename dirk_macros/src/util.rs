@@ -1,7 +1,52 @@
+use std::cell::RefCell;
+
 use proc_macro2::Span;
-use syn::{
-    punctuated::Punctuated, token::PathSep, Ident, Path, PathArguments, PathSegment, Type, TypePath,
-};
+use syn::{punctuated::Punctuated, Ident, Path, PathArguments, PathSegment, Type, TypePath};
+
+const FRAMEWORK_CRATE: &str = "dirk_framework";
+
+thread_local! {
+    /// Path to the framework used by generated code, if configured via `crate = ...`
+    static FRAMEWORK_PATH: RefCell<Option<Path>> = const { RefCell::new(None) };
+}
+
+/// Resets the framework path once a macro invocation is done, even if it aborts
+struct FrameworkPathGuard(Option<Path>);
+
+impl Drop for FrameworkPathGuard {
+    fn drop(&mut self) {
+        FRAMEWORK_PATH.set(self.0.take());
+    }
+}
+
+/// Evaluates `f`, rooting all paths to `dirk_framework` created in this module at `path` instead (if given)
+pub(crate) fn with_framework_path<T>(path: Option<&Path>, f: impl FnOnce() -> T) -> T {
+    let _guard = FrameworkPathGuard(FRAMEWORK_PATH.replace(path.cloned()));
+    f()
+}
+
+/// Creates a path prefix from `names`, replacing `dirk_framework` by the configured framework path
+fn mk_prefix(names: &[&str], span: Span) -> Path {
+    let mut path = Path {
+        leading_colon: None,
+        segments: Punctuated::new(),
+    };
+
+    for name in names {
+        if *name == FRAMEWORK_CRATE {
+            if let Some(framework) = FRAMEWORK_PATH.with_borrow(Clone::clone) {
+                path.leading_colon = framework.leading_colon;
+                path.segments.extend(framework.segments);
+                continue;
+            }
+        }
+
+        let ident = Ident::new(name, span);
+        path.segments.push(PathSegment::from(ident));
+    }
+
+    path
+}
 
 macro_rules! mk_type {
     ($ty:ident, $($segments:literal),+) => {
@@ -16,25 +61,16 @@ macro_rules! mk_type {
 
 pub(crate) fn $ty(generics: PathArguments, span: Span) -> Type {
     let path =  {
-        let mut segments: Punctuated<PathSegment, PathSep> = Punctuated::new();
-
-        $(
-        let segment =  {
-            let ident = Ident::new($segments, span.clone());
-            let arguments = PathArguments::None;
-            PathSegment { ident, arguments}
-        };
-        segments.push(segment);
-        )*
+        let mut path = mk_prefix(&[$($segments),*], span);
 
         let segment =  {
             let ident = Ident::new($head, span);
             let arguments = generics;
             PathSegment { ident, arguments}
         };
-        segments.push(segment);
+        path.segments.push(segment);
 
-        TypePath { qself: None, path:  Path { leading_colon: None, segments }}
+        TypePath { qself: None, path }
     };
     Type::Path(path)
 }
@@ -42,6 +78,12 @@ pub(crate) fn $ty(generics: PathArguments, span: Span) -> Type {
 }
 
 mk_type!(type_provider, "dirk_framework", "provides", "Provider");
+mk_type!(
+    type_members_injector,
+    "dirk_framework",
+    "provides",
+    "MembersInjector"
+);
 mk_type!(
     type_factory_instance,
     "dirk_framework",
@@ -56,8 +98,29 @@ mk_type!(
     "Unset"
 );
 mk_type!(type_set, "dirk_framework", "component", "builder", "Set");
+mk_type!(
+    type_binding_descriptor,
+    "dirk_framework",
+    "component",
+    "BindingDescriptor"
+);
+mk_type!(type_vec, "std", "vec", "Vec");
+mk_type!(type_formatter, "std", "fmt", "Formatter");
+mk_type!(type_fmt_result, "std", "fmt", "Result");
+mk_type!(type_result, "std", "result", "Result");
+mk_type!(
+    type_build_error,
+    "dirk_framework",
+    "component",
+    "builder",
+    "BuildError"
+);
 mk_type!(type_rc, "std", "rc", "Rc");
+mk_type!(type_option, "std", "option", "Option");
+mk_type!(type_str, "str");
+mk_type!(type_bool, "bool");
 mk_type!(type_refcell, "std", "cell", "RefCell");
+mk_type!(type_cell, "std", "cell", "Cell");
 mk_type!(type_arc, "std", "sync", "Arc");
 mk_type!(type_rwlock, "std", "sync", "RwLock");
 
@@ -72,28 +135,16 @@ macro_rules! mk_path {
 
     ($name:ident [$($segments:literal)*] $head:literal) => {
 pub(crate) fn $name(generics: PathArguments, span: Span) -> Path {
-    let segments = {
-        let mut segments: Punctuated<PathSegment, PathSep> = Punctuated::new();
+    let mut path = mk_prefix(&[$($segments),*], span);
 
-        $(
-        let segment =  {
-            let ident = Ident::new($segments, span);
-            let arguments = PathArguments::None;
-            PathSegment { ident, arguments}
-        };
-        segments.push(segment);
-        )*
-
-        let segment =  {
-            let ident = Ident::new($head, span);
-            let arguments = generics;
-            PathSegment { ident, arguments}
-        };
-        segments.push(segment);
-
-        segments
+    let segment =  {
+        let ident = Ident::new($head, span);
+        let arguments = generics;
+        PathSegment { ident, arguments}
     };
-    Path {leading_colon: None, segments }
+    path.segments.push(segment);
+
+    path
 }
     };
 }
@@ -103,6 +154,82 @@ mk_path!(path_unused_imports, "unused_imports");
 mk_path!(path_crate, "crate");
 
 mk_path!(path_provider, "dirk_framework", "provides", "Provider");
+mk_path!(
+    path_members_injector,
+    "dirk_framework",
+    "provides",
+    "MembersInjector"
+);
+mk_path!(
+    path_bindable_via,
+    "dirk_framework",
+    "provides",
+    "kind",
+    "BindableVia"
+);
+mk_path!(
+    path_matches_kind,
+    "dirk_framework",
+    "provides",
+    "kind",
+    "MatchesKind"
+);
+mk_type!(
+    type_kind_static,
+    "dirk_framework",
+    "provides",
+    "kind",
+    "static_bind"
+);
+mk_type!(
+    type_kind_scoped,
+    "dirk_framework",
+    "provides",
+    "kind",
+    "scoped_bind"
+);
+mk_type!(
+    type_kind_singleton,
+    "dirk_framework",
+    "provides",
+    "kind",
+    "singleton_bind"
+);
+mk_path!(
+    path_qualified,
+    "dirk_framework",
+    "provides",
+    "qualifier",
+    "Qualified"
+);
+mk_path!(
+    path_from_dependencies,
+    "dirk_framework",
+    "provides",
+    "qualifier",
+    "FromDependencies"
+);
+mk_type!(
+    type_qualifier_char,
+    "dirk_framework",
+    "provides",
+    "qualifier",
+    "QualifierChar"
+);
+mk_type!(
+    type_overrides,
+    "dirk_framework",
+    "component",
+    "overrides",
+    "Overrides"
+);
+mk_type!(
+    type_no_overrides,
+    "dirk_framework",
+    "component",
+    "overrides",
+    "NoOverrides"
+);
 mk_path!(
     path_factory_instance_new,
     "dirk_framework",
@@ -111,12 +238,39 @@ mk_path!(
     "new"
 );
 mk_path!(path_component, "dirk_framework", "component", "Component");
+mk_path!(path_component_attribute, "dirk_framework", "component");
 mk_path!(
     path_static_component,
     "dirk_framework",
     "component",
     "StaticComponent"
 );
+mk_path!(path_introspect, "dirk_framework", "component", "Introspect");
+mk_path!(
+    path_introspect_fmt_bindings,
+    "dirk_framework",
+    "component",
+    "Introspect",
+    "fmt_bindings"
+);
+mk_path!(
+    path_binding_descriptor,
+    "dirk_framework",
+    "component",
+    "BindingDescriptor"
+);
+mk_path!(
+    path_binding_kind,
+    "dirk_framework",
+    "component",
+    "BindingKind"
+);
+mk_path!(
+    path_is_materialized,
+    "dirk_framework",
+    "component",
+    "is_materialized"
+);
 mk_path!(
     path_unset_builder,
     "dirk_framework",
@@ -147,8 +301,16 @@ mk_path!(
     "InputStatus"
 );
 
+mk_path!(path_vec_from, "std", "vec", "Vec", "from");
+mk_path!(path_some, "std", "option", "Option", "Some");
+mk_path!(path_ok, "std", "result", "Result", "Ok");
+mk_path!(path_none, "std", "option", "Option", "None");
+mk_path!(path_debug, "std", "fmt", "Debug");
+mk_path!(path_unimplemented, "std", "unimplemented");
+mk_path!(path_option_env, "std", "option_env");
 mk_path!(path_rc_new, "std", "rc", "Rc", "new");
 mk_path!(path_refcell_new, "std", "cell", "RefCell", "new");
+mk_path!(path_cell_new, "std", "cell", "Cell", "new");
 mk_path!(path_arc_new, "std", "sync", "Arc", "new");
 mk_path!(path_rwlock_new, "std", "sync", "RwLock", "new");
 mk_path!(
@@ -160,13 +322,91 @@ mk_path!(
     "new"
 );
 mk_path!(
-    path_scoped_instance_factory_new,
+    path_scoped_instance_factory_from_shared,
     "dirk_framework",
     "component",
     "instance_binds",
     "ScopedInstanceFactory",
+    "from_shared"
+);
+mk_path!(
+    path_shared_instance_factory_from_shared,
+    "dirk_framework",
+    "component",
+    "instance_binds",
+    "SharedInstanceFactory",
+    "from_shared"
+);
+mk_path!(
+    path_arc_instance_factory_from_shared,
+    "dirk_framework",
+    "component",
+    "instance_binds",
+    "ArcInstanceFactory",
+    "from_shared"
+);
+mk_path!(
+    path_env_factory_from_env,
+    "dirk_framework",
+    "component",
+    "env_binds",
+    "EnvFactory",
+    "from_env"
+);
+mk_path!(
+    path_config_factory_from_config,
+    "dirk_framework",
+    "component",
+    "config_binds",
+    "ConfigFactory",
+    "from_config"
+);
+mk_type!(
+    type_config,
+    "dirk_framework",
+    "component",
+    "config_binds",
+    "Config"
+);
+mk_path!(path_into, "std", "convert", "Into");
+mk_path!(path_into_into, "std", "convert", "Into", "into");
+mk_path!(
+    path_builder_check,
+    "dirk_framework",
+    "component",
+    "builder",
+    "check"
+);
+mk_path!(
+    path_builder_unwrap_built,
+    "dirk_framework",
+    "component",
+    "builder",
+    "unwrap_built"
+);
+mk_path!(
+    path_provider_instance_factory_new,
+    "dirk_framework",
+    "component",
+    "instance_binds",
+    "ProviderInstanceFactory",
     "new"
 );
+mk_path!(
+    path_into_provider,
+    "dirk_framework",
+    "component",
+    "instance_binds",
+    "IntoProvider"
+);
+mk_path!(
+    path_into_provider_into_provider,
+    "dirk_framework",
+    "component",
+    "instance_binds",
+    "IntoProvider",
+    "into_provider"
+);
 
 mk_path!(path_self, "Self");
 mk_path!(path_small_self, "self");
@@ -174,4 +414,8 @@ mk_path!(path_self_new, "Self", "new");
 mk_path!(path_self_new_instance, "Self", "new_instance");
 
 mk_path!(path_derive, "derive");
+mk_path!(path_do_not_recommend, "diagnostic", "do_not_recommend");
+mk_path!(path_on_unimplemented, "diagnostic", "on_unimplemented");
+mk_path!(path_sized, "std", "marker", "Sized");
 mk_path!(path_clone, "Clone");
+mk_path!(path_fn, "std", "ops", "Fn");
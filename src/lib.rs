@@ -12,6 +12,7 @@
 //!    - instance bindings, supplied by the user
 //!        - scoped instance, wrapped in `Rc<RefCell<...>>` (shared inside an individual component)
 //!        - cloned instance, not wrapped (cloned whenever it is required)
+//!    - members injection bindings, assigning fields of an existing instance (see [`#[inject_members]`](macro@inject_members))
 //!
 //! [`#[use_provides(...)]`](macro@use_provides) and [`#[use_component(...)]`](macro@use_component) may be used to import providers and components in other modules.
 //!
@@ -82,13 +83,26 @@
 //! let component = DirkGenericComponent::builder().answer(42).build();
 //! assert_eq!(component.answer(), 42);
 //!```
+//!
+//! ## Lints
+//! The macros warn about
+//! - `unused_binding`: a binding which is neither queried by a function of the component nor a dependency of another binding.
+//! - `unreceived_dependency`: a `_`-prefixed argument of a `#[*_provides]` function which is never used, i.e. a dependency which is passed, but never received.
+//!   Other unused arguments are already reported by the `unused_variables` lint of the compiler, unless the lint is denied.
+//!
+//! Setting the environment variable `DIRK_LINTS` to `allow` or `deny` silences these warnings or turns them into errors, respectively.
+//!
+//! Additionally, types provided via `#[*_provides]`, but not bound by any component can be reported by setting `DIRK_LINT_UNBOUND_PROVIDES` to `warn` or `deny`.
+//! As this relies on the `dead_code` lint of the compiler, it is only reported for crate-private types.
 
 #[macro_use(component, provides, use_provides)]
 #[allow(unused_imports)]
 extern crate dirk_macros;
 
 pub use dirk_macros::component;
+pub use dirk_macros::inject_members;
 pub use dirk_macros::provides;
+pub use dirk_macros::test;
 pub use dirk_macros::use_component;
 pub use dirk_macros::use_provides;
 
@@ -109,6 +123,26 @@ pub mod provides {
          * Returns the thing that is being provided
          */
         fn get(&self) -> T;
+
+        /**
+         * Whether the provided instance has already been created and handed out, only tracked by providers of scoped (instance) bindings
+         */
+        #[doc(hidden)]
+        fn is_materialized(&self) -> bool {
+            false
+        }
+    }
+
+    /**
+     * A trait used by the `#[inject_members]` macro
+     *
+     * The `#[inject_members]` macro generates types implementing this trait, by convention named `MembersInjector`
+     */
+    pub trait MembersInjector<T> {
+        /**
+         * Assigns all fields marked `#[inject]` of an already existing instance
+         */
+        fn inject_members(&self, target: &mut T);
     }
 
     /**
@@ -132,6 +166,104 @@ pub mod provides {
             &self.0
         }
     }
+
+    pub mod kind {
+        //! Marker types naming the kinds of bindings, used to check that a type is bound the way it is provided
+
+        #![allow(non_camel_case_types)]
+
+        /// Kind of types annotated with `#[provides(static_inject)]`
+        pub struct static_bind;
+
+        /// Kind of types annotated with `#[provides(scoped_inject)]`
+        pub struct scoped_bind;
+
+        /// Kind of types annotated with `#[provides(singleton_inject)]`
+        pub struct singleton_bind;
+
+        /**
+         * A trait used by the `#[provides]` macro
+         *
+         * The `#[provides]` macro implements this trait for the provided type, using the kind of binding it can be bound with as `Kind`
+         */
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` cannot be bound, as it is not provided",
+            label = "not annotated with `#[provides(...)]`",
+            note = "`static_bind`, `scoped_bind` and `singleton_bind` require a type annotated with `#[provides(static_inject)]`, `#[provides(scoped_inject)]` and `#[provides(singleton_inject)]`, respectively"
+        )]
+        pub trait BindableVia {
+            /// Kind of binding the type can be bound with
+            type Kind;
+        }
+
+        /**
+         * Used by the `#[component(...)]` macro to check that `T`, which is bindable via the kind `Self`, is bound via `K`
+         *
+         * The factory passed to [`bind`](MatchesKind::bind) is returned as is, unless the kinds differ.
+         * In that case, only the mismatch is reported instead of every use of the factory and of the types it provides as well.
+         */
+        #[diagnostic::on_unimplemented(
+            message = "`{T}` cannot be bound via `{K}`",
+            label = "provided as required by `{Self}`, use `{Self}` instead",
+            note = "`static_bind`, `scoped_bind` and `singleton_bind` require a type annotated with `#[provides(static_inject)]`, `#[provides(scoped_inject)]` and `#[provides(singleton_inject)]`, respectively"
+        )]
+        pub trait MatchesKind<T: ?Sized, K> {
+            /// `X`, if the kinds match
+            type Checked<X: ?Sized>: ?Sized;
+
+            #[allow(missing_docs)]
+            fn bind<F>(factory: F) -> Self::Checked<F>;
+        }
+
+        macro_rules! impl_matches_kind {
+            ($($kind:ident),*) => {
+                $(
+                    impl<T: ?Sized> MatchesKind<T, $kind> for $kind {
+                        type Checked<X: ?Sized> = X;
+
+                        fn bind<F>(factory: F) -> F {
+                            factory
+                        }
+                    }
+                )*
+            };
+        }
+
+        // Implemented per kind instead of for any `K`, such that a mismatch is not resolved to a blanket implementation
+        impl_matches_kind!(static_bind, scoped_bind, singleton_bind);
+    }
+
+    pub mod qualifier {
+        //! Types selecting one of several provider functions of a type, marked with `#[qualifier(...)]`
+
+        /// A character of a qualifier, which is named by the tuple of its characters, e.g., `(QualifierChar<'r'>, QualifierChar<'e'>, ...)` for `read`
+        pub struct QualifierChar<const C: char>;
+
+        /**
+         * A trait used by the `#[provides]` macro
+         *
+         * The `#[provides]` macro implements this trait for the type provided by a qualified function, using the qualifier as `Q` and a type generated per impl block as `Tag`.
+         * The `#[component(...)]` macro passes the qualifier of a binding as `Name` as well, such that an unknown qualifier is reported by name.
+         */
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` is not provided with qualifier `{Name}`",
+            label = "no provider function of `{Self}` is marked with `#[qualifier({Name})]`"
+        )]
+        pub trait Qualified<Q, Name, Tag> {
+            /// Factory generated for the qualified function
+            type Factory;
+        }
+
+        /**
+         * A trait used by the `#[provides]` macro
+         *
+         * Creates the factory of a qualified function from its dependencies, such that it may be created via [`Qualified::Factory`]
+         */
+        pub trait FromDependencies<D> {
+            #[allow(missing_docs)]
+            fn from_dependencies(dependencies: D) -> Self;
+        }
+    }
 }
 
 pub mod component {
@@ -166,6 +298,90 @@ pub mod component {
         }
     }
 
+    /**
+     * The kind of a binding declared in a `#[component(...)]` macro
+     */
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum BindingKind {
+        /// `static_bind(...)`
+        Static,
+        /// `scoped_bind(...)`
+        Scoped,
+        /// `singleton_bind(...)`
+        Singleton,
+        /// `cloned_instance_bind(...)`
+        ClonedInstance,
+        /// `scoped_instance_bind(...)`
+        ScopedInstance,
+        /// `shared_instance_bind(...)`
+        SharedInstance,
+        /// `arc_instance_bind(...)`
+        ArcInstance,
+        /// `provider_instance_bind(...)`
+        ProviderInstance,
+        /// `env_bind(...)`
+        Env,
+        /// `config_bind(...)`, requires the `serde` feature
+        Config,
+        /// `members_bind(...)`
+        Members,
+    }
+
+    /**
+     * Describes a binding of a component at runtime
+     */
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct BindingDescriptor {
+        /// The name of the binding
+        pub name: &'static str,
+        /// The kind of the binding
+        pub kind: BindingKind,
+        /// The type of the binding, as specified in the `#[component(...)]` macro
+        pub type_name: &'static str,
+        /// The names of the bindings this binding depends on
+        pub dependencies: &'static [&'static str],
+        /// For scoped (instance) bindings, whether the shared instance has already been created and handed out by the component (i.e., queried or injected), `None` for all other kinds
+        pub materialized: Option<bool>,
+    }
+
+    /**
+     * Allows to enumerate the bindings of a component at runtime
+     *
+     * **Do not implement this trait yourself! Use the [`#[component(...)]`](macro@component) macro to generate a type implementing this trait.**
+     */
+    pub trait Introspect {
+        /**
+         * Returns the name of the generated type implementing the component
+         */
+        fn component_name(&self) -> &'static str;
+
+        /**
+         * Returns descriptors of all bindings, in the order in which they are instantiated
+         */
+        fn bindings(&self) -> Vec<BindingDescriptor>;
+
+        /**
+         * Formats all bindings, used by the generated `Debug` implementation
+         */
+        fn fmt_bindings(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let mut debug_struct = f.debug_struct(self.component_name());
+            for binding in self.bindings() {
+                debug_struct.field(binding.name, &binding);
+            }
+            debug_struct.finish()
+        }
+    }
+
+    /**
+     * Used by the generated implementation of [`Introspect`] to determine whether a scoped instance has already been handed out by a component
+     */
+    #[doc(hidden)]
+    pub fn is_materialized<T: ?Sized>(
+        provider: &dyn crate::provides::Provider<std::rc::Rc<std::cell::RefCell<T>>>,
+    ) -> bool {
+        provider.is_materialized()
+    }
+
     pub mod builder {
         //! Contains data types used in the type-safe builder pattern created by the `#[component(...)]` macro
 
@@ -187,8 +403,130 @@ pub mod component {
              */
             #[must_use = "Instances created via dependency injection are supposed to be used somewhere"]
             fn build(self) -> T;
+
+            /**
+             * Build an instance of a component, reporting all environment variables of `env_bind(...)`s and configuration sections of `config_bind(...)`s that are missing or malformed
+             *
+             * [`Builder::build`] panics in these cases instead
+             */
+            fn try_build(self) -> Result<T, BuildError>
+            where
+                Self: Sized,
+            {
+                Ok(self.build())
+            }
         }
 
+        /**
+         * Returned by [`Builder::try_build`] if any environment variable of an `env_bind(...)` or configuration section of a `config_bind(...)` is missing or malformed
+         */
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct BuildError {
+            pub(crate) errors: Vec<BindingError>,
+        }
+
+        impl BuildError {
+            /**
+             * Returns all errors, in the order in which the bindings are instantiated
+             */
+            pub fn errors(&self) -> &[BindingError] {
+                &self.errors
+            }
+        }
+
+        impl<E: Into<BindingError>> From<E> for BuildError {
+            fn from(value: E) -> Self {
+                Self {
+                    errors: vec![value.into()],
+                }
+            }
+        }
+
+        /**
+         * Describes why a single binding could not be instantiated by [`Builder::try_build`]
+         */
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum BindingError {
+            /// An `env_bind(...)` failed
+            Env(super::env_binds::EnvError),
+            /// A `config_bind(...)` failed
+            #[cfg(feature = "serde")]
+            Config(super::config_binds::ConfigError),
+        }
+
+        impl From<super::env_binds::EnvError> for BindingError {
+            fn from(value: super::env_binds::EnvError) -> Self {
+                Self::Env(value)
+            }
+        }
+
+        impl From<&super::env_binds::EnvError> for BindingError {
+            fn from(value: &super::env_binds::EnvError) -> Self {
+                Self::Env(value.clone())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl From<super::config_binds::ConfigError> for BindingError {
+            fn from(value: super::config_binds::ConfigError) -> Self {
+                Self::Config(value)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl From<&super::config_binds::ConfigError> for BindingError {
+            fn from(value: &super::config_binds::ConfigError) -> Self {
+                Self::Config(value.clone())
+            }
+        }
+
+        impl std::fmt::Display for BindingError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    Self::Env(error) => write!(f, "{error}"),
+                    #[cfg(feature = "serde")]
+                    Self::Config(error) => write!(f, "{error}"),
+                }
+            }
+        }
+
+        impl std::error::Error for BindingError {}
+
+        /**
+         * Used by generated builders to report all errors at once
+         */
+        #[doc(hidden)]
+        pub fn check<const N: usize>(errors: [Option<BindingError>; N]) -> Result<(), BuildError> {
+            let errors: Vec<_> = errors.into_iter().flatten().collect();
+            match errors.as_slice() {
+                [] => Ok(()),
+                _ => Err(BuildError { errors }),
+            }
+        }
+
+        /**
+         * Used by generated builders to implement [`Builder::build`] via [`Builder::try_build`]
+         */
+        #[doc(hidden)]
+        pub fn unwrap_built<T>(result: Result<T, BuildError>) -> T {
+            match result {
+                Ok(built) => built,
+                Err(e) => panic!("{e}"),
+            }
+        }
+
+        impl std::fmt::Display for BuildError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "Failed to build component")?;
+                for error in &self.errors {
+                    write!(f, "\n - {error}")?;
+                }
+                Ok(())
+            }
+        }
+
+        impl std::error::Error for BuildError {}
+
         /**
          * Used in a type-safe builder pattern to indicate that some parameter has not yet been set
          */
@@ -239,23 +577,604 @@ pub mod component {
          * A type used by `scoped_instance_binds(...)`
          * Wraps whatever is being provided in a `Rc<RefCell<...>>`
          */
-        pub struct ScopedInstanceFactory<T> {
+        pub struct ScopedInstanceFactory<T: ?Sized> {
             inner: std::rc::Rc<std::cell::RefCell<T>>,
+            handed_out: std::cell::Cell<bool>,
         }
 
         impl<T> ScopedInstanceFactory<T> {
             #[allow(missing_docs)]
             pub fn new(inner: T) -> Self {
+                Self::from_shared(std::rc::Rc::new(std::cell::RefCell::new(inner)))
+            }
+        }
+
+        impl<T: ?Sized> ScopedInstanceFactory<T> {
+            /**
+             * Shares an already existing instance, such that the component and the caller observe the same instance
+             */
+            pub fn from_shared(inner: std::rc::Rc<std::cell::RefCell<T>>) -> Self {
                 Self {
-                    inner: std::rc::Rc::new(std::cell::RefCell::new(inner)),
+                    inner,
+                    handed_out: std::cell::Cell::new(false),
                 }
             }
         }
 
-        impl<T> Provider<std::rc::Rc<std::cell::RefCell<T>>> for ScopedInstanceFactory<T> {
+        impl<T: ?Sized> Provider<std::rc::Rc<std::cell::RefCell<T>>> for ScopedInstanceFactory<T> {
             fn get(&self) -> std::rc::Rc<std::cell::RefCell<T>> {
+                self.handed_out.set(true);
+                self.inner.clone()
+            }
+
+            fn is_materialized(&self) -> bool {
+                self.handed_out.get()
+            }
+        }
+
+        /**
+         * A type used by `shared_instance_bind(...)`
+         * Wraps whatever is being provided in a `Rc<...>`
+         */
+        pub struct SharedInstanceFactory<T: ?Sized> {
+            inner: std::rc::Rc<T>,
+        }
+
+        impl<T> SharedInstanceFactory<T> {
+            #[allow(missing_docs)]
+            pub fn new(inner: T) -> Self {
+                Self {
+                    inner: std::rc::Rc::new(inner),
+                }
+            }
+        }
+
+        impl<T: ?Sized> SharedInstanceFactory<T> {
+            /**
+             * Shares an already existing instance, e.g., a trait object
+             */
+            pub fn from_shared(inner: std::rc::Rc<T>) -> Self {
+                Self { inner }
+            }
+        }
+
+        impl<T: ?Sized> Provider<std::rc::Rc<T>> for SharedInstanceFactory<T> {
+            fn get(&self) -> std::rc::Rc<T> {
+                self.inner.clone()
+            }
+        }
+
+        /**
+         * A type used by `arc_instance_bind(...)`
+         * Wraps whatever is being provided in an `Arc<...>`
+         */
+        pub struct ArcInstanceFactory<T: ?Sized> {
+            inner: std::sync::Arc<T>,
+        }
+
+        impl<T> ArcInstanceFactory<T> {
+            #[allow(missing_docs)]
+            pub fn new(inner: T) -> Self {
+                Self {
+                    inner: std::sync::Arc::new(inner),
+                }
+            }
+        }
+
+        impl<T: ?Sized> ArcInstanceFactory<T> {
+            /**
+             * Shares an already existing instance, e.g., a trait object
+             */
+            pub fn from_shared(inner: std::sync::Arc<T>) -> Self {
+                Self { inner }
+            }
+        }
+
+        impl<T: ?Sized> Provider<std::sync::Arc<T>> for ArcInstanceFactory<T> {
+            fn get(&self) -> std::sync::Arc<T> {
+                self.inner.clone()
+            }
+        }
+
+        /**
+         * A type used by `provider_instance_bind(...)`
+         *
+         * Delegates to a provider supplied by the user, every time it is queried
+         */
+        pub struct ProviderInstanceFactory<T> {
+            inner: std::rc::Rc<dyn Provider<T>>,
+        }
+
+        impl<T> ProviderInstanceFactory<T> {
+            #[allow(missing_docs)]
+            pub fn new(inner: std::rc::Rc<dyn Provider<T>>) -> Self {
+                Self { inner }
+            }
+        }
+
+        impl<T> Provider<T> for ProviderInstanceFactory<T> {
+            fn get(&self) -> T {
+                self.inner.get()
+            }
+        }
+
+        /**
+         * Conversion into a provider, accepted by the builder methods of `provider_instance_bind(...)`s
+         *
+         * Implemented for every [`Provider<T>`] and every closure `Fn() -> T`, the type parameter `M` only serves to distinguish both implementations
+         */
+        pub trait IntoProvider<T, M> {
+            /**
+             * Turns `self` into a shared provider
+             */
+            fn into_provider(self) -> std::rc::Rc<dyn Provider<T>>;
+        }
+
+        /**
+         * Marks the implementation of [`IntoProvider`] for types implementing [`Provider`]
+         */
+        pub struct FromProvider;
+
+        /**
+         * Marks the implementation of [`IntoProvider`] for closures
+         */
+        pub struct FromFn;
+
+        impl<T, P: Provider<T> + 'static> IntoProvider<T, FromProvider> for P {
+            fn into_provider(self) -> std::rc::Rc<dyn Provider<T>> {
+                std::rc::Rc::new(self)
+            }
+        }
+
+        impl<T, F: Fn() -> T + 'static> IntoProvider<T, FromFn> for F {
+            fn into_provider(self) -> std::rc::Rc<dyn Provider<T>> {
+                std::rc::Rc::new(FnProvider(self))
+            }
+        }
+
+        struct FnProvider<F>(F);
+
+        impl<T, F: Fn() -> T> Provider<T> for FnProvider<F> {
+            fn get(&self) -> T {
+                (self.0)()
+            }
+        }
+    }
+
+    pub mod env_binds {
+        //! Contains data types used by `env_bind(...)` bindings that may be used in a `#[component(...)]` macro
+
+        use std::{fmt::Display, str::FromStr};
+
+        use crate::provides::Provider;
+
+        /**
+         * Describes why the environment variable of an `env_bind(...)` could not be read
+         */
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum EnvError {
+            /// The variable is not set and no default has been specified
+            Missing {
+                /// Name of the variable
+                variable: &'static str,
+            },
+            /// The value of the variable could not be parsed
+            Malformed {
+                /// Name of the variable
+                variable: &'static str,
+                /// Value of the variable, lossily converted if not valid unicode
+                value: String,
+                /// Error reported by [`FromStr`], or that the value is not valid unicode
+                reason: String,
+            },
+        }
+
+        impl Display for EnvError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    Self::Missing { variable } => {
+                        write!(f, "environment variable `{variable}` is not set")
+                    }
+                    Self::Malformed {
+                        variable,
+                        value,
+                        reason,
+                    } => write!(
+                        f,
+                        "environment variable `{variable}` has malformed value `{value}`: {reason}"
+                    ),
+                }
+            }
+        }
+
+        impl std::error::Error for EnvError {}
+
+        /**
+         * A type used by `env_bind(...)`
+         *
+         * Holds the parsed value of an environment variable, which is cloned every time it is queried
+         */
+        pub struct EnvFactory<T: Clone> {
+            inner: T,
+        }
+
+        impl<T: Clone + FromStr> EnvFactory<T>
+        where
+            T::Err: Display,
+        {
+            /**
+             * Parses the environment variable `variable`, falling back to `default` if it is not set
+             */
+            pub fn from_env(variable: &'static str, default: Option<T>) -> Result<Self, EnvError> {
+                let value = match std::env::var_os(variable) {
+                    Some(value) => value,
+                    None => {
+                        return default
+                            .map(|inner| Self { inner })
+                            .ok_or(EnvError::Missing { variable })
+                    }
+                };
+
+                let value = value.into_string().map_err(|value| EnvError::Malformed {
+                    variable,
+                    value: value.to_string_lossy().into_owned(),
+                    reason: "not valid unicode".to_string(),
+                })?;
+                value
+                    .parse()
+                    .map(|inner| Self { inner })
+                    .map_err(|e: T::Err| EnvError::Malformed {
+                        variable,
+                        value,
+                        reason: e.to_string(),
+                    })
+            }
+        }
+
+        impl<T: Clone> Provider<T> for EnvFactory<T> {
+            fn get(&self) -> T {
                 self.inner.clone()
             }
         }
     }
+
+    #[cfg(feature = "serde")]
+    pub mod config_binds {
+        //! Contains data types used by `config_bind(...)` bindings that may be used in a `#[component(...)]` macro, requires the `serde` feature
+
+        use std::{
+            fmt::Display,
+            path::{Path, PathBuf},
+        };
+
+        use serde::de::DeserializeOwned;
+
+        use crate::provides::Provider;
+
+        /**
+         * A configuration passed to the `config(...)` method of a builder, from which `config_bind(...)`s deserialize their sections
+         *
+         * May be created from a [`serde_json::Value`], a [`toml::Value`] or a path to a `.json` or `.toml` file.
+         * Errors while reading a file are reported by every `config_bind(...)` when the component is built.
+         */
+        #[derive(Debug, Clone)]
+        pub struct Config {
+            value: Result<serde_json::Value, String>,
+        }
+
+        impl Config {
+            /**
+             * Reads a configuration from a file, whose format is determined by its extension (`.json` or `.toml`)
+             */
+            pub fn from_file(path: impl AsRef<Path>) -> Self {
+                let path = path.as_ref();
+                let value = std::fs::read_to_string(path)
+                    .map_err(|e| format!("failed to read `{}`: {e}", path.display()))
+                    .and_then(|content| {
+                        match path.extension().and_then(|extension| extension.to_str()) {
+                            Some("json") => {
+                                serde_json::from_str(&content).map_err(|e| e.to_string())
+                            }
+                            Some("toml") => toml::from_str(&content).map_err(|e| e.to_string()),
+                            _ => Err("expected a `.json` or `.toml` file".to_string()),
+                        }
+                        .map_err(|e| format!("failed to parse `{}`: {e}", path.display()))
+                    });
+                Self { value }
+            }
+
+            /**
+             * Looks up a section, where `section` is a sequence of keys separated by `.` and the empty string denotes the whole configuration
+             */
+            fn section(&self, section: &str) -> Result<Option<&serde_json::Value>, &str> {
+                let value = self.value.as_ref().map_err(String::as_str)?;
+                if section.is_empty() {
+                    return Ok(Some(value));
+                }
+                Ok(section
+                    .split('.')
+                    .try_fold(value, |value, key| value.get(key)))
+            }
+        }
+
+        impl From<serde_json::Value> for Config {
+            fn from(value: serde_json::Value) -> Self {
+                Self { value: Ok(value) }
+            }
+        }
+
+        impl From<toml::Value> for Config {
+            fn from(value: toml::Value) -> Self {
+                Self {
+                    value: serde_json::to_value(value).map_err(|e| e.to_string()),
+                }
+            }
+        }
+
+        impl From<&Path> for Config {
+            fn from(value: &Path) -> Self {
+                Self::from_file(value)
+            }
+        }
+
+        impl From<PathBuf> for Config {
+            fn from(value: PathBuf) -> Self {
+                Self::from_file(value)
+            }
+        }
+
+        /**
+         * Describes why the configuration section of a `config_bind(...)` could not be deserialized
+         */
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum ConfigError {
+            /// No configuration has been supplied or it could not be read
+            Unavailable {
+                /// Name of the binding
+                binding: &'static str,
+                /// Section of the binding, empty for the whole configuration
+                section: &'static str,
+                /// Why the configuration is not available
+                reason: String,
+            },
+            /// The section is not contained in the configuration
+            Missing {
+                /// Name of the binding
+                binding: &'static str,
+                /// Section of the binding
+                section: &'static str,
+            },
+            /// The section could not be deserialized
+            Malformed {
+                /// Name of the binding
+                binding: &'static str,
+                /// Section of the binding, empty for the whole configuration
+                section: &'static str,
+                /// Error reported by serde
+                reason: String,
+            },
+        }
+
+        /// Names the binding and its section, the latter being omitted for the whole configuration
+        fn describe(binding: &str, section: &str) -> String {
+            if section.is_empty() {
+                format!("binding `{binding}`")
+            } else {
+                format!("binding `{binding}` (section `{section}`)")
+            }
+        }
+
+        impl Display for ConfigError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    Self::Unavailable {
+                        binding,
+                        section,
+                        reason,
+                    } => write!(
+                        f,
+                        "configuration for {} is not available: {reason}",
+                        describe(binding, section)
+                    ),
+                    Self::Missing { binding, section } => write!(
+                        f,
+                        "configuration for {} is missing",
+                        describe(binding, section)
+                    ),
+                    Self::Malformed {
+                        binding,
+                        section,
+                        reason,
+                    } => write!(
+                        f,
+                        "configuration for {} is malformed: {reason}",
+                        describe(binding, section)
+                    ),
+                }
+            }
+        }
+
+        impl std::error::Error for ConfigError {}
+
+        /**
+         * A type used by `config_bind(...)`
+         *
+         * Holds the deserialized section of a configuration, which is cloned every time it is queried
+         */
+        pub struct ConfigFactory<T: Clone> {
+            inner: T,
+        }
+
+        impl<T: Clone + DeserializeOwned> ConfigFactory<T> {
+            /**
+             * Deserializes `section` of `config` for the binding `binding`
+             */
+            pub fn from_config(
+                config: &Config,
+                binding: &'static str,
+                section: &'static str,
+            ) -> Result<Self, ConfigError> {
+                let value = config
+                    .section(section)
+                    .map_err(|reason| ConfigError::Unavailable {
+                        binding,
+                        section,
+                        reason: reason.to_string(),
+                    })?
+                    .ok_or(ConfigError::Missing { binding, section })?;
+
+                T::deserialize(value)
+                    .map(|inner| Self { inner })
+                    .map_err(|e| ConfigError::Malformed {
+                        binding,
+                        section,
+                        reason: e.to_string(),
+                    })
+            }
+        }
+
+        impl<T: Clone> Provider<T> for ConfigFactory<T> {
+            fn get(&self) -> T {
+                self.inner.clone()
+            }
+        }
+    }
+
+    pub mod overrides {
+        //! Contains data types used by the `override_...(...)` methods of builders created by the `#[component(...)]` macro
+
+        use std::{
+            any::Any,
+            cell::RefCell,
+            collections::HashMap,
+            rc::Rc,
+            sync::{Arc, RwLock},
+        };
+
+        use super::instance_binds::ScopedInstanceFactory;
+        use crate::provides::Provider;
+
+        /**
+         * Providers replacing automatic bindings of a component, collected by its builder
+         */
+        #[derive(Default)]
+        pub struct Overrides {
+            providers: HashMap<&'static str, Box<dyn Any>>,
+        }
+
+        impl Overrides {
+            /**
+             * Replaces the `static_bind(...)` called `name`, calling `factory` every time an instance is queried or injected
+             */
+            pub fn override_static<T: 'static>(
+                &mut self,
+                name: &'static str,
+                factory: impl Fn() -> T + 'static,
+            ) {
+                self.insert::<T>(name, Rc::new(StaticOverride(factory)));
+            }
+
+            /**
+             * Replaces the `scoped_bind(...)` called `name` by `instance`, wrapped in a `Rc<RefCell<...>>`
+             */
+            pub fn override_scoped<T: 'static>(&mut self, name: &'static str, instance: T) {
+                self.insert::<Rc<RefCell<T>>>(name, Rc::new(ScopedInstanceFactory::new(instance)));
+            }
+
+            /**
+             * Replaces the `singleton_bind(...)` called `name` by `instance`, wrapped in a `Arc<RwLock<...>>`
+             */
+            pub fn override_singleton<T: 'static>(&mut self, name: &'static str, instance: T) {
+                self.insert::<Arc<RwLock<T>>>(
+                    name,
+                    Rc::new(SingletonOverride(Arc::new(RwLock::new(instance)))),
+                );
+            }
+
+            /**
+             * Returns the provider replacing the binding called `name`, calling `create` only if there is none
+             */
+            pub fn take_or<P>(
+                &mut self,
+                name: &'static str,
+                create: impl FnOnce() -> P,
+            ) -> Overridable<P> {
+                match self.providers.remove(name) {
+                    Some(provider) => Overridable(Source::Overridden(name, provider)),
+                    None => Overridable(Source::Factory(create())),
+                }
+            }
+
+            fn insert<T: 'static>(&mut self, name: &'static str, provider: Rc<dyn Provider<T>>) {
+                self.providers.insert(name, Box::new(provider));
+            }
+        }
+
+        /**
+         * Stands in for [`Overrides`] outside of tests, such that components neither store nor wrap any replacing providers
+         */
+        #[derive(Default)]
+        pub struct NoOverrides;
+
+        impl NoOverrides {
+            /**
+             * Returns the provider created by `create`, as there is nothing to replace it by
+             */
+            #[inline]
+            pub fn take_or<P>(&mut self, _name: &'static str, create: impl FnOnce() -> P) -> P {
+                create()
+            }
+        }
+
+        /**
+         * A provider of an automatic binding, which may have been replaced via an `override_...(...)` method of the builder
+         *
+         * The `override_...(...)` methods are typed by the binding they replace, such that the replacing provider always matches the factory.
+         */
+        pub struct Overridable<P>(Source<P>);
+
+        enum Source<P> {
+            Factory(P),
+            Overridden(&'static str, Box<dyn Any>),
+        }
+
+        impl<T: 'static, P: Provider<T>> Provider<T> for Overridable<P> {
+            fn get(&self) -> T {
+                match &self.0 {
+                    Source::Factory(factory) => factory.get(),
+                    Source::Overridden(name, provider) => provider
+                        .downcast_ref::<Rc<dyn Provider<T>>>()
+                        .unwrap_or_else(|| {
+                            unreachable!("`{name}` is overridden by an instance of another type")
+                        })
+                        .get(),
+                }
+            }
+
+            fn is_materialized(&self) -> bool {
+                match &self.0 {
+                    Source::Factory(factory) => factory.is_materialized(),
+                    Source::Overridden(_name, provider) => provider
+                        .downcast_ref::<Rc<dyn Provider<T>>>()
+                        .is_some_and(|provider| provider.is_materialized()),
+                }
+            }
+        }
+
+        struct StaticOverride<F>(F);
+
+        impl<T, F: Fn() -> T> Provider<T> for StaticOverride<F> {
+            fn get(&self) -> T {
+                (self.0)()
+            }
+        }
+
+        struct SingletonOverride<T>(Arc<RwLock<T>>);
+
+        impl<T> Provider<Arc<RwLock<T>>> for SingletonOverride<T> {
+            fn get(&self) -> Arc<RwLock<T>> {
+                self.0.clone()
+            }
+        }
+    }
 }
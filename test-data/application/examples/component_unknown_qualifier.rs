@@ -0,0 +1,27 @@
+//! An example involving a connection pool, bound with a qualifier it is not provided with
+
+use dirk_framework::{component, component::StaticComponent, provides};
+
+fn main() {
+    let application = DirkApplication::create();
+    println!("{}", application.read_pool().url);
+}
+
+#[component(read_pool: static_bind(Pool, qualifier = reed))]
+trait Application {
+    fn read_pool(&self) -> Pool;
+}
+
+struct Pool {
+    url: &'static str,
+}
+
+#[provides]
+impl Pool {
+    #[qualifier(read)]
+    fn replica() -> Self {
+        Self {
+            url: "postgres://replica",
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! An example involving two connection pools, given the same qualifier by mistake
+
+use dirk_framework::{component, component::StaticComponent, provides};
+
+fn main() {
+    let application = DirkApplication::create();
+    println!("{}", application.read_pool().url);
+}
+
+#[component(read_pool: static_bind(Pool, qualifier = read))]
+trait Application {
+    fn read_pool(&self) -> Pool;
+}
+
+struct Pool {
+    url: &'static str,
+}
+
+#[provides]
+impl Pool {
+    #[qualifier(read)]
+    fn replica() -> Self {
+        Self {
+            url: "postgres://replica",
+        }
+    }
+
+    #[qualifier(read)]
+    fn primary() -> Self {
+        Self {
+            url: "postgres://primary",
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! An example involving two differently configured connection pools of the same type
+
+use std::sync::{Arc, RwLock};
+
+use dirk_framework::{component, component::StaticComponent, provides, use_provides};
+
+#[use_provides]
+use pools::Pool;
+
+fn main() {
+    let application = DirkApplication::create();
+
+    let repository = application.repository();
+    repository.save("alice");
+    repository.load();
+    repository.load();
+
+    println!("connection: {}", application.connection().url);
+    println!("test connection: {}", application.test_connection().url);
+    for pool in [repository.read_pool, repository.write_pool] {
+        let pool = pool.read().unwrap();
+        println!("{}: {} ({} queries)", pool.name, pool.url, pool.queries);
+    }
+}
+
+#[component(
+    read_pool: singleton_bind(Pool, qualifier = read),
+    write_pool: singleton_bind(Pool, qualifier = write),
+    connection: static_bind(Connection),
+    test_connection: static_bind(Connection, qualifier = test),
+    repository: static_bind(Repository) [read_pool, write_pool]
+)]
+trait Application {
+    fn connection(&self) -> Connection;
+    fn test_connection(&self) -> Connection;
+    fn repository(&self) -> Repository;
+}
+
+mod pools {
+    use dirk_framework::provides;
+
+    pub(crate) struct Pool {
+        pub(crate) name: &'static str,
+        pub(crate) url: &'static str,
+        pub(crate) queries: usize,
+    }
+
+    // imported via `#[use_provides]`, which keeps the qualifiers selectable
+    #[provides(singleton_inject)]
+    impl Pool {
+        #[qualifier(read)]
+        fn replica() -> Self {
+            Self {
+                name: "read",
+                url: "postgres://replica",
+                queries: 0,
+            }
+        }
+
+        #[qualifier(write)]
+        fn primary() -> Self {
+            Self {
+                name: "write",
+                url: "postgres://primary",
+                queries: 0,
+            }
+        }
+    }
+}
+
+struct Connection {
+    url: &'static str,
+}
+
+// an unqualified function may be provided next to qualified ones
+#[provides]
+impl Connection {
+    fn new() -> Self {
+        Self {
+            url: "postgres://localhost",
+        }
+    }
+
+    #[qualifier(test)]
+    fn in_memory() -> Self {
+        Self { url: "sqlite::memory:" }
+    }
+}
+
+struct Repository {
+    read_pool: Arc<RwLock<Pool>>,
+    write_pool: Arc<RwLock<Pool>>,
+}
+
+#[provides]
+impl Repository {
+    fn new(read_pool: Arc<RwLock<Pool>>, write_pool: Arc<RwLock<Pool>>) -> Self {
+        Self {
+            read_pool,
+            write_pool,
+        }
+    }
+}
+
+impl Repository {
+    fn save(&self, user: &str) {
+        println!("saving {user}");
+        self.write_pool.write().unwrap().queries += 1;
+    }
+
+    fn load(&self) {
+        self.read_pool.write().unwrap().queries += 1;
+    }
+}
@@ -0,0 +1,73 @@
+//! An example involving a car composed of an engine - choosing the names of generated types
+
+use dirk_framework::{
+    component::{builder::Builder, Component},
+    provides::Provider,
+    use_component, use_provides,
+};
+
+#[use_provides(factory = EngineFactory)]
+use engine::Engine;
+
+mod engine {
+    use dirk_framework::provides;
+
+    pub(crate) struct Engine {
+        power: usize,
+    }
+
+    #[provides(scoped_inject, factory = EngineFactory)]
+    impl Engine {
+        fn new() -> Self {
+            Self { power: 200 }
+        }
+    }
+
+    impl Engine {
+        pub(crate) fn power(&self) -> usize {
+            self.power
+        }
+    }
+}
+
+mod car {
+    use std::{cell::RefCell, rc::Rc};
+
+    use dirk_framework::{component, use_provides};
+
+    #[use_provides]
+    use crate::engine::Engine;
+
+    #[component(
+        name = CarContainer,
+        builder = CarContainerBuilder,
+        engine: scoped_bind(Engine)
+    )]
+    pub(crate) trait Car {
+        fn engine(&self) -> Rc<RefCell<Engine>>;
+    }
+}
+
+#[use_component(name = CarContainer, builder = CarContainerBuilder)]
+use car::Car;
+
+fn main() {
+    let builder: CarContainerBuilder = CarContainer::builder();
+    let car = builder.build();
+    println!("car: {} hp", car.engine().borrow().power());
+
+    let engine: std::rc::Rc<std::cell::RefCell<Engine>> = EngineFactory::create().get();
+    println!("spare engine: {} hp", engine.borrow().power());
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::{Car, CarContainer, Engine};
+
+    #[dirk_framework::test(component = Car, name = CarContainer)]
+    fn engine_has_power(engine: Rc<RefCell<Engine>>) {
+        assert_eq!(engine.borrow().power(), 200);
+    }
+}
@@ -0,0 +1,88 @@
+//! An example involving a car and a boat, both composed of an engine named `Engine`
+
+use dirk_framework::{component, component::StaticComponent, provides};
+
+fn main() {
+    let vehicles = DirkVehicles::create();
+
+    println!("car: {} hp", vehicles.car_engine().borrow().power());
+    println!("boat: {} hp", vehicles.boat_engine().power());
+    println!("total: {} hp", vehicles.fleet().total_power());
+}
+
+#[component(
+    car_engine: scoped_bind(car::Engine),
+    boat_engine: static_bind(crate::boat::Engine),
+    fleet: static_bind(Fleet) [car_engine, boat_engine]
+)]
+trait Vehicles {
+    fn car_engine(&self) -> std::rc::Rc<std::cell::RefCell<car::Engine>>;
+    fn boat_engine(&self) -> boat::Engine;
+    fn fleet(&self) -> Fleet;
+}
+
+struct Fleet {
+    car_engine: std::rc::Rc<std::cell::RefCell<car::Engine>>,
+    boat_engine: boat::Engine,
+}
+
+#[provides]
+impl Fleet {
+    fn new(
+        car_engine: std::rc::Rc<std::cell::RefCell<car::Engine>>,
+        boat_engine: boat::Engine,
+    ) -> Self {
+        Self {
+            car_engine,
+            boat_engine,
+        }
+    }
+}
+
+impl Fleet {
+    fn total_power(&self) -> usize {
+        self.car_engine.borrow().power() + self.boat_engine.power()
+    }
+}
+
+mod car {
+    use dirk_framework::provides;
+
+    pub(crate) struct Engine {
+        power: usize,
+    }
+
+    #[provides(scoped_inject)]
+    impl Engine {
+        fn new() -> Self {
+            Self { power: 200 }
+        }
+    }
+
+    impl Engine {
+        pub(crate) fn power(&self) -> usize {
+            self.power
+        }
+    }
+}
+
+mod boat {
+    use dirk_framework::provides;
+
+    pub(crate) struct Engine {
+        power: usize,
+    }
+
+    #[provides]
+    impl Engine {
+        fn new() -> Self {
+            Self { power: 150 }
+        }
+    }
+
+    impl Engine {
+        pub(crate) fn power(&self) -> usize {
+            self.power
+        }
+    }
+}
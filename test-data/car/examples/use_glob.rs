@@ -0,0 +1,76 @@
+//! An example involving a car composed of parts - importing all components and provided types of a module
+
+use dirk_framework::{component::StaticComponent, use_component};
+
+mod parts {
+    use dirk_framework::provides;
+
+    pub(crate) struct Engine {
+        power: usize,
+    }
+
+    #[provides(scoped_inject)]
+    impl Engine {
+        fn new() -> Self {
+            Self { power: 200 }
+        }
+    }
+
+    impl Engine {
+        pub(crate) fn power(&self) -> usize {
+            self.power
+        }
+    }
+
+    pub(crate) struct Wheel {
+        size: usize,
+    }
+
+    #[provides]
+    impl Wheel {
+        fn new() -> Self {
+            Self { size: 17 }
+        }
+    }
+
+    impl Wheel {
+        pub(crate) fn size(&self) -> usize {
+            self.size
+        }
+    }
+}
+
+mod vehicles {
+    use std::{cell::RefCell, rc::Rc};
+
+    use dirk_framework::{component, use_provides};
+
+    #[use_provides(scoped_inject)]
+    use crate::parts::*;
+
+    #[component(engine: scoped_bind(Engine))]
+    pub(crate) trait Car {
+        fn engine(&self) -> Rc<RefCell<Engine>>;
+    }
+
+    #[component(engine: scoped_bind(Engine), wheel: static_bind(Wheel))]
+    pub(crate) trait Truck {
+        fn engine(&self) -> Rc<RefCell<Engine>>;
+        fn wheel(&self) -> Wheel;
+    }
+}
+
+#[use_component]
+use vehicles::*;
+
+fn main() {
+    let car = DirkCar::create();
+    println!("car: {} hp", car.engine().borrow().power());
+
+    let truck = DirkTruck::create();
+    println!(
+        "truck: {} hp, {} inch wheels",
+        truck.engine().borrow().power(),
+        truck.wheel().size()
+    );
+}
@@ -0,0 +1,32 @@
+//! An example involving a coffee machine - trait objects cannot be cloned instances
+
+use std::rc::Rc;
+
+use dirk_framework::{
+    component,
+    component::{builder::Builder, Component},
+};
+
+fn main() {
+    let coffee_shop = DirkCoffeeShop::builder().grinder(BurrGrinder).build();
+    coffee_shop.grinder().grind();
+}
+
+#[component(grinder: cloned_instance_bind(dyn Grinder))]
+trait CoffeeShop {
+    fn grinder(&self) -> Rc<dyn Grinder>;
+}
+
+//######################################################################################################################
+
+trait Grinder {
+    fn grind(&self);
+}
+
+struct BurrGrinder;
+
+impl Grinder for BurrGrinder {
+    fn grind(&self) {
+        println!("* * * grinding * * *");
+    }
+}
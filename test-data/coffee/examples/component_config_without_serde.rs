@@ -0,0 +1,23 @@
+//! An example involving a coffee machine - configuration bindings require the `serde` feature
+
+use dirk_framework::{
+    component,
+    component::{builder::Builder, Component},
+};
+
+fn main() {
+    let coffee_shop = DirkCoffeeShop::builder().build();
+    println!("{}", coffee_shop.recipe().name);
+}
+
+#[component(recipe: config_bind(Recipe, path = "recipe"))]
+trait CoffeeShop {
+    fn recipe(&self) -> Recipe;
+}
+
+//######################################################################################################################
+
+#[derive(Clone)]
+struct Recipe {
+    name: String,
+}
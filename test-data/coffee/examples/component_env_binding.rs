@@ -0,0 +1,75 @@
+//! An example involving a coffee machine - configuration read from environment variables
+
+use std::env;
+
+use dirk_framework::{
+    component,
+    component::{builder::Builder, Component},
+    provides,
+};
+
+fn main() {
+    env::remove_var("COFFEE_CUPS");
+    env::set_var("COFFEE_STRENGTH", "very strong");
+
+    match DirkCoffeeShop::builder()
+        .name("Dirk's".to_string())
+        .try_build()
+    {
+        Ok(_) => println!("unexpectedly built"),
+        Err(e) => println!("{e}"),
+    }
+
+    env::set_var("COFFEE_CUPS", "2");
+    env::set_var("COFFEE_STRENGTH", "7");
+
+    let coffee_shop = DirkCoffeeShop::builder()
+        .name("Dirk's".to_string())
+        .try_build()
+        .unwrap();
+
+    coffee_shop.maker().brew();
+}
+
+#[component(
+    name: cloned_instance_bind(String),
+    cups: env_bind(usize, "COFFEE_CUPS"),
+    strength: env_bind(u8, "COFFEE_STRENGTH"),
+    temperature: env_bind(u8, "COFFEE_TEMPERATURE", default = 92),
+    maker: static_bind(CoffeeMaker) [name, cups, strength, temperature]
+)]
+trait CoffeeShop {
+    fn maker(&self) -> CoffeeMaker;
+}
+
+//######################################################################################################################
+
+struct CoffeeMaker {
+    name: String,
+    cups: usize,
+    strength: u8,
+    temperature: u8,
+}
+
+#[provides]
+impl CoffeeMaker {
+    fn new(name: String, cups: usize, strength: u8, temperature: u8) -> Self {
+        Self {
+            name,
+            cups,
+            strength,
+            temperature,
+        }
+    }
+}
+
+impl CoffeeMaker {
+    fn brew(&self) {
+        for _ in 0..self.cups {
+            println!(
+                " [_]P {} coffee of strength {} at {}°C [_]P ",
+                self.name, self.strength, self.temperature
+            );
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! An example involving two coffee shops of the same name, whose dependency graphs would be exported to the same file
+
+use dirk_framework::component::StaticComponent;
+
+use downtown::CoffeeShop as _;
+use uptown::CoffeeShop as _;
+
+fn main() {
+    let downtown = downtown::DirkCoffeeShop::create();
+    let uptown = uptown::DirkCoffeeShop::create();
+
+    println!("{} and {}", downtown.shop().name, uptown.shop().name);
+}
+
+mod downtown {
+    use dirk_framework::{component, provides};
+
+    #[component(shop: static_bind(Shop))]
+    pub(crate) trait CoffeeShop {
+        fn shop(&self) -> Shop;
+    }
+
+    pub(crate) struct Shop {
+        pub(crate) name: &'static str,
+    }
+
+    #[provides]
+    impl Shop {
+        fn new() -> Self {
+            Self { name: "Downtown" }
+        }
+    }
+}
+
+mod uptown {
+    use dirk_framework::{component, provides};
+
+    #[component(shop: static_bind(Shop))]
+    pub(crate) trait CoffeeShop {
+        fn shop(&self) -> Shop;
+    }
+
+    pub(crate) struct Shop {
+        pub(crate) name: &'static str,
+    }
+
+    #[provides]
+    impl Shop {
+        fn new() -> Self {
+            Self { name: "Uptown" }
+        }
+    }
+}
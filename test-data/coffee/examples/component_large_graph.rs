@@ -0,0 +1,362 @@
+//! A component containing 300 bindings, each depending on the two declared after it
+//!
+//! Bindings are declared in reverse topological order, so providers can only be instantiated after sorting them.
+
+use std::{cell::RefCell, rc::Rc};
+
+use dirk_framework::{
+    component,
+    component::{builder::Builder, Component},
+    use_provides,
+};
+
+#[use_provides(scoped_inject)]
+use weight::Weight;
+
+fn main() {
+    let component = DirkLargeGraph::builder().seed(Weight::default()).build();
+
+    println!("depth of node_0: {}", component.node_0().borrow().depth());
+    println!(
+        "depth of node_150: {}",
+        component.node_150().borrow().depth()
+    );
+    println!(
+        "depth of node_299: {}",
+        component.node_299().borrow().depth()
+    );
+}
+
+#[component(
+    node_0: scoped_bind(Weight) [node_1, node_2],
+    node_1: scoped_bind(Weight) [node_2, node_3],
+    node_2: scoped_bind(Weight) [node_3, node_4],
+    node_3: scoped_bind(Weight) [node_4, node_5],
+    node_4: scoped_bind(Weight) [node_5, node_6],
+    node_5: scoped_bind(Weight) [node_6, node_7],
+    node_6: scoped_bind(Weight) [node_7, node_8],
+    node_7: scoped_bind(Weight) [node_8, node_9],
+    node_8: scoped_bind(Weight) [node_9, node_10],
+    node_9: scoped_bind(Weight) [node_10, node_11],
+    node_10: scoped_bind(Weight) [node_11, node_12],
+    node_11: scoped_bind(Weight) [node_12, node_13],
+    node_12: scoped_bind(Weight) [node_13, node_14],
+    node_13: scoped_bind(Weight) [node_14, node_15],
+    node_14: scoped_bind(Weight) [node_15, node_16],
+    node_15: scoped_bind(Weight) [node_16, node_17],
+    node_16: scoped_bind(Weight) [node_17, node_18],
+    node_17: scoped_bind(Weight) [node_18, node_19],
+    node_18: scoped_bind(Weight) [node_19, node_20],
+    node_19: scoped_bind(Weight) [node_20, node_21],
+    node_20: scoped_bind(Weight) [node_21, node_22],
+    node_21: scoped_bind(Weight) [node_22, node_23],
+    node_22: scoped_bind(Weight) [node_23, node_24],
+    node_23: scoped_bind(Weight) [node_24, node_25],
+    node_24: scoped_bind(Weight) [node_25, node_26],
+    node_25: scoped_bind(Weight) [node_26, node_27],
+    node_26: scoped_bind(Weight) [node_27, node_28],
+    node_27: scoped_bind(Weight) [node_28, node_29],
+    node_28: scoped_bind(Weight) [node_29, node_30],
+    node_29: scoped_bind(Weight) [node_30, node_31],
+    node_30: scoped_bind(Weight) [node_31, node_32],
+    node_31: scoped_bind(Weight) [node_32, node_33],
+    node_32: scoped_bind(Weight) [node_33, node_34],
+    node_33: scoped_bind(Weight) [node_34, node_35],
+    node_34: scoped_bind(Weight) [node_35, node_36],
+    node_35: scoped_bind(Weight) [node_36, node_37],
+    node_36: scoped_bind(Weight) [node_37, node_38],
+    node_37: scoped_bind(Weight) [node_38, node_39],
+    node_38: scoped_bind(Weight) [node_39, node_40],
+    node_39: scoped_bind(Weight) [node_40, node_41],
+    node_40: scoped_bind(Weight) [node_41, node_42],
+    node_41: scoped_bind(Weight) [node_42, node_43],
+    node_42: scoped_bind(Weight) [node_43, node_44],
+    node_43: scoped_bind(Weight) [node_44, node_45],
+    node_44: scoped_bind(Weight) [node_45, node_46],
+    node_45: scoped_bind(Weight) [node_46, node_47],
+    node_46: scoped_bind(Weight) [node_47, node_48],
+    node_47: scoped_bind(Weight) [node_48, node_49],
+    node_48: scoped_bind(Weight) [node_49, node_50],
+    node_49: scoped_bind(Weight) [node_50, node_51],
+    node_50: scoped_bind(Weight) [node_51, node_52],
+    node_51: scoped_bind(Weight) [node_52, node_53],
+    node_52: scoped_bind(Weight) [node_53, node_54],
+    node_53: scoped_bind(Weight) [node_54, node_55],
+    node_54: scoped_bind(Weight) [node_55, node_56],
+    node_55: scoped_bind(Weight) [node_56, node_57],
+    node_56: scoped_bind(Weight) [node_57, node_58],
+    node_57: scoped_bind(Weight) [node_58, node_59],
+    node_58: scoped_bind(Weight) [node_59, node_60],
+    node_59: scoped_bind(Weight) [node_60, node_61],
+    node_60: scoped_bind(Weight) [node_61, node_62],
+    node_61: scoped_bind(Weight) [node_62, node_63],
+    node_62: scoped_bind(Weight) [node_63, node_64],
+    node_63: scoped_bind(Weight) [node_64, node_65],
+    node_64: scoped_bind(Weight) [node_65, node_66],
+    node_65: scoped_bind(Weight) [node_66, node_67],
+    node_66: scoped_bind(Weight) [node_67, node_68],
+    node_67: scoped_bind(Weight) [node_68, node_69],
+    node_68: scoped_bind(Weight) [node_69, node_70],
+    node_69: scoped_bind(Weight) [node_70, node_71],
+    node_70: scoped_bind(Weight) [node_71, node_72],
+    node_71: scoped_bind(Weight) [node_72, node_73],
+    node_72: scoped_bind(Weight) [node_73, node_74],
+    node_73: scoped_bind(Weight) [node_74, node_75],
+    node_74: scoped_bind(Weight) [node_75, node_76],
+    node_75: scoped_bind(Weight) [node_76, node_77],
+    node_76: scoped_bind(Weight) [node_77, node_78],
+    node_77: scoped_bind(Weight) [node_78, node_79],
+    node_78: scoped_bind(Weight) [node_79, node_80],
+    node_79: scoped_bind(Weight) [node_80, node_81],
+    node_80: scoped_bind(Weight) [node_81, node_82],
+    node_81: scoped_bind(Weight) [node_82, node_83],
+    node_82: scoped_bind(Weight) [node_83, node_84],
+    node_83: scoped_bind(Weight) [node_84, node_85],
+    node_84: scoped_bind(Weight) [node_85, node_86],
+    node_85: scoped_bind(Weight) [node_86, node_87],
+    node_86: scoped_bind(Weight) [node_87, node_88],
+    node_87: scoped_bind(Weight) [node_88, node_89],
+    node_88: scoped_bind(Weight) [node_89, node_90],
+    node_89: scoped_bind(Weight) [node_90, node_91],
+    node_90: scoped_bind(Weight) [node_91, node_92],
+    node_91: scoped_bind(Weight) [node_92, node_93],
+    node_92: scoped_bind(Weight) [node_93, node_94],
+    node_93: scoped_bind(Weight) [node_94, node_95],
+    node_94: scoped_bind(Weight) [node_95, node_96],
+    node_95: scoped_bind(Weight) [node_96, node_97],
+    node_96: scoped_bind(Weight) [node_97, node_98],
+    node_97: scoped_bind(Weight) [node_98, node_99],
+    node_98: scoped_bind(Weight) [node_99, node_100],
+    node_99: scoped_bind(Weight) [node_100, node_101],
+    node_100: scoped_bind(Weight) [node_101, node_102],
+    node_101: scoped_bind(Weight) [node_102, node_103],
+    node_102: scoped_bind(Weight) [node_103, node_104],
+    node_103: scoped_bind(Weight) [node_104, node_105],
+    node_104: scoped_bind(Weight) [node_105, node_106],
+    node_105: scoped_bind(Weight) [node_106, node_107],
+    node_106: scoped_bind(Weight) [node_107, node_108],
+    node_107: scoped_bind(Weight) [node_108, node_109],
+    node_108: scoped_bind(Weight) [node_109, node_110],
+    node_109: scoped_bind(Weight) [node_110, node_111],
+    node_110: scoped_bind(Weight) [node_111, node_112],
+    node_111: scoped_bind(Weight) [node_112, node_113],
+    node_112: scoped_bind(Weight) [node_113, node_114],
+    node_113: scoped_bind(Weight) [node_114, node_115],
+    node_114: scoped_bind(Weight) [node_115, node_116],
+    node_115: scoped_bind(Weight) [node_116, node_117],
+    node_116: scoped_bind(Weight) [node_117, node_118],
+    node_117: scoped_bind(Weight) [node_118, node_119],
+    node_118: scoped_bind(Weight) [node_119, node_120],
+    node_119: scoped_bind(Weight) [node_120, node_121],
+    node_120: scoped_bind(Weight) [node_121, node_122],
+    node_121: scoped_bind(Weight) [node_122, node_123],
+    node_122: scoped_bind(Weight) [node_123, node_124],
+    node_123: scoped_bind(Weight) [node_124, node_125],
+    node_124: scoped_bind(Weight) [node_125, node_126],
+    node_125: scoped_bind(Weight) [node_126, node_127],
+    node_126: scoped_bind(Weight) [node_127, node_128],
+    node_127: scoped_bind(Weight) [node_128, node_129],
+    node_128: scoped_bind(Weight) [node_129, node_130],
+    node_129: scoped_bind(Weight) [node_130, node_131],
+    node_130: scoped_bind(Weight) [node_131, node_132],
+    node_131: scoped_bind(Weight) [node_132, node_133],
+    node_132: scoped_bind(Weight) [node_133, node_134],
+    node_133: scoped_bind(Weight) [node_134, node_135],
+    node_134: scoped_bind(Weight) [node_135, node_136],
+    node_135: scoped_bind(Weight) [node_136, node_137],
+    node_136: scoped_bind(Weight) [node_137, node_138],
+    node_137: scoped_bind(Weight) [node_138, node_139],
+    node_138: scoped_bind(Weight) [node_139, node_140],
+    node_139: scoped_bind(Weight) [node_140, node_141],
+    node_140: scoped_bind(Weight) [node_141, node_142],
+    node_141: scoped_bind(Weight) [node_142, node_143],
+    node_142: scoped_bind(Weight) [node_143, node_144],
+    node_143: scoped_bind(Weight) [node_144, node_145],
+    node_144: scoped_bind(Weight) [node_145, node_146],
+    node_145: scoped_bind(Weight) [node_146, node_147],
+    node_146: scoped_bind(Weight) [node_147, node_148],
+    node_147: scoped_bind(Weight) [node_148, node_149],
+    node_148: scoped_bind(Weight) [node_149, node_150],
+    node_149: scoped_bind(Weight) [node_150, node_151],
+    node_150: scoped_bind(Weight) [node_151, node_152],
+    node_151: scoped_bind(Weight) [node_152, node_153],
+    node_152: scoped_bind(Weight) [node_153, node_154],
+    node_153: scoped_bind(Weight) [node_154, node_155],
+    node_154: scoped_bind(Weight) [node_155, node_156],
+    node_155: scoped_bind(Weight) [node_156, node_157],
+    node_156: scoped_bind(Weight) [node_157, node_158],
+    node_157: scoped_bind(Weight) [node_158, node_159],
+    node_158: scoped_bind(Weight) [node_159, node_160],
+    node_159: scoped_bind(Weight) [node_160, node_161],
+    node_160: scoped_bind(Weight) [node_161, node_162],
+    node_161: scoped_bind(Weight) [node_162, node_163],
+    node_162: scoped_bind(Weight) [node_163, node_164],
+    node_163: scoped_bind(Weight) [node_164, node_165],
+    node_164: scoped_bind(Weight) [node_165, node_166],
+    node_165: scoped_bind(Weight) [node_166, node_167],
+    node_166: scoped_bind(Weight) [node_167, node_168],
+    node_167: scoped_bind(Weight) [node_168, node_169],
+    node_168: scoped_bind(Weight) [node_169, node_170],
+    node_169: scoped_bind(Weight) [node_170, node_171],
+    node_170: scoped_bind(Weight) [node_171, node_172],
+    node_171: scoped_bind(Weight) [node_172, node_173],
+    node_172: scoped_bind(Weight) [node_173, node_174],
+    node_173: scoped_bind(Weight) [node_174, node_175],
+    node_174: scoped_bind(Weight) [node_175, node_176],
+    node_175: scoped_bind(Weight) [node_176, node_177],
+    node_176: scoped_bind(Weight) [node_177, node_178],
+    node_177: scoped_bind(Weight) [node_178, node_179],
+    node_178: scoped_bind(Weight) [node_179, node_180],
+    node_179: scoped_bind(Weight) [node_180, node_181],
+    node_180: scoped_bind(Weight) [node_181, node_182],
+    node_181: scoped_bind(Weight) [node_182, node_183],
+    node_182: scoped_bind(Weight) [node_183, node_184],
+    node_183: scoped_bind(Weight) [node_184, node_185],
+    node_184: scoped_bind(Weight) [node_185, node_186],
+    node_185: scoped_bind(Weight) [node_186, node_187],
+    node_186: scoped_bind(Weight) [node_187, node_188],
+    node_187: scoped_bind(Weight) [node_188, node_189],
+    node_188: scoped_bind(Weight) [node_189, node_190],
+    node_189: scoped_bind(Weight) [node_190, node_191],
+    node_190: scoped_bind(Weight) [node_191, node_192],
+    node_191: scoped_bind(Weight) [node_192, node_193],
+    node_192: scoped_bind(Weight) [node_193, node_194],
+    node_193: scoped_bind(Weight) [node_194, node_195],
+    node_194: scoped_bind(Weight) [node_195, node_196],
+    node_195: scoped_bind(Weight) [node_196, node_197],
+    node_196: scoped_bind(Weight) [node_197, node_198],
+    node_197: scoped_bind(Weight) [node_198, node_199],
+    node_198: scoped_bind(Weight) [node_199, node_200],
+    node_199: scoped_bind(Weight) [node_200, node_201],
+    node_200: scoped_bind(Weight) [node_201, node_202],
+    node_201: scoped_bind(Weight) [node_202, node_203],
+    node_202: scoped_bind(Weight) [node_203, node_204],
+    node_203: scoped_bind(Weight) [node_204, node_205],
+    node_204: scoped_bind(Weight) [node_205, node_206],
+    node_205: scoped_bind(Weight) [node_206, node_207],
+    node_206: scoped_bind(Weight) [node_207, node_208],
+    node_207: scoped_bind(Weight) [node_208, node_209],
+    node_208: scoped_bind(Weight) [node_209, node_210],
+    node_209: scoped_bind(Weight) [node_210, node_211],
+    node_210: scoped_bind(Weight) [node_211, node_212],
+    node_211: scoped_bind(Weight) [node_212, node_213],
+    node_212: scoped_bind(Weight) [node_213, node_214],
+    node_213: scoped_bind(Weight) [node_214, node_215],
+    node_214: scoped_bind(Weight) [node_215, node_216],
+    node_215: scoped_bind(Weight) [node_216, node_217],
+    node_216: scoped_bind(Weight) [node_217, node_218],
+    node_217: scoped_bind(Weight) [node_218, node_219],
+    node_218: scoped_bind(Weight) [node_219, node_220],
+    node_219: scoped_bind(Weight) [node_220, node_221],
+    node_220: scoped_bind(Weight) [node_221, node_222],
+    node_221: scoped_bind(Weight) [node_222, node_223],
+    node_222: scoped_bind(Weight) [node_223, node_224],
+    node_223: scoped_bind(Weight) [node_224, node_225],
+    node_224: scoped_bind(Weight) [node_225, node_226],
+    node_225: scoped_bind(Weight) [node_226, node_227],
+    node_226: scoped_bind(Weight) [node_227, node_228],
+    node_227: scoped_bind(Weight) [node_228, node_229],
+    node_228: scoped_bind(Weight) [node_229, node_230],
+    node_229: scoped_bind(Weight) [node_230, node_231],
+    node_230: scoped_bind(Weight) [node_231, node_232],
+    node_231: scoped_bind(Weight) [node_232, node_233],
+    node_232: scoped_bind(Weight) [node_233, node_234],
+    node_233: scoped_bind(Weight) [node_234, node_235],
+    node_234: scoped_bind(Weight) [node_235, node_236],
+    node_235: scoped_bind(Weight) [node_236, node_237],
+    node_236: scoped_bind(Weight) [node_237, node_238],
+    node_237: scoped_bind(Weight) [node_238, node_239],
+    node_238: scoped_bind(Weight) [node_239, node_240],
+    node_239: scoped_bind(Weight) [node_240, node_241],
+    node_240: scoped_bind(Weight) [node_241, node_242],
+    node_241: scoped_bind(Weight) [node_242, node_243],
+    node_242: scoped_bind(Weight) [node_243, node_244],
+    node_243: scoped_bind(Weight) [node_244, node_245],
+    node_244: scoped_bind(Weight) [node_245, node_246],
+    node_245: scoped_bind(Weight) [node_246, node_247],
+    node_246: scoped_bind(Weight) [node_247, node_248],
+    node_247: scoped_bind(Weight) [node_248, node_249],
+    node_248: scoped_bind(Weight) [node_249, node_250],
+    node_249: scoped_bind(Weight) [node_250, node_251],
+    node_250: scoped_bind(Weight) [node_251, node_252],
+    node_251: scoped_bind(Weight) [node_252, node_253],
+    node_252: scoped_bind(Weight) [node_253, node_254],
+    node_253: scoped_bind(Weight) [node_254, node_255],
+    node_254: scoped_bind(Weight) [node_255, node_256],
+    node_255: scoped_bind(Weight) [node_256, node_257],
+    node_256: scoped_bind(Weight) [node_257, node_258],
+    node_257: scoped_bind(Weight) [node_258, node_259],
+    node_258: scoped_bind(Weight) [node_259, node_260],
+    node_259: scoped_bind(Weight) [node_260, node_261],
+    node_260: scoped_bind(Weight) [node_261, node_262],
+    node_261: scoped_bind(Weight) [node_262, node_263],
+    node_262: scoped_bind(Weight) [node_263, node_264],
+    node_263: scoped_bind(Weight) [node_264, node_265],
+    node_264: scoped_bind(Weight) [node_265, node_266],
+    node_265: scoped_bind(Weight) [node_266, node_267],
+    node_266: scoped_bind(Weight) [node_267, node_268],
+    node_267: scoped_bind(Weight) [node_268, node_269],
+    node_268: scoped_bind(Weight) [node_269, node_270],
+    node_269: scoped_bind(Weight) [node_270, node_271],
+    node_270: scoped_bind(Weight) [node_271, node_272],
+    node_271: scoped_bind(Weight) [node_272, node_273],
+    node_272: scoped_bind(Weight) [node_273, node_274],
+    node_273: scoped_bind(Weight) [node_274, node_275],
+    node_274: scoped_bind(Weight) [node_275, node_276],
+    node_275: scoped_bind(Weight) [node_276, node_277],
+    node_276: scoped_bind(Weight) [node_277, node_278],
+    node_277: scoped_bind(Weight) [node_278, node_279],
+    node_278: scoped_bind(Weight) [node_279, node_280],
+    node_279: scoped_bind(Weight) [node_280, node_281],
+    node_280: scoped_bind(Weight) [node_281, node_282],
+    node_281: scoped_bind(Weight) [node_282, node_283],
+    node_282: scoped_bind(Weight) [node_283, node_284],
+    node_283: scoped_bind(Weight) [node_284, node_285],
+    node_284: scoped_bind(Weight) [node_285, node_286],
+    node_285: scoped_bind(Weight) [node_286, node_287],
+    node_286: scoped_bind(Weight) [node_287, node_288],
+    node_287: scoped_bind(Weight) [node_288, node_289],
+    node_288: scoped_bind(Weight) [node_289, node_290],
+    node_289: scoped_bind(Weight) [node_290, node_291],
+    node_290: scoped_bind(Weight) [node_291, node_292],
+    node_291: scoped_bind(Weight) [node_292, node_293],
+    node_292: scoped_bind(Weight) [node_293, node_294],
+    node_293: scoped_bind(Weight) [node_294, node_295],
+    node_294: scoped_bind(Weight) [node_295, node_296],
+    node_295: scoped_bind(Weight) [node_296, node_297],
+    node_296: scoped_bind(Weight) [node_297, node_298],
+    node_297: scoped_bind(Weight) [node_298, node_299],
+    node_298: scoped_bind(Weight) [node_299, seed],
+    node_299: scoped_bind(Weight) [seed, seed],
+    seed: scoped_instance_bind(Weight)
+)]
+trait LargeGraph {
+    fn node_0(&self) -> Rc<RefCell<Weight>>;
+    fn node_150(&self) -> Rc<RefCell<Weight>>;
+    fn node_299(&self) -> Rc<RefCell<Weight>>;
+}
+
+mod weight {
+    use std::{cell::RefCell, rc::Rc};
+
+    use dirk_framework::provides;
+
+    #[derive(Default)]
+    pub(crate) struct Weight {
+        depth: usize,
+    }
+
+    #[provides(scoped_inject)]
+    impl Weight {
+        fn new(left: Rc<RefCell<Weight>>, right: Rc<RefCell<Weight>>) -> Self {
+            let depth = 1 + usize::max(left.borrow().depth, right.borrow().depth);
+            Self { depth }
+        }
+    }
+
+    impl Weight {
+        pub(crate) fn depth(&self) -> usize {
+            self.depth
+        }
+    }
+}
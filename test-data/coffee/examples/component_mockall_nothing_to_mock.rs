@@ -0,0 +1,63 @@
+//! An example involving a coffee machine
+
+use std::{cell::RefCell, rc::Rc};
+
+use dirk_framework::{
+    component,
+    component::{builder::Builder, Component},
+    provides,
+};
+
+use heater::{ElectricHeater, Heater};
+
+fn main() {
+    let coffee_shop = DirkCoffeeShop::builder().heater(ElectricHeater).build();
+    coffee_shop.maker().brew();
+}
+
+#[component(
+    mockall,
+    heater: scoped_instance_bind(ElectricHeater),
+    maker: static_bind(CoffeeMaker<ElectricHeater>) [heater]
+)]
+trait CoffeeShop {
+    fn maker(&self) -> CoffeeMaker<ElectricHeater>;
+}
+
+//######################################################################################################################
+
+struct CoffeeMaker<H: Heater> {
+    heater: Rc<RefCell<H>>,
+}
+
+#[provides]
+impl<H: Heater> CoffeeMaker<H> {
+    fn new(heater: Rc<RefCell<H>>) -> Self {
+        Self { heater }
+    }
+}
+
+impl<H: Heater> CoffeeMaker<H> {
+    fn brew(&mut self) {
+        self.heater.borrow_mut().on();
+        println!(" [_]P coffee! [_]P ");
+        self.heater.borrow_mut().off();
+    }
+}
+
+mod heater {
+    pub trait Heater {
+        fn on(&mut self);
+        fn off(&mut self);
+    }
+
+    pub struct ElectricHeater;
+
+    impl Heater for ElectricHeater {
+        fn on(&mut self) {
+            println!("~ ~ ~ heating ~ ~ ~");
+        }
+
+        fn off(&mut self) {}
+    }
+}
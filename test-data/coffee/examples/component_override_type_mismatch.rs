@@ -0,0 +1,64 @@
+//! An example involving a coffee machine - overriding a binding by an instance of a different type
+
+use dirk_framework::{component, component::StaticComponent, provides};
+
+fn main() {
+    let coffee_shop = DirkCoffeeShop::create();
+    coffee_shop.maker().brew();
+}
+
+#[component(
+    heater: scoped_bind(Heater),
+    maker: static_bind(CoffeeMaker) [heater]
+)]
+trait CoffeeShop {
+    fn maker(&self) -> CoffeeMaker;
+}
+
+#[cfg(test)]
+mod test {
+    use dirk_framework::component::{builder::Builder, Component};
+
+    use crate::{CoffeeShop, DirkCoffeeShop};
+
+    #[test]
+    fn test_coffe_maker() {
+        // the heater binding is of type `Heater`
+        let coffee_shop = DirkCoffeeShop::builder()
+            .override_heater(String::from("heater"))
+            .build();
+
+        coffee_shop.maker().brew();
+    }
+}
+
+//######################################################################################################################
+
+use std::{cell::RefCell, rc::Rc};
+
+struct Heater;
+
+#[provides(scoped_inject)]
+impl Heater {
+    fn new() -> Self {
+        Self
+    }
+}
+
+struct CoffeeMaker {
+    heater: Rc<RefCell<Heater>>,
+}
+
+#[provides]
+impl CoffeeMaker {
+    fn new(heater: Rc<RefCell<Heater>>) -> Self {
+        Self { heater }
+    }
+}
+
+impl CoffeeMaker {
+    fn brew(&self) {
+        let _heater = self.heater.borrow();
+        println!(" [_]P coffee! [_]P ");
+    }
+}
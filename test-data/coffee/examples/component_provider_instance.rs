@@ -0,0 +1,68 @@
+//! An example involving a coffee machine - instances created by providers supplied via the builder
+
+use std::cell::Cell;
+
+use dirk_framework::{
+    component,
+    component::{builder::Builder, Component},
+    provides,
+    provides::Provider,
+};
+
+fn main() {
+    let coffee_shop = DirkCoffeeShop::builder()
+        .temperature(|| 92)
+        .order(OrderNumbers::default())
+        .build();
+
+    coffee_shop.maker().brew();
+    coffee_shop.maker().brew();
+    println!("next order: #{}", coffee_shop.order());
+}
+
+#[component(
+    temperature: provider_instance_bind(u8),
+    order: provider_instance_bind(usize),
+    maker: static_bind(CoffeeMaker) [temperature, order]
+)]
+trait CoffeeShop {
+    fn order(&self) -> usize;
+    fn maker(&self) -> CoffeeMaker;
+}
+
+//######################################################################################################################
+
+/// Hands out a new number every time it is asked for one
+#[derive(Default)]
+struct OrderNumbers {
+    next: Cell<usize>,
+}
+
+impl Provider<usize> for OrderNumbers {
+    fn get(&self) -> usize {
+        let next = self.next.get() + 1;
+        self.next.set(next);
+        next
+    }
+}
+
+struct CoffeeMaker {
+    temperature: u8,
+    order: usize,
+}
+
+#[provides]
+impl CoffeeMaker {
+    fn new(temperature: u8, order: usize) -> Self {
+        Self { temperature, order }
+    }
+}
+
+impl CoffeeMaker {
+    fn brew(&self) {
+        println!(
+            " [_]P coffee #{} at {}°C [_]P ",
+            self.order, self.temperature
+        );
+    }
+}
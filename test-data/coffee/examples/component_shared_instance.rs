@@ -0,0 +1,77 @@
+//! An example involving a coffee machine - immutable instances shared via `Rc` and `Arc`
+
+use std::{rc::Rc, sync::Arc, thread};
+
+use dirk_framework::{
+    component,
+    component::{builder::Builder, Component},
+    provides,
+};
+
+fn main() {
+    let coffee_shop = DirkCoffeeShop::builder()
+        .recipe(Recipe {
+            name: "espresso".to_string(),
+            grams: 18,
+        })
+        .menu(Menu {
+            prices: vec![("espresso".to_string(), 2), ("lungo".to_string(), 3)],
+        })
+        .build();
+
+    coffee_shop.maker().brew();
+    coffee_shop.maker().brew();
+
+    let recipe = coffee_shop.recipe();
+    println!("recipe shared by {} references", Rc::strong_count(&recipe));
+
+    let menu = coffee_shop.menu();
+    let handle = thread::spawn(move || {
+        menu.prices
+            .iter()
+            .for_each(|(name, price)| println!("{name}: {price}$"));
+    });
+    handle.join().unwrap();
+}
+
+#[component(
+    recipe: shared_instance_bind(Recipe),
+    menu: arc_instance_bind(Menu),
+    maker: static_bind(CoffeeMaker) [recipe]
+)]
+trait CoffeeShop {
+    fn recipe(&self) -> Rc<Recipe>;
+    fn menu(&self) -> Arc<Menu>;
+    fn maker(&self) -> CoffeeMaker;
+}
+
+//######################################################################################################################
+
+struct Recipe {
+    name: String,
+    grams: usize,
+}
+
+struct Menu {
+    prices: Vec<(String, usize)>,
+}
+
+struct CoffeeMaker {
+    recipe: Rc<Recipe>,
+}
+
+#[provides]
+impl CoffeeMaker {
+    fn new(recipe: Rc<Recipe>) -> Self {
+        Self { recipe }
+    }
+}
+
+impl CoffeeMaker {
+    fn brew(&self) {
+        println!(
+            " [_]P {} with {}g of coffee [_]P ",
+            self.recipe.name, self.recipe.grams
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! An example involving a coffee machine - a scoped instance shared between the component and its caller
+
+use std::{cell::RefCell, rc::Rc};
+
+use dirk_framework::{
+    component,
+    component::{builder::Builder, Component},
+    provides,
+};
+
+fn main() {
+    // e.g., also held by a user interface
+    let display = Rc::new(RefCell::new(Display::default()));
+
+    let coffee_shop = DirkCoffeeShop::builder()
+        .display_shared(display.clone())
+        .build();
+
+    coffee_shop.maker().brew();
+    coffee_shop.maker().brew();
+
+    display
+        .borrow()
+        .lines
+        .iter()
+        .for_each(|line| println!("display: {line}"));
+}
+
+#[component(
+    display: scoped_instance_bind(Display),
+    maker: static_bind(CoffeeMaker) [display]
+)]
+trait CoffeeShop {
+    fn maker(&self) -> CoffeeMaker;
+}
+
+//######################################################################################################################
+
+#[derive(Default)]
+struct Display {
+    lines: Vec<String>,
+}
+
+struct CoffeeMaker {
+    display: Rc<RefCell<Display>>,
+}
+
+#[provides]
+impl CoffeeMaker {
+    fn new(display: Rc<RefCell<Display>>) -> Self {
+        Self { display }
+    }
+}
+
+impl CoffeeMaker {
+    fn brew(&self) {
+        println!(" [_]P coffee! [_]P ");
+        self.display
+            .borrow_mut()
+            .lines
+            .push("enjoy your coffee".to_string());
+    }
+}
@@ -0,0 +1,124 @@
+//! An example involving a coffee machine - tests receiving their parameters from a component
+
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::{Arc, RwLock},
+};
+
+use dirk_framework::{component, component::StaticComponent, provides};
+
+fn main() {
+    let coffee_shop = DirkCoffeeShop::create();
+    coffee_shop.maker().brew();
+    coffee_shop.maker().brew();
+
+    let cups = coffee_shop.counter().read().unwrap().cups;
+    println!("{cups} cups brewed");
+
+    let hot = coffee_shop.heater().borrow().is_hot();
+    println!("heater is hot: {hot}");
+}
+
+#[component(
+    counter: singleton_bind(CupCounter),
+    heater: scoped_bind(Heater),
+    maker: static_bind(CoffeeMaker) [counter, heater]
+)]
+trait CoffeeShop {
+    fn counter(&self) -> Arc<RwLock<CupCounter>>;
+    fn heater(&self) -> Rc<RefCell<Heater>>;
+    fn maker(&self) -> CoffeeMaker;
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+        sync::{Arc, RwLock},
+    };
+
+    use crate::{CoffeeMaker, CoffeeShop, CupCounter, DirkCoffeeShop, Heater};
+
+    #[dirk_framework::test(component = CoffeeShop)]
+    fn brews_one_cup(mut maker: CoffeeMaker, counter: Arc<RwLock<CupCounter>>) {
+        maker.brew();
+
+        assert_eq!(counter.read().unwrap().cups, 1);
+    }
+
+    // singletons are not shared with other tests
+    #[dirk_framework::test(component = crate::CoffeeShop)]
+    fn brews_two_cups(
+        mut maker: CoffeeMaker,
+        counter: Arc<RwLock<CupCounter>>,
+        heater: Rc<RefCell<Heater>>,
+    ) {
+        maker.brew();
+        maker.brew();
+
+        assert_eq!(counter.read().unwrap().cups, 2);
+        assert!(!heater.borrow().is_hot());
+    }
+}
+
+//######################################################################################################################
+
+struct CupCounter {
+    cups: usize,
+}
+
+#[provides(singleton_inject)]
+impl CupCounter {
+    fn new() -> Self {
+        Self { cups: 0 }
+    }
+}
+
+struct Heater {
+    heating: bool,
+}
+
+#[provides(scoped_inject)]
+impl Heater {
+    fn new() -> Self {
+        Self { heating: false }
+    }
+}
+
+impl Heater {
+    fn on(&mut self) {
+        self.heating = true;
+        println!("~ ~ ~ heating ~ ~ ~");
+    }
+
+    fn off(&mut self) {
+        self.heating = false;
+    }
+
+    fn is_hot(&self) -> bool {
+        self.heating
+    }
+}
+
+struct CoffeeMaker {
+    counter: Arc<RwLock<CupCounter>>,
+    heater: Rc<RefCell<Heater>>,
+}
+
+#[provides]
+impl CoffeeMaker {
+    fn new(counter: Arc<RwLock<CupCounter>>, heater: Rc<RefCell<Heater>>) -> Self {
+        Self { counter, heater }
+    }
+}
+
+impl CoffeeMaker {
+    fn brew(&mut self) {
+        self.heater.borrow_mut().on();
+        println!(" [_]P coffee! [_]P ");
+        self.heater.borrow_mut().off();
+        self.counter.write().unwrap().cups += 1;
+    }
+}
@@ -0,0 +1,117 @@
+//! An example involving a coffee machine - strategy objects supplied at runtime as trait objects
+
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+use dirk_framework::{
+    component,
+    component::{builder::Builder, Component},
+    provides,
+};
+
+fn main() {
+    // e.g., chosen by configuration
+    let grinder: Rc<dyn Grinder> = Rc::new(BurrGrinder);
+
+    let coffee_shop = DirkCoffeeShop::builder()
+        .heater(ElectricHeater { heating: false })
+        .grinder_shared(grinder)
+        .clock(FixedClock(7))
+        .build();
+
+    coffee_shop.maker().brew();
+
+    let hot = coffee_shop.heater().borrow().is_hot();
+    println!("heater is hot: {hot}");
+}
+
+#[component(
+    heater: scoped_instance_bind(dyn Heater),
+    grinder: shared_instance_bind(dyn Grinder),
+    clock: arc_instance_bind(dyn Clock + Send + Sync),
+    maker: static_bind(CoffeeMaker) [heater, grinder, clock]
+)]
+trait CoffeeShop {
+    fn heater(&self) -> Rc<RefCell<dyn Heater>>;
+    fn maker(&self) -> CoffeeMaker;
+}
+
+//######################################################################################################################
+
+trait Heater {
+    fn on(&mut self);
+    fn off(&mut self);
+    fn is_hot(&self) -> bool;
+}
+
+struct ElectricHeater {
+    heating: bool,
+}
+
+impl Heater for ElectricHeater {
+    fn on(&mut self) {
+        self.heating = true;
+        println!("~ ~ ~ heating ~ ~ ~");
+    }
+
+    fn off(&mut self) {
+        self.heating = false;
+    }
+
+    fn is_hot(&self) -> bool {
+        self.heating
+    }
+}
+
+trait Grinder {
+    fn grind(&self);
+}
+
+struct BurrGrinder;
+
+impl Grinder for BurrGrinder {
+    fn grind(&self) {
+        println!("* * * grinding * * *");
+    }
+}
+
+trait Clock {
+    fn hour(&self) -> u8;
+}
+
+struct FixedClock(u8);
+
+impl Clock for FixedClock {
+    fn hour(&self) -> u8 {
+        self.0
+    }
+}
+
+struct CoffeeMaker {
+    heater: Rc<RefCell<dyn Heater>>,
+    grinder: Rc<dyn Grinder>,
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+#[provides]
+impl CoffeeMaker {
+    fn new(
+        heater: Rc<RefCell<dyn Heater>>,
+        grinder: Rc<dyn Grinder>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self {
+            heater,
+            grinder,
+            clock,
+        }
+    }
+}
+
+impl CoffeeMaker {
+    fn brew(&self) {
+        self.grinder.grind();
+        self.heater.borrow_mut().on();
+        println!(" [_]P coffee at {} o'clock [_]P ", self.clock.hour());
+        self.heater.borrow_mut().off();
+    }
+}
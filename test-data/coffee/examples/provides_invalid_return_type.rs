@@ -11,7 +11,7 @@ use dirk_framework::{component, component::StaticComponent, provides, use_provid
 use heater::Heater;
 use pump::Pump;
 
-use crate::logger::SingletonFactoryOption;
+use crate::logger::DirkFactoryOption;
 use logger::CoffeeLogger;
 
 #[use_provides(scoped_inject)]
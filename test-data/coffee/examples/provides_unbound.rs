@@ -0,0 +1,50 @@
+//! An example involving a coffee machine
+
+use std::{cell::RefCell, rc::Rc};
+
+use dirk_framework::{component, component::StaticComponent, provides};
+
+fn main() {
+    let coffee_shop = DirkCoffeeShop::create();
+    coffee_shop.heater().borrow_mut().on();
+    println!("hot: {}", coffee_shop.heater().borrow().is_hot());
+}
+
+#[component(
+    heater: scoped_bind(ElectricHeater),
+)]
+trait CoffeeShop {
+    fn heater(&self) -> Rc<RefCell<ElectricHeater>>;
+}
+
+//######################################################################################################################
+
+struct ElectricHeater {
+    heating: bool,
+}
+
+#[provides(scoped_inject)]
+impl ElectricHeater {
+    fn new() -> Self {
+        Self { heating: false }
+    }
+}
+
+impl ElectricHeater {
+    fn on(&mut self) {
+        self.heating = true;
+    }
+
+    fn is_hot(&self) -> bool {
+        self.heating
+    }
+}
+
+struct GasHeater {}
+
+#[provides(scoped_inject)]
+impl GasHeater {
+    fn new() -> Self {
+        Self {}
+    }
+}
@@ -0,0 +1,69 @@
+//! An example involving a coffee machine
+
+use std::{cell::RefCell, rc::Rc};
+
+use dirk_framework::{component, component::StaticComponent, provides};
+
+fn main() {
+    let coffee_shop = DirkCoffeeShop::create();
+    coffee_shop.heater().borrow_mut().on();
+    println!("hot: {}", coffee_shop.heater().borrow().is_hot());
+    coffee_shop.grinder().borrow().grind();
+}
+
+#[component(
+    logger: scoped_bind(CoffeeLogger),
+    heater: scoped_bind(ElectricHeater) [logger],
+    grinder: scoped_bind(BurrGrinder) [logger],
+)]
+trait CoffeeShop {
+    fn heater(&self) -> Rc<RefCell<ElectricHeater>>;
+    fn grinder(&self) -> Rc<RefCell<BurrGrinder>>;
+}
+
+//######################################################################################################################
+
+struct CoffeeLogger {}
+
+#[provides(scoped_inject)]
+impl CoffeeLogger {
+    fn new() -> Self {
+        Self {}
+    }
+}
+
+struct ElectricHeater {
+    heating: bool,
+}
+
+#[provides(scoped_inject)]
+impl ElectricHeater {
+    fn new(_logger: Rc<RefCell<CoffeeLogger>>) -> Self {
+        Self { heating: false }
+    }
+}
+
+impl ElectricHeater {
+    fn on(&mut self) {
+        self.heating = true;
+    }
+
+    fn is_hot(&self) -> bool {
+        self.heating
+    }
+}
+
+struct BurrGrinder {}
+
+#[provides(scoped_inject)]
+impl BurrGrinder {
+    fn new(logger: Rc<RefCell<CoffeeLogger>>) -> Self {
+        Self {}
+    }
+}
+
+impl BurrGrinder {
+    fn grind(&self) {
+        println!("* * * grinding * * *");
+    }
+}
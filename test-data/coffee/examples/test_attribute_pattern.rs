@@ -0,0 +1,28 @@
+//! An example involving a coffee machine - a test whose parameter cannot be queried from a component
+
+use dirk_framework::{component, provides};
+
+fn main() {}
+
+#[component(heater: static_bind(Heater))]
+trait CoffeeShop {
+    fn heater(&self) -> Heater;
+}
+
+#[dirk_framework::test(component = CoffeeShop)]
+fn heats(Heater { heating }: Heater) {
+    assert!(!heating);
+}
+
+//######################################################################################################################
+
+struct Heater {
+    heating: bool,
+}
+
+#[provides]
+impl Heater {
+    fn new() -> Self {
+        Self { heating: false }
+    }
+}
@@ -0,0 +1,181 @@
+//! An example involving a coffee machine - importing provided types without restating how they are injected
+
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::{Arc, RwLock},
+};
+
+use dirk_framework::{component, component::StaticComponent, provides, use_provides};
+
+#[use_provides]
+use heater::ElectricHeater as Heater;
+#[use_provides]
+use logger::CoffeeLogger;
+#[use_provides]
+use pump::ThermoSiphon;
+
+use pump::Pump;
+
+fn main() {
+    let coffee_shop = DirkCoffeeShop::create();
+    coffee_shop.maker().brew();
+    coffee_shop
+        .logger()
+        .read()
+        .unwrap()
+        .logs()
+        .iter()
+        .for_each(|l| println!("{l}"));
+}
+
+#[component(
+    logger: singleton_bind(CoffeeLogger),
+    heater: scoped_bind(Heater) [logger],
+    pump: static_bind(ThermoSiphon) [logger, heater],
+    maker: static_bind(CoffeeMaker) [logger, heater, pump]
+)]
+trait CoffeeShop {
+    fn maker(&self) -> CoffeeMaker;
+    fn logger(&self) -> Arc<RwLock<CoffeeLogger>>;
+}
+
+//######################################################################################################################
+
+struct CoffeeMaker {
+    logger: Arc<RwLock<CoffeeLogger>>,
+    heater: Rc<RefCell<Heater>>,
+    pump: ThermoSiphon,
+}
+
+#[provides]
+impl CoffeeMaker {
+    fn new(
+        logger: Arc<RwLock<CoffeeLogger>>,
+        heater: Rc<RefCell<Heater>>,
+        pump: ThermoSiphon,
+    ) -> Self {
+        Self {
+            logger,
+            heater,
+            pump,
+        }
+    }
+}
+
+impl CoffeeMaker {
+    fn brew(&mut self) {
+        self.heater.borrow_mut().on();
+        self.pump.pump();
+        self.logger
+            .write()
+            .unwrap()
+            .log(" [_]P coffee! [_]P ".to_string());
+        self.heater.borrow_mut().off();
+    }
+}
+
+mod logger {
+    use dirk_framework::provides;
+
+    pub(crate) struct CoffeeLogger {
+        logs: Vec<String>,
+    }
+
+    #[provides(singleton_inject)]
+    impl CoffeeLogger {
+        fn new() -> Self {
+            Self { logs: Vec::new() }
+        }
+    }
+
+    impl CoffeeLogger {
+        pub(crate) fn log(&mut self, msg: String) {
+            self.logs.push(msg);
+        }
+
+        pub(crate) fn logs(&self) -> Vec<String> {
+            self.logs.clone()
+        }
+    }
+}
+
+mod heater {
+    use std::sync::{Arc, RwLock};
+
+    use dirk_framework::provides;
+
+    use crate::logger::CoffeeLogger;
+
+    pub(crate) struct ElectricHeater {
+        logger: Arc<RwLock<CoffeeLogger>>,
+        heating: bool,
+    }
+
+    #[provides(scoped_inject)]
+    impl ElectricHeater {
+        fn new(logger: Arc<RwLock<CoffeeLogger>>) -> Self {
+            Self {
+                logger,
+                heating: false,
+            }
+        }
+    }
+
+    impl ElectricHeater {
+        pub(crate) fn on(&mut self) {
+            self.heating = true;
+            self.logger
+                .write()
+                .unwrap()
+                .log("~ ~ ~ heating ~ ~ ~".to_string());
+        }
+
+        pub(crate) fn off(&mut self) {
+            self.heating = false;
+        }
+
+        pub(crate) fn is_hot(&self) -> bool {
+            self.heating
+        }
+    }
+}
+
+mod pump {
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+        sync::{Arc, RwLock},
+    };
+
+    use dirk_framework::provides;
+
+    use crate::{heater::ElectricHeater, logger::CoffeeLogger};
+
+    pub(crate) trait Pump {
+        fn pump(&mut self);
+    }
+
+    pub(crate) struct ThermoSiphon {
+        logger: Arc<RwLock<CoffeeLogger>>,
+        heater: Rc<RefCell<ElectricHeater>>,
+    }
+
+    #[provides]
+    impl ThermoSiphon {
+        fn new(logger: Arc<RwLock<CoffeeLogger>>, heater: Rc<RefCell<ElectricHeater>>) -> Self {
+            Self { logger, heater }
+        }
+    }
+
+    impl Pump for ThermoSiphon {
+        fn pump(&mut self) {
+            if self.heater.borrow().is_hot() {
+                self.logger
+                    .write()
+                    .unwrap()
+                    .log("=> => pumping => =>".to_string());
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! An example involving a coffee machine - configuration deserialized from JSON or TOML
+
+use std::path::Path;
+
+use dirk_framework::{
+    component,
+    component::{builder::Builder, Component},
+    provides,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+fn main() {
+    let config = json!({
+        "shop": { "name": "Dirk's" },
+        "machine": { "brewing": { "grams": "a lot" } }
+    });
+    match DirkCoffeeShop::builder().config(config).try_build() {
+        Ok(_) => println!("unexpectedly built"),
+        Err(e) => println!("{e}"),
+    }
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples/coffee.toml");
+    let coffee_shop = DirkCoffeeShop::builder().config(path).try_build().unwrap();
+
+    coffee_shop.maker().brew();
+}
+
+#[component(
+    shop: config_bind(Shop, path = "shop"),
+    brewing: config_bind(Brewing, path = "machine.brewing"),
+    maker: static_bind(CoffeeMaker) [shop, brewing]
+)]
+trait CoffeeShop {
+    fn maker(&self) -> CoffeeMaker;
+}
+
+//######################################################################################################################
+
+#[derive(Clone, Deserialize)]
+struct Shop {
+    name: String,
+}
+
+#[derive(Clone, Deserialize)]
+struct Brewing {
+    grams: u8,
+    temperature: u8,
+}
+
+struct CoffeeMaker {
+    shop: Shop,
+    brewing: Brewing,
+}
+
+#[provides]
+impl CoffeeMaker {
+    fn new(shop: Shop, brewing: Brewing) -> Self {
+        Self { shop, brewing }
+    }
+}
+
+impl CoffeeMaker {
+    fn brew(&self) {
+        println!(
+            " [_]P {} coffee with {}g at {}°C [_]P ",
+            self.shop.name, self.brewing.grams, self.brewing.temperature
+        );
+    }
+}
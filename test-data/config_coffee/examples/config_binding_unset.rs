@@ -0,0 +1,46 @@
+//! An example involving a coffee machine - the configuration is never passed to the builder
+
+use dirk_framework::{
+    component,
+    component::{builder::Builder, Component},
+    provides,
+};
+use serde::Deserialize;
+
+fn main() {
+    let coffee_shop = DirkCoffeeShop::builder().build();
+
+    coffee_shop.maker().brew();
+}
+
+#[component(
+    shop: config_bind(Shop, path = "shop"),
+    maker: static_bind(CoffeeMaker) [shop]
+)]
+trait CoffeeShop {
+    fn maker(&self) -> CoffeeMaker;
+}
+
+//######################################################################################################################
+
+#[derive(Clone, Deserialize)]
+struct Shop {
+    name: String,
+}
+
+struct CoffeeMaker {
+    shop: Shop,
+}
+
+#[provides]
+impl CoffeeMaker {
+    fn new(shop: Shop) -> Self {
+        Self { shop }
+    }
+}
+
+impl CoffeeMaker {
+    fn brew(&self) {
+        println!(" [_]P {} coffee [_]P ", self.shop.name);
+    }
+}
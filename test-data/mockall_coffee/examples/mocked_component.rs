@@ -0,0 +1,138 @@
+//! An example involving a coffee machine - creating mocks for a component via the `mockall` feature
+
+use std::{cell::RefCell, rc::Rc};
+
+use dirk_framework::{
+    component,
+    component::{builder::Builder, Component},
+    provides,
+};
+use heater::{ElectricHeater, Heater};
+use pump::{Pump, ThermoSiphon};
+
+// mocks need to be in scope of the component
+#[cfg(test)]
+use heater::MockHeater;
+#[cfg(test)]
+use pump::MockPump;
+
+fn main() {
+    let coffee_shop = DirkCoffeeShop::builder()
+        .heater(ElectricHeater)
+        .pump(ThermoSiphon)
+        .build();
+    coffee_shop.maker().brew();
+}
+
+#[component(
+    mockall,
+    heater: scoped_instance_bind(H),
+    pump: scoped_instance_bind(P),
+    maker: static_bind(CoffeeMaker<H, P>) [heater, pump]
+)]
+trait CoffeeShop<H: Heater + 'static, P: Pump + 'static> {
+    fn maker(&self) -> CoffeeMaker<H, P>;
+}
+
+#[cfg(test)]
+mod test {
+    use dirk_framework::component::builder::Builder;
+
+    use crate::{CoffeeShop, DirkCoffeeShop};
+
+    #[test]
+    fn test_coffe_maker() {
+        // one mock for each binding of a generic type
+        let mut mocks = DirkCoffeeShop::mocked();
+
+        mocks.heater.expect_on().once().return_const(());
+        mocks.pump.expect_pump().once().return_const(());
+        mocks.heater.expect_off().once().return_const(());
+
+        let (coffee_shop, handles) = mocks.build();
+
+        // call function
+        coffee_shop.maker().brew();
+
+        // the mocks are still accessible after building the component
+        handles.heater.borrow_mut().checkpoint();
+        handles.pump.borrow_mut().checkpoint();
+
+        handles.pump.borrow_mut().expect_pump().never();
+        let _maker = coffee_shop.maker();
+    }
+
+    #[test]
+    fn test_coffe_maker_builder() {
+        let mut mocks = DirkCoffeeShop::mocked();
+        mocks.pump.expect_pump().once().return_const(());
+
+        // further instance bindings may be set on the builder
+        let (builder, handles) = mocks.builder();
+        let coffee_shop = builder.build();
+
+        handles.heater.borrow_mut().expect_on().once().return_const(());
+        handles.heater.borrow_mut().expect_off().once().return_const(());
+        coffee_shop.maker().brew();
+    }
+}
+
+//######################################################################################################################
+
+struct CoffeeMaker<H: Heater, P: Pump> {
+    heater: Rc<RefCell<H>>,
+    pump: Rc<RefCell<P>>,
+}
+
+#[provides]
+impl<H: Heater, P: Pump> CoffeeMaker<H, P> {
+    fn new(heater: Rc<RefCell<H>>, pump: Rc<RefCell<P>>) -> Self {
+        Self { heater, pump }
+    }
+}
+
+impl<H: Heater, P: Pump> CoffeeMaker<H, P> {
+    fn brew(&mut self) {
+        self.heater.borrow_mut().on();
+        self.pump.borrow_mut().pump();
+        println!(" [_]P coffee! [_]P ");
+        self.heater.borrow_mut().off();
+    }
+}
+
+mod heater {
+    use mockall::automock;
+
+    #[automock]
+    pub trait Heater {
+        fn on(&mut self);
+        fn off(&mut self);
+    }
+
+    pub struct ElectricHeater;
+
+    impl Heater for ElectricHeater {
+        fn on(&mut self) {
+            println!("~ ~ ~ heating ~ ~ ~");
+        }
+
+        fn off(&mut self) {}
+    }
+}
+
+mod pump {
+    use mockall::automock;
+
+    #[automock]
+    pub trait Pump {
+        fn pump(&mut self);
+    }
+
+    pub struct ThermoSiphon;
+
+    impl Pump for ThermoSiphon {
+        fn pump(&mut self) {
+            println!("=> => pumping => =>");
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! An example involving a coffee machine - replacing a binding by a mock in tests
+
+use std::{cell::RefCell, rc::Rc};
+
+use dirk_framework::{component, component::StaticComponent, provides, use_provides};
+
+#[use_provides(scoped_inject)]
+use heater::ElectricHeater;
+#[use_provides(scoped_inject)]
+use pump::ThermoSiphon;
+
+fn main() {
+    let coffee_shop = DirkCoffeeShop::create();
+    coffee_shop.maker().brew();
+}
+
+#[component(
+    heater: scoped_bind(ElectricHeater),
+    pump: scoped_bind(ThermoSiphon) [heater],
+    maker: static_bind(CoffeeMaker) [heater, pump]
+)]
+trait CoffeeShop {
+    fn maker(&self) -> CoffeeMaker;
+}
+
+#[cfg(test)]
+mod test {
+    use dirk_framework::component::{builder::Builder, Component};
+    use mockall::predicate::eq;
+
+    use crate::heater::{ElectricHeater, MockHeatingElement};
+    use crate::{CoffeeShop, DirkCoffeeShop};
+
+    #[test]
+    fn test_coffe_maker() {
+        // prepare mock
+        let mut element_mock = MockHeatingElement::new();
+        element_mock
+            .expect_heat()
+            .with(eq(true))
+            .once()
+            .return_const(());
+        element_mock
+            .expect_heat()
+            .with(eq(false))
+            .once()
+            .return_const(());
+
+        // only the heater is replaced, the pump still receives it
+        let coffee_shop = DirkCoffeeShop::builder()
+            .override_heater(ElectricHeater::with_element(Box::new(element_mock)))
+            .build();
+
+        // call function
+        coffee_shop.maker().brew();
+    }
+}
+
+//######################################################################################################################
+
+struct CoffeeMaker {
+    heater: Rc<RefCell<ElectricHeater>>,
+    pump: Rc<RefCell<ThermoSiphon>>,
+}
+
+#[provides]
+impl CoffeeMaker {
+    fn new(heater: Rc<RefCell<ElectricHeater>>, pump: Rc<RefCell<ThermoSiphon>>) -> Self {
+        Self { heater, pump }
+    }
+}
+
+impl CoffeeMaker {
+    fn brew(&mut self) {
+        self.heater.borrow_mut().on();
+        self.pump.borrow_mut().pump();
+        println!(" [_]P coffee! [_]P ");
+        self.heater.borrow_mut().off();
+    }
+}
+
+mod heater {
+    use dirk_framework::provides;
+    use mockall::automock;
+
+    #[automock]
+    pub trait HeatingElement {
+        fn heat(&mut self, on: bool);
+    }
+
+    struct Coil;
+
+    impl HeatingElement for Coil {
+        fn heat(&mut self, on: bool) {
+            if on {
+                println!("~ ~ ~ heating ~ ~ ~");
+            }
+        }
+    }
+
+    pub struct ElectricHeater {
+        element: Box<dyn HeatingElement>,
+        heating: bool,
+    }
+
+    #[provides(scoped_inject)]
+    impl ElectricHeater {
+        fn new() -> Self {
+            Self::with_element(Box::new(Coil))
+        }
+    }
+
+    impl ElectricHeater {
+        pub fn with_element(element: Box<dyn HeatingElement>) -> Self {
+            Self {
+                element,
+                heating: false,
+            }
+        }
+
+        pub fn on(&mut self) {
+            self.heating = true;
+            self.element.heat(true);
+        }
+
+        pub fn off(&mut self) {
+            self.heating = false;
+            self.element.heat(false);
+        }
+
+        pub fn is_hot(&self) -> bool {
+            self.heating
+        }
+    }
+}
+
+mod pump {
+    use std::{cell::RefCell, rc::Rc};
+
+    use dirk_framework::provides;
+
+    use crate::heater::ElectricHeater;
+
+    pub struct ThermoSiphon {
+        heater: Rc<RefCell<ElectricHeater>>,
+    }
+
+    #[provides(scoped_inject)]
+    impl ThermoSiphon {
+        fn new(heater: Rc<RefCell<ElectricHeater>>) -> Self {
+            Self { heater }
+        }
+    }
+
+    impl ThermoSiphon {
+        pub fn pump(&mut self) {
+            if self.heater.borrow().is_hot() {
+                println!("=> => pumping => =>");
+            }
+        }
+    }
+}
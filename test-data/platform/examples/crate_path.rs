@@ -0,0 +1,100 @@
+//! An example involving a coffee machine - using dirk re-exported by a different crate
+
+use std::{cell::RefCell, rc::Rc};
+
+use platform::di::{component, component::StaticComponent, inject_members, provides};
+
+fn main() {
+    let coffee_shop = DirkCoffeeShop::create();
+
+    // e.g., deserialized from a staff roster
+    let mut barista = Barista {
+        name: "Bob".to_owned(),
+        heater: Rc::new(RefCell::new(Heater { heating: false })),
+    };
+    coffee_shop.inject_barista(&mut barista);
+    barista.serve();
+
+    coffee_shop.maker().brew();
+}
+
+#[component(
+    crate = platform::di,
+    heater: scoped_bind(Heater),
+    maker: static_bind(CoffeeMaker) [heater],
+    inject_barista: members_bind(Barista) [heater]
+)]
+trait CoffeeShop {
+    fn maker(&self) -> CoffeeMaker;
+    fn inject_barista(&self, barista: &mut Barista);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CoffeeMaker, CoffeeShop, DirkCoffeeShop};
+
+    #[platform::di::test(component = CoffeeShop, crate = platform::di)]
+    fn heater_is_off_after_brewing(mut maker: CoffeeMaker) {
+        maker.brew();
+
+        assert!(!maker.heater.borrow().heating);
+    }
+}
+
+//######################################################################################################################
+
+struct Heater {
+    heating: bool,
+}
+
+#[provides(scoped_inject, crate = platform::di)]
+impl Heater {
+    fn new() -> Self {
+        Self { heating: false }
+    }
+}
+
+impl Heater {
+    fn on(&mut self) {
+        self.heating = true;
+        println!("~ ~ ~ heating ~ ~ ~");
+    }
+
+    fn off(&mut self) {
+        self.heating = false;
+    }
+}
+
+struct CoffeeMaker {
+    heater: Rc<RefCell<Heater>>,
+}
+
+#[provides(crate = platform::di)]
+impl CoffeeMaker {
+    fn new(heater: Rc<RefCell<Heater>>) -> Self {
+        Self { heater }
+    }
+}
+
+impl CoffeeMaker {
+    fn brew(&mut self) {
+        self.heater.borrow_mut().on();
+        println!(" [_]P coffee! [_]P ");
+        self.heater.borrow_mut().off();
+    }
+}
+
+#[inject_members(crate = platform::di)]
+struct Barista {
+    name: String,
+    #[inject]
+    heater: Rc<RefCell<Heater>>,
+}
+
+impl Barista {
+    fn serve(&mut self) {
+        self.heater.borrow_mut().on();
+        println!("{} is serving hot water", self.name);
+        self.heater.borrow_mut().off();
+    }
+}
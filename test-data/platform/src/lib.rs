@@ -0,0 +1,5 @@
+//! An internal platform crate re-exporting dirk
+
+pub mod di {
+    pub use dirk::*;
+}
@@ -0,0 +1,62 @@
+//! An example involving services provided by a different crate
+
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::{Arc, RwLock},
+};
+
+use dirk_framework::{
+    component, component::StaticComponent, provides, use_component, use_provides,
+};
+
+#[use_provides(scoped_inject)]
+use core_services::Database;
+#[use_provides]
+use core_services::Logger;
+
+#[use_component]
+use core_services::CoreServices;
+
+fn main() {
+    // a component of a different crate
+    let core = DirkCoreServices::create();
+    println!("core users: {:?}", core.database().borrow().users());
+
+    // a component of this crate, binding types provided by a different crate
+    let app = DirkApp::create();
+    app.greeter().greet();
+
+    for log in app.logger().read().unwrap().logs() {
+        println!("log: {log}");
+    }
+}
+
+#[component(
+    logger: singleton_bind(Logger),
+    database: scoped_bind(Database) [logger],
+    greeter: static_bind(Greeter) [database]
+)]
+trait App {
+    fn logger(&self) -> Arc<RwLock<Logger>>;
+    fn greeter(&self) -> Greeter;
+}
+
+struct Greeter {
+    database: Rc<RefCell<Database>>,
+}
+
+#[provides]
+impl Greeter {
+    fn new(database: Rc<RefCell<Database>>) -> Self {
+        Self { database }
+    }
+}
+
+impl Greeter {
+    fn greet(&self) {
+        for user in self.database.borrow().users() {
+            println!("hello {user}");
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! Services provided to other crates
+
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::{Arc, RwLock},
+};
+
+use dirk_framework::{component, provides};
+
+pub struct Logger {
+    logs: Vec<String>,
+}
+
+#[provides(singleton_inject, vis = pub)]
+impl Logger {
+    fn new() -> Self {
+        Self { logs: Vec::new() }
+    }
+}
+
+impl Logger {
+    pub fn log(&mut self, msg: String) {
+        self.logs.push(msg);
+    }
+
+    pub fn logs(&self) -> &[String] {
+        &self.logs
+    }
+}
+
+pub struct Database {
+    logger: Arc<RwLock<Logger>>,
+    users: Vec<String>,
+}
+
+#[provides(scoped_inject, vis = pub)]
+impl Database {
+    fn new(logger: Arc<RwLock<Logger>>) -> Self {
+        Self {
+            logger,
+            users: vec!["alice".to_owned(), "bob".to_owned()],
+        }
+    }
+}
+
+impl Database {
+    pub fn users(&self) -> &[String] {
+        self.logger
+            .write()
+            .unwrap()
+            .log("querying users".to_owned());
+        &self.users
+    }
+}
+
+#[component(
+    logger: singleton_bind(Logger),
+    database: scoped_bind(Database) [logger]
+)]
+pub trait CoreServices {
+    fn logger(&self) -> Arc<RwLock<Logger>>;
+    fn database(&self) -> Rc<RefCell<Database>>;
+}
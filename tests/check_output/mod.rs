@@ -4,6 +4,10 @@ use insta::{assert_snapshot, with_settings, Settings};
 use tempdir::TempDir;
 
 pub(crate) fn test_main(command: &str, path: &str, name: &str) {
+    test_main_with_env(command, path, name, &[]);
+}
+
+pub(crate) fn test_main_with_env(command: &str, path: &str, name: &str, envs: &[(&str, &str)]) {
     let mut cmd = Command::new(env!("CARGO"));
 
     cmd.arg(command);
@@ -12,6 +16,7 @@ pub(crate) fn test_main(command: &str, path: &str, name: &str) {
 
     cmd.env("RUSTFLAGS", "--cap-lints=allow");
     cmd.env("CARGO_TERM_COLOR", "never");
+    cmd.envs(envs.iter().copied());
 
     let mut current_dir = PathBuf::new();
     current_dir.push("test-data");
@@ -21,6 +26,10 @@ pub(crate) fn test_main(command: &str, path: &str, name: &str) {
 
     let target_dir = TempDir::new_in(env!("CARGO_TARGET_TMPDIR"), name).unwrap();
     cmd.env("TARGET_DIR", target_dir.path());
+    if envs.iter().any(|(key, _)| *key == "RUSTFLAGS") {
+        // Avoid rebuilding the shared target directory whenever `RUSTFLAGS` changes
+        cmd.env("CARGO_TARGET_DIR", target_dir.path());
+    }
 
     let output = cmd
         .output()
@@ -49,6 +58,14 @@ pub(crate) fn test_main(command: &str, path: &str, name: &str) {
     let filter_adding = r".*Adding .*(\(.*\))?\n";
     settings.add_filter(filter_adding, "");
 
+    // Long type names are written to a file named after the hash of the crate, which differs between target directories
+    let filter_long_type = r".*= note: the full name for the type has been written to .*\n";
+    settings.add_filter(filter_long_type, "");
+
+    let filter_verbose =
+        r".*= note: consider using `--verbose` to print the full type name to the console\n";
+    settings.add_filter(filter_verbose, "");
+
     let filter_path = if cfg!(windows) {
         r"(?:([\w\-_]+)(?:\\))+([\w\-_]+?\.[\w\-_]+)?"
     } else {
@@ -73,7 +90,7 @@ pub(crate) fn test_main(command: &str, path: &str, name: &str) {
 
     settings.bind(|| {
         with_settings!({snapshot_suffix => format!("{}_{}", path, name)}, {
-            assert_snapshot!(pretty);
+            assert_snapshot!("main", pretty);
         });
     });
 }
@@ -11,21 +11,66 @@ mod check_output;
 #[test_case("coffee", "component_missing_dependency")]
 #[test_case("coffee", "component_too_few_dependencies")]
 #[test_case("coffee", "component_too_many_dependencies")]
+#[test_case("coffee", "component_dependency_type_mismatch")]
 #[test_case("coffee", "component_missing_binding")]
 #[test_case("coffee", "component_cycle")]
+#[test_case("coffee", "component_cycle_path")]
 #[test_case("coffee", "component_singleton_with_dependencies")]
 #[test_case("coffee", "component_where_clause")]
+#[test_case("coffee", "component_members_invalid_signature")]
+#[test_case("coffee", "component_members_as_dependency")]
+#[test_case("coffee", "component_multiple_errors")]
+#[test_case("coffee", "component_type_mismatch_stub")]
+#[test_case("coffee", "component_cloned_trait_object")]
+#[test_case("coffee", "component_config_without_serde")]
+#[test_case("coffee", "component_mockall_nothing_to_mock")]
+#[test_case("config_coffee", "config_binding_unset")]
 #[test_case("coffee", "provides_on_trait")]
 #[test_case("coffee", "provides_on_empty_impl")]
 #[test_case("coffee", "provides_on_impl_with_more_than_one_function")]
 #[test_case("coffee", "provides_invalid_return_type")]
 #[test_case("coffee", "provides_singleton_with_args")]
+#[test_case("coffee", "provides_multiple_errors")]
 #[test_case("coffee", "provides_duplicate")]
+#[test_case("application", "provides_duplicate_qualifier")]
+#[test_case("application", "component_unknown_qualifier")]
 #[test_case("application", "component_binding_impl_trait")]
 #[test_case("application", "component_function_returning_impl_trait")]
 #[test_case("application", "component_wrapped_impl_trait")]
 #[test_case("application", "component_unwrapped_impl_trait")]
 #[test_case("car", "use_component_on_fn")]
+#[test_case("coffee", "test_attribute_pattern")]
 fn run_errors(path: &str, name: &str) {
     check_output::test_main("run", path, name);
 }
+
+#[test_case("coffee", "component_override_type_mismatch")]
+fn test_errors(path: &str, name: &str) {
+    check_output::test_main("test", path, name);
+}
+
+#[test_case("coffee", "component_unused_binding")]
+#[test_case("coffee", "provides_unreceived_dependency")]
+fn lints_denied(path: &str, name: &str) {
+    check_output::test_main_with_env("run", path, name, &[("DIRK_LINTS", "deny")]);
+}
+
+#[test_case("coffee", "provides_unbound")]
+fn lint_unbound_provides_denied(path: &str, name: &str) {
+    check_output::test_main_with_env(
+        "run",
+        path,
+        name,
+        &[("RUSTFLAGS", ""), ("DIRK_LINT_UNBOUND_PROVIDES", "deny")],
+    );
+}
+
+#[test_case("coffee", "component_graph_collision")]
+fn graph_exported(path: &str, name: &str) {
+    check_output::test_main_with_env(
+        "run",
+        path,
+        name,
+        &[("RUSTFLAGS", ""), ("DIRK_GRAPH_DIR", "target/dirk")],
+    );
+}
@@ -4,13 +4,39 @@ mod check_output;
 
 #[test_case("coffee", "blueprint")]
 #[test_case("coffee", "component_order_of_bindings")]
+#[test_case("coffee", "component_members_injection")]
+#[test_case("coffee", "component_dependency_graph")]
+#[test_case("coffee", "component_introspection")]
+#[test_case("coffee", "component_large_graph")]
+#[test_case("coffee", "use_provides_any_kind")]
+#[test_case("coffee", "component_provider_instance")]
+#[test_case("coffee", "component_shared_instance")]
+#[test_case("coffee", "component_shared_scoped_instance")]
+#[test_case("coffee", "component_trait_object_instance")]
+#[test_case("coffee", "component_env_binding")]
 #[test_case("application", "test_generics")]
 #[test_case("car", "blueprint")]
+#[test_case("car", "use_glob")]
+#[test_case("car", "custom_names")]
+#[test_case("car", "qualified_paths")]
+#[test_case("application", "qualifiers")]
+#[test_case("services", "cross_crate")]
+#[test_case("config_coffee", "config_binding")]
 fn run_examples(path: &str, name: &str) {
     check_output::test_main("run", path, name);
 }
 
+#[test_case("coffee", "component_test_attribute")]
+#[test_case("platform", "crate_path")]
 #[test_case("mockall_coffee", "blueprint")]
+#[test_case("mockall_coffee", "override_bindings")]
+#[test_case("mockall_coffee", "mocked_component")]
 fn test_examples(path: &str, name: &str) {
     check_output::test_main("test", path, name);
 }
+
+#[test_case("coffee", "component_unused_binding")]
+#[test_case("coffee", "provides_unreceived_dependency")]
+fn lints_warned(path: &str, name: &str) {
+    check_output::test_main_with_env("build", path, name, &[("RUSTFLAGS", "")]);
+}